@@ -0,0 +1,232 @@
+// Off-chain replay of a job's history from transaction logs. Support tooling
+// fetches the job's transactions (getSignaturesForAddress + getTransaction)
+// with whatever RPC client it already uses, and hands the logs here to get a
+// typed timeline back instead of re-implementing Anchor's log parsing.
+
+use crate::*;
+use anchor_lang::Discriminator;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+// Logs of one confirmed transaction, as returned by getTransaction
+pub struct TransactionLogs {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub logs: Vec<String>,
+}
+
+// One decoded event and the transaction that emitted it
+pub struct JobEventRecord {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub event: JobEvent,
+}
+
+macro_rules! job_events {
+    ($($name:ident),* $(,)?) => {
+        // Every event the program emits that names the job it concerns
+        pub enum JobEvent {
+            $($name($name),)*
+        }
+
+        impl JobEvent {
+            // Decodes a `Program data:` payload; None for events that aren't
+            // job-scoped or don't belong to this program
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                $(
+                    if let Some(body) = data.strip_prefix($name::DISCRIMINATOR) {
+                        return $name::try_from_slice(body).ok().map(JobEvent::$name);
+                    }
+                )*
+                None
+            }
+
+            pub fn job_post(&self) -> Pubkey {
+                match self {
+                    $(JobEvent::$name(event) => event.job_post,)*
+                }
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(JobEvent::$name(_) => stringify!($name),)*
+                }
+            }
+        }
+    };
+}
+
+job_events!(
+    JobPosted,
+    ApplicationSubmitted,
+    FreelancerInvited,
+    TimezoneMismatch,
+    LanguageMismatch,
+    ApplicationsClosed,
+    ApplicationApproved,
+    ApplicationDeclined,
+    CreditsApplied,
+    CreditsReturned,
+    RefundedAsCredits,
+    JobAmountRevealed,
+    ReviewRevealed,
+    FeedbackUpdated,
+    BountyClaimSubmitted,
+    BountyVoteCast,
+    BountyAwarded,
+    ApplicationWithdrawn,
+    ApplicationStakeForfeited,
+    FreelancerRemoved,
+    WorkSubmitted,
+    DeliverableAdded,
+    DeliverableAccepted,
+    DeliverableRejected,
+    SubmissionRejected,
+    ReleaseCoSigned,
+    SubmissionApproved,
+    ReviewLateFeePaid,
+    EscrowToppedUp,
+    BadgeMinted,
+    SolLegFunded,
+    SolLegSettled,
+    TipPaid,
+    ReferralRewarded,
+    MilestoneApproved,
+    PeriodPaid,
+    RetainerCancelled,
+    HoursLogged,
+    TimesheetApproved,
+    HourlyJobClosed,
+    VestedWithdrawn,
+    StreamCancelled,
+    ReminderFired,
+    TeamSharePaid,
+    LargePayout,
+    TransferFeeWithheld,
+    EscrowRentReconciled,
+    EscrowSnapshot,
+    EscrowDiscrepancy,
+    EscrowSwept,
+    BudgetThresholdCrossed,
+    DeadlineSet,
+    ReviewWindowSet,
+    DisputeOpened,
+    SettlementOffered,
+    SettlementAccepted,
+    DisputeResolved,
+    InsuranceClaimPaid,
+    JobFreezeChanged,
+    JobModerated,
+    MutualCancelRequested,
+    JobCancelled,
+    JobExpired,
+);
+
+// Events emitted directly by this program in one transaction's logs, in order.
+// Program data logged by other programs (including CPIs out of this one) is
+// skipped by tracking the invoke stack.
+pub fn decode_logs(logs: &[String]) -> Vec<JobEvent> {
+    let program = crate::ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for line in logs {
+        if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            let id = words.next().unwrap_or_default();
+            match words.next() {
+                Some("invoke") => stack.push(id),
+                Some("success") | Some("failed:") => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+        if stack.last() != Some(&program.as_str()) {
+            continue;
+        }
+        let Some(payload) = line.strip_prefix(PROGRAM_DATA) else {
+            continue;
+        };
+        if let Some(event) = STANDARD.decode(payload).ok().and_then(|data| JobEvent::decode(&data)) {
+            events.push(event);
+        }
+    }
+    events
+}
+
+// Timeline of one job across the given transactions, oldest first. RPC
+// returns signatures newest first, so order is restored by slot here.
+pub fn job_timeline(job_post: &Pubkey, transactions: Vec<TransactionLogs>) -> Vec<JobEventRecord> {
+    let mut timeline: Vec<JobEventRecord> = transactions
+        .into_iter()
+        .flat_map(|tx| {
+            decode_logs(&tx.logs)
+                .into_iter()
+                .filter(|event| event.job_post() == *job_post)
+                .map(|event| JobEventRecord {
+                    signature: tx.signature.clone(),
+                    slot: tx.slot,
+                    block_time: tx.block_time,
+                    event,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    // Stable, so events within one transaction keep their log order
+    timeline.sort_by_key(|record| record.slot);
+    timeline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_line<E: AnchorSerialize + Discriminator>(event: &E) -> String {
+        let mut data = E::DISCRIMINATOR.to_vec();
+        data.extend(event.try_to_vec().unwrap());
+        format!("{PROGRAM_DATA}{}", STANDARD.encode(data))
+    }
+
+    #[test]
+    fn timeline_keeps_only_this_programs_events_for_the_job() {
+        let job = Pubkey::new_unique();
+        let other_job = Pubkey::new_unique();
+        let cancelled = |job_post| JobCancelled {
+            job_post,
+            client: Pubkey::default(),
+            refund: BaseUnits(5),
+            timestamp: 0,
+        };
+        let program = crate::ID.to_string();
+        let logs = vec![
+            format!("Program {program} invoke [1]"),
+            data_line(&cancelled(job)),
+            data_line(&cancelled(other_job)),
+            "Program 11111111111111111111111111111111 invoke [2]".to_string(),
+            data_line(&cancelled(job)),
+            "Program 11111111111111111111111111111111 success".to_string(),
+            format!("Program {program} success"),
+        ];
+        let later = TransactionLogs {
+            signature: "b".into(),
+            slot: 9,
+            block_time: None,
+            logs: logs.clone(),
+        };
+        let earlier = TransactionLogs {
+            signature: "a".into(),
+            slot: 3,
+            block_time: None,
+            logs,
+        };
+
+        let timeline = job_timeline(&job, vec![later, earlier]);
+        let signatures: Vec<_> = timeline.iter().map(|r| r.signature.as_str()).collect();
+        assert_eq!(signatures, ["a", "b"]);
+        assert!(timeline.iter().all(|r| r.event.name() == "JobCancelled"));
+    }
+}
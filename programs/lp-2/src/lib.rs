@@ -1,14 +1,74 @@
 use anchor_lang::prelude::*;
+// The prelude's `Lamports` trait, kept in scope for `add_lamports` and
+// `sub_lamports` under the amount type of the same name
+use anchor_lang::Lamports as _;
 use anchor_lang::system_program;
-use anchor_lang::solana_program::{system_instruction, program::invoke_signed};
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use anchor_lang::solana_program::program::set_return_data;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::{system_instruction, program::{invoke, invoke_signed}};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::{self, Token2022};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_2022_extensions::{
+    self as token_extensions, MetadataPointerInitialize, NonTransferableMintInitialize,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 declare_id!("AkDSbrdvrnfe558WDZEkGuJUayt8nChyog6bcGr1hVFm");
 
+#[cfg(feature = "client")]
+pub mod history;
+#[cfg(feature = "client")]
+pub mod test_vectors;
+
+// Pure helpers the handlers are built on, public to clients so both share
+// one implementation
+#[cfg(feature = "client")]
+pub mod math;
+#[cfg(not(feature = "client"))]
+mod math;
+#[cfg(feature = "client")]
+pub mod pda;
+#[cfg(not(feature = "client"))]
+#[allow(dead_code)]
+mod pda;
+#[cfg(feature = "client")]
+pub mod state;
+#[cfg(not(feature = "client"))]
+mod state;
+
+pub use math::{bps_of, checked_add, checked_sub, civil_date};
+use math::{days_late, late_fee, periods_elapsed, split_payout, vested_amount, PayoutSplit};
+pub use state::JobStatus;
+use state::JobLifecycle;
+
+// `require!` that also leaves an ErrorContext naming the offending field, and
+// the bound it had to meet against the value it had, in return data
+macro_rules! require_ctx {
+    ($cond:expr, $err:expr, $field:expr, $expected:expr, $actual:expr $(,)?) => {
+        if !($cond) {
+            return Err($crate::error_context($err, $field, $expected as i128, $actual as i128));
+        }
+    };
+}
+
 #[program]
 pub mod lp_program {
     use super::*;
 
-    // Client posts a job offer and locks funds in escrow
+    // Client posts a job offer and locks funds in escrow. DAO clients pass the
+    // realm and proposal that authorized the spend.
     #[allow(clippy::too_many_arguments)]
     pub fn initialize_job_post(
         ctx: Context<InitializeJobPost>,
@@ -17,77 +77,78 @@ pub mod lp_program {
         amount: u64,
         start_date: i64,
         end_date: i64,
+        governance: Option<GovernanceReceipt>,
     ) -> Result<()> {
-        require!(!title.is_empty(), ErrorCode::InvalidInput);
-        require!(!description.is_empty(), ErrorCode::InvalidInput);
-        require!(amount > 0, ErrorCode::InvalidAmount);
-        require!(start_date <= end_date, ErrorCode::InvalidDates);
-
-        let clock = Clock::get()?;
-        require!(start_date >= clock.unix_timestamp, ErrorCode::InvalidDates);
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
 
         let job_post = &mut ctx.accounts.job_post;
-        job_post.client = ctx.accounts.client.key();
-        job_post.title = title;
-        job_post.description = description;
-        job_post.amount = amount;
-        job_post.is_filled = false;
-        job_post.start_date = start_date;
-        job_post.end_date = end_date;
-        job_post.escrow_bump = ctx.bumps.escrow;
-        job_post.cancelled = false;
-        job_post.freelancer = None;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.governance = governance;
 
-        // Derive PDA seeds for escrow
-        let job_post_key = job_post.key();
         let escrow_key = ctx.accounts.escrow.key();
-        let bump = ctx.bumps.escrow;
-        let seeds = &[b"escrow", job_post_key.as_ref(), &[bump]];
-        let signer_seeds = &[&seeds[..]];
-
-        // Create the escrow account (a pure system account to hold lamports)
-        let lamports = Rent::get()?.minimum_balance(0).max(amount);
-        invoke_signed(
-            &system_instruction::create_account(
-                &ctx.accounts.client.key(),
-                &escrow_key,
-                lamports,
-                0, // 0 bytes = no data
-                &system_program::ID,
-            ),
-            &[
-                ctx.accounts.client.to_account_info(),
-                ctx.accounts.escrow.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            signer_seeds,
+        let credit = ctx.accounts.credits.as_ref().map_or(0, |credits| credits.balance.min(amount));
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount - credit,
         )?;
+        if let Some(credits) = ctx.accounts.credits.as_mut() {
+            apply_credits(credits, &ctx.accounts.escrow, job_post, credit)?;
+        }
 
-        // Transfer job amount from client to escrow
-        let cpi_ctx = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.client.to_account_info(),
-                to: ctx.accounts.escrow.to_account_info(),
-            },
-        );
-        system_program::transfer(cpi_ctx, amount)?;
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
 
-        // Update client stats
-        let client_stats = &mut ctx.accounts.client_stats;
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
 
-        // Get current month (1–12)
-        let month = (Clock::get()?.unix_timestamp / 2_592_000) % 12 + 1; // ~30 days
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
 
-        if client_stats.last_updated_month != month as u8 {
-            client_stats.monthly_gigs = 0;
-            client_stats.monthly_revenue = 0;
-            client_stats.last_updated_month = month as u8;
+        if let Some(receipt) = governance {
+            msg!(
+                "🏛️ Authorized by proposal {} of realm {}",
+                receipt.proposal,
+                receipt.realm
+            );
         }
 
-        client_stats.total_gigs_posted += 1;
-        client_stats.monthly_gigs += 1;
-
         msg!(
             "✅ Job post created: '{}' for {} lamports. Escrow: {}",
             job_post.title,
@@ -98,455 +159,19789 @@ pub mod lp_program {
         Ok(())
     }
 
-    // Freelancer applies to a job
-    pub fn apply_to_job(
-        ctx: Context<ApplyToJob>,
-        resume_link: String,
-        expected_end_date: i64,
+    // Client posts a SOL job budgeted in USD cents. The budget is converted to
+    // lamports at the operator's SOL/USD price, and both are kept on the job.
+    pub fn initialize_usd_job_post(
+        ctx: Context<InitializeJobPost>,
+        title: String,
+        description: String,
+        usd_cents: u64,
+        start_date: i64,
+        end_date: i64,
     ) -> Result<()> {
-        require!(!resume_link.is_empty(), ErrorCode::InvalidInput);
-        require!(expected_end_date >= 0, ErrorCode::InvalidDates);
-        require!(
-            !ctx.accounts.job_post.is_filled,
-            ErrorCode::JobAlreadyFilled
-        );
-        require!(!ctx.accounts.job_post.cancelled, ErrorCode::JobCancelled);
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let (amount, price) = usd_budget_in_lamports(
+            &ctx.accounts.platform_config,
+            ctx.accounts.price_update.as_ref(),
+            usd_cents,
+        )?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
 
-        let application = &mut ctx.accounts.application;
-        application.applicant = ctx.accounts.freelancer.key();
-        application.job_post = ctx.accounts.job_post.key();
-        application.resume_link = resume_link;
-        application.approved = false;
-        application.completed = false;
-        application.submission_link = String::new();
-        application.narration = String::new();
-        application.client_review = String::new();
-        application.expected_end_date = expected_end_date;
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = Some(price);
+        job_post.usd_cents = Some(usd_cents);
 
-        application.submitted = false;
-        application.rejected = false;
+        let escrow_key = ctx.accounts.escrow.key();
+        let credit = ctx.accounts.credits.as_ref().map_or(0, |credits| credits.balance.min(amount));
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount - credit,
+        )?;
+        if let Some(credits) = ctx.accounts.credits.as_mut() {
+            apply_credits(credits, &ctx.accounts.escrow, job_post, credit)?;
+        }
 
-        msg!("📩 Application submitted by {}", application.applicant);
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        msg!(
+            "✅ Job post created: '{}' for ${}.{:02} ({} lamports). Escrow: {}",
+            job_post.title,
+            usd_cents / 100,
+            usd_cents % 100,
+            amount,
+            escrow_key
+        );
         Ok(())
     }
 
-    // Client approves a freelancer's application
-    pub fn approve_application(ctx: Context<ApproveApplication>) -> Result<()> {
-        let job_post = &mut ctx.accounts.job_post;
-        let application = &mut ctx.accounts.application;
-
+    // Agency posts a pack of small jobs in one transaction. Each spec needs its
+    // job post and escrow PDAs passed, in that order, as remaining accounts;
+    // job posts take consecutive ids from the client's job counter.
+    pub fn initialize_job_posts_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeJobPostsBatch<'info>>,
+        specs: Vec<JobSpec>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
         require!(
-            job_post.client == ctx.accounts.client.key(),
-            ErrorCode::Unauthorized
+            !specs.is_empty() && specs.len() <= MAX_BATCH_JOBS,
+            ErrorCode::InvalidBatchSize
         );
-        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
-        require!(!job_post.cancelled, ErrorCode::JobCancelled);
         require!(
-            application.job_post == job_post.key(),
+            ctx.remaining_accounts.len() == specs.len() * 2,
             ErrorCode::InvalidAccount
         );
-        require!(!application.approved, ErrorCode::ApplicationAlreadyApproved);
 
-        application.approved = true;
-        job_post.is_filled = true;
-        job_post.freelancer = Some(application.applicant);
+        let namespace = ctx.accounts.platform_config.namespace;
+        let client = ctx.accounts.client.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let space = 8 + JobPost::INIT_SPACE;
+        let rent = Rent::get()?;
+
+        for (spec, accounts) in specs.into_iter().zip(ctx.remaining_accounts.chunks(2)) {
+            validate_job_terms(&spec)?;
+            validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
+            let (job_info, escrow_info) = (&accounts[0], &accounts[1]);
+
+            let job_id = ctx.accounts.job_counter.jobs_posted.to_le_bytes();
+            let (job_key, job_bump) = pda::job_post(client.key, ctx.accounts.job_counter.jobs_posted);
+            require_keys_eq!(job_info.key(), job_key, ErrorCode::InvalidAccount);
+            let (escrow_key, escrow_bump) = pda::escrow(&job_key);
+            require_keys_eq!(escrow_info.key(), escrow_key, ErrorCode::InvalidAccount);
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    client.key,
+                    &job_key,
+                    rent.minimum_balance(space),
+                    space as u64,
+                    ctx.program_id,
+                ),
+                &[client.clone(), job_info.clone(), system_program.clone()],
+                &[&[b"job_post", client.key.as_ref(), &job_id, &[job_bump]]],
+            )?;
+            ctx.accounts.job_counter.record_post(client.key(), ctx.bumps.job_counter)?;
+            index_for_user(ctx.accounts.client_index.as_mut(), job_key)?;
+
+            let amount = spec.amount;
+            let mut job_post = new_job_post(namespace, client.key(), spec, escrow_bump, None);
+            job_post.escrow_rent = fund_escrow(
+                &client,
+                escrow_info,
+                &system_program,
+                job_key,
+                escrow_bump,
+                amount,
+            )?;
+            job_post.try_serialize(&mut &mut job_info.try_borrow_mut_data()?[..])?;
+
+            record_gig_posted(
+                &mut ctx.accounts.client_stats,
+                ctx.accounts.client_monthly_stats.as_mut(),
+            )?;
+
+            let operator_stats = &mut ctx.accounts.operator_stats;
+            operator_stats.record_job_posted(amount)?;
+
+            emit!(JobPosted {
+                job_post: job_key,
+                client: job_post.client,
+                escrow: escrow_key,
+                amount: BaseUnits(amount),
+                decimals: job_post.decimals,
+                symbol: job_post.symbol.clone(),
+                review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            emit_deadline(job_key, None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+            msg!(
+                "✅ Job post created: '{}' for {} lamports. Escrow: {}",
+                job_post.title,
+                amount,
+                escrow_key
+            );
+        }
 
-        msg!("✅ Application approved for job '{}'", job_post.title);
         Ok(())
     }
 
-    // Freelancer submits their completed work
-    pub fn submit_work(
-        ctx: Context<SubmitWork>,
-        submission_link: String,
-        narration: String,
+    // Client sets up a job that reposts itself every `cadence_seconds`, prefunding
+    // the escrow and rent for every occurrence up front
+    pub fn create_recurring_job(
+        ctx: Context<CreateRecurringJob>,
+        template: JobSpec,
+        cadence_seconds: i64,
+        occurrences: u32,
     ) -> Result<()> {
-        require!(!submission_link.is_empty(), ErrorCode::InvalidInput);
-        require!(!narration.is_empty(), ErrorCode::InvalidInput);
-
-        let application = &mut ctx.accounts.application;
+        require_not_paused(&ctx.accounts.platform_config)?;
+        validate_job_terms(&template)?;
+        require_ctx!(cadence_seconds > 0, ErrorCode::InvalidInput, "cadence_seconds", 1, cadence_seconds);
+        require_ctx!(occurrences > 0, ErrorCode::InvalidInput, "occurrences", 1, occurrences);
 
-        require!(
-            application.applicant == ctx.accounts.freelancer.key(),
-            ErrorCode::Unauthorized
+        let budget = occurrence_cost(template.amount)?
+            .checked_mul(u64::from(occurrences))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.client.to_account_info(),
+                to: ctx.accounts.recurring_job.to_account_info(),
+            },
         );
-        require!(application.approved, ErrorCode::ApplicationNotApproved);
-        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        system_program::transfer(cpi_ctx, budget)?;
 
-        // ✅ allow resubmission if rejected
-        application.submission_link = submission_link;
-        application.narration = narration;
-        application.submitted = true;
-        application.rejected = false; // reset rejection flag
+        let recurring_job = &mut ctx.accounts.recurring_job;
+        recurring_job.namespace = ctx.accounts.platform_config.namespace;
+        recurring_job.client = ctx.accounts.client.key();
+        recurring_job.duration = template.end_date - template.start_date;
+        recurring_job.next_run_at = template.start_date;
+        recurring_job.template = template;
+        recurring_job.cadence_seconds = cadence_seconds;
+        recurring_job.occurrences = occurrences;
+        recurring_job.spawned = 0;
+        recurring_job.bump = ctx.bumps.recurring_job;
 
-        msg!("📤 Work submitted by {}", application.applicant);
+        msg!(
+            "🔁 Recurring job '{}' created: {} occurrences every {}s, {} lamports prefunded",
+            recurring_job.template.title,
+            occurrences,
+            cadence_seconds,
+            budget
+        );
         Ok(())
     }
 
-    // Client approves work and releases escrow funds to freelancer
-    pub fn approve_submission(
-        ctx: Context<ApproveSubmission>,
-        client_review: String,
-    ) -> Result<()> {
-        let job_post = &ctx.accounts.job_post;
-        let application = &mut ctx.accounts.application;
+    // Permissionless crank: posts and funds the next occurrence of a recurring job
+    // once it is due. The cranker fronts the job post rent and is reimbursed.
+    pub fn spawn_next_occurrence(ctx: Context<SpawnNextOccurrence>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let now = Clock::get()?.unix_timestamp;
+        let recurring_job = &mut ctx.accounts.recurring_job;
 
-        // --- VALIDATIONS ---
         require!(
-            job_post.client == ctx.accounts.client.key(),
-            ErrorCode::Unauthorized
+            recurring_job.spawned < recurring_job.occurrences,
+            ErrorCode::RecurringJobExhausted
         );
-        require!(application.submitted, ErrorCode::WorkNotCompleted);
-        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
-        require!(
-            application.job_post == job_post.key(),
-            ErrorCode::InvalidAccount
+        require!(now >= recurring_job.next_run_at, ErrorCode::OccurrenceNotDue);
+
+        let end_date = recurring_job
+            .next_run_at
+            .checked_add(recurring_job.duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let spec = JobSpec {
+            start_date: recurring_job.next_run_at,
+            end_date,
+            ..recurring_job.template.clone()
+        };
+        let amount = spec.amount;
+        recurring_job.spawned = recurring_job
+            .spawned
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        recurring_job.next_run_at = recurring_job
+            .next_run_at
+            .checked_add(recurring_job.cadence_seconds)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            recurring_job.namespace,
+            recurring_job.client,
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+
+        // Budget covers the escrow (amount + rent-exempt minimum) and refunds the
+        // job post rent fronted by the cranker
+        let rent = Rent::get()?;
+        job_post.escrow_rent = Lamports(rent.minimum_balance(8 + Escrow::INIT_SPACE));
+        let escrow_lamports = amount + job_post.escrow_rent.0;
+        let job_rent = job_post.to_account_info().lamports();
+        recurring_job
+            .to_account_info()
+            .sub_lamports(checked_add(escrow_lamports, job_rent)?)?;
+        ctx.accounts.escrow.add_lamports(escrow_lamports)?;
+        ctx.accounts.cranker.add_lamports(job_rent)?;
+        init_typed_escrow(
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            job_post.escrow_bump,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: ctx.accounts.escrow.key(),
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: now,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        msg!(
+            "🔁 Occurrence {}/{} of '{}' posted",
+            recurring_job.spawned,
+            recurring_job.occurrences,
+            job_post.title
         );
-        require!(
-            job_post.freelancer == Some(application.applicant),
-            ErrorCode::Unauthorized
+        Ok(())
+    }
+
+    // Client stops a recurring job and recovers the budget for unspawned occurrences
+    pub fn cancel_recurring_job(ctx: Context<CancelRecurringJob>) -> Result<()> {
+        let recurring_job = &ctx.accounts.recurring_job;
+        msg!(
+            "❌ Recurring job '{}' cancelled after {}/{} occurrences",
+            recurring_job.template.title,
+            recurring_job.spawned,
+            recurring_job.occurrences
         );
+        Ok(())
+    }
 
-        // Ensure escrow has enough lamports
+    // Client opens a project grouping related jobs under one budget for reporting.
+    // Jobs paid in `payment_mint` (None for SOL) can attach to it.
+    pub fn create_project(
+        ctx: Context<CreateProject>,
+        name: String,
+        metadata_uri: String,
+        budget: u64,
+        payment_mint: Option<Pubkey>,
+    ) -> Result<()> {
         require!(
-            **ctx.accounts.escrow.to_account_info().lamports.borrow() >= job_post.amount,
-            ErrorCode::InsufficientEscrowBalance
+            !name.is_empty() && name.len() <= MAX_PROJECT_NAME_LEN,
+            ErrorCode::InvalidInput
         );
+        require_ctx!(
+            metadata_uri.len() <= MAX_URI_LEN,
+            ErrorCode::InvalidInput,
+            "metadata_uri",
+            MAX_URI_LEN,
+            metadata_uri.len()
+        );
+        if payment_mint.is_some() {
+            require_feature(&ctx.accounts.platform_config, FEATURE_TOKENS)?;
+        }
 
-        // --- UPDATE APPLICATION STATUS ---
-        application.client_review = client_review;
-        application.completed = true;
+        let project = &mut ctx.accounts.project;
+        project.namespace = ctx.accounts.platform_config.namespace;
+        project.client = ctx.accounts.client.key();
+        project.name = name;
+        project.metadata_uri = metadata_uri;
+        project.payment_mint = payment_mint;
+        project.budget = budget;
+        project.spent = 0;
+        project.jobs = 0;
+        project.jobs_completed = 0;
+        project.alert_thresholds_bps = DEFAULT_BUDGET_ALERTS_BPS.to_vec();
+        project.bump = ctx.bumps.project;
 
-        // --- TRANSFER FUNDS FROM ESCROW TO FREELANCER ---
-        let job_post_key = job_post.key();
-        let seeds = &[b"escrow", job_post_key.as_ref(), &[job_post.escrow_bump]];
-        let signer_seeds = &[&seeds[..]];
+        msg!("📁 Project '{}' created with budget {}", project.name, budget);
+        Ok(())
+    }
 
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.escrow.to_account_info(),
-                to: ctx.accounts.freelancer.to_account_info(),
-            },
-            signer_seeds,
+    // Client updates a project's metadata and budget
+    pub fn update_project(
+        ctx: Context<UpdateProject>,
+        metadata_uri: String,
+        budget: u64,
+    ) -> Result<()> {
+        require_ctx!(
+            metadata_uri.len() <= MAX_URI_LEN,
+            ErrorCode::InvalidInput,
+            "metadata_uri",
+            MAX_URI_LEN,
+            metadata_uri.len()
         );
 
-        system_program::transfer(cpi_ctx, job_post.amount)?;
+        let project = &mut ctx.accounts.project;
+        project.metadata_uri = metadata_uri;
+        project.budget = budget;
 
-        // --- UPDATE FREELANCER STATS ---
-        let freelancer_stats = &mut ctx.accounts.freelancer_stats;
-        let current_time = Clock::get()?.unix_timestamp;
-        let current_month = (current_time / 2_592_000) % 12 + 1; // ~30 days per month
+        msg!("📁 Project '{}' updated, budget {}", project.name, budget);
+        Ok(())
+    }
 
-        if freelancer_stats.last_updated_month != current_month as u8 {
-            freelancer_stats.monthly_gigs = 0;
-            freelancer_stats.monthly_revenue = 0;
-            freelancer_stats.last_updated_month = current_month as u8;
-        }
+    // Client sets the shares of the budget (in bps, ascending) at which spending
+    // emits a `BudgetThresholdCrossed` warning
+    pub fn set_budget_alerts(ctx: Context<UpdateProject>, thresholds_bps: Vec<u16>) -> Result<()> {
+        require_ctx!(
+            thresholds_bps.len() <= MAX_BUDGET_ALERTS,
+            ErrorCode::InvalidInput,
+            "thresholds_bps",
+            MAX_BUDGET_ALERTS,
+            thresholds_bps.len()
+        );
+        require!(
+            thresholds_bps.windows(2).all(|pair| pair[0] < pair[1])
+                && thresholds_bps.iter().all(|&bps| bps > 0 && bps <= MAX_BPS),
+            ErrorCode::InvalidInput
+        );
 
-        freelancer_stats.total_revenue_earned += job_post.amount;
-        freelancer_stats.monthly_revenue += job_post.amount;
-        freelancer_stats.monthly_gigs += 1;
+        let project = &mut ctx.accounts.project;
+        project.alert_thresholds_bps = thresholds_bps;
 
         msg!(
-            "💸 Funds released to freelancer: {} lamports. Stats updated.",
-            job_post.amount
+            "🚨 Budget alerts for '{}' at {:?} bps",
+            project.name,
+            project.alert_thresholds_bps
         );
-
         Ok(())
     }
 
-    pub fn reject_submission(ctx: Context<RejectSubmission>, client_review: String) -> Result<()> {
-        let job_post = &ctx.accounts.job_post;
-        let application = &mut ctx.accounts.application;
+    // Client attaches one of their unsettled jobs to a project
+    pub fn attach_job_to_project(ctx: Context<AttachJobToProject>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let project = &mut ctx.accounts.project;
 
+        require!(job_post.project.is_none(), ErrorCode::JobInProject);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require_keys_eq!(project.namespace, job_post.namespace, ErrorCode::InvalidAccount);
         require!(
-            job_post.client == ctx.accounts.client.key(),
-            ErrorCode::Unauthorized
+            project.payment_mint == job_post.payment_mint,
+            ErrorCode::InvalidAccount
         );
-        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
-        require!(application.submitted, ErrorCode::WorkNotCompleted);
 
-        application.client_review = client_review;
-        application.rejected = true;
-        application.submitted = false; // Allow resubmission
+        job_post.project = Some(project.key());
+        project.jobs = project.jobs.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
 
-        msg!("❌ Work rejected. Feedback: {}", application.client_review);
+        msg!("📁 Job '{}' attached to project '{}'", job_post.title, project.name);
         Ok(())
     }
 
-    // Client cancels job and gets refund (only if no freelancer approved)
-    pub fn cancel_job(ctx: Context<CancelJob>) -> Result<()> {
+    // Client drafts a job paid in an SPL token. Nothing is escrowed yet: the client
+    // approves the job's escrow PDA as delegate for `amount`, then calls `publish_job`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_token_job_post(
+        ctx: Context<InitializeTokenJobPost>,
+        title: String,
+        description: String,
+        amount: u64,
+        start_date: i64,
+        end_date: i64,
+        symbol: String,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, false)?;
+        require!(
+            !symbol.is_empty() && symbol.len() <= MAX_SYMBOL_LEN,
+            ErrorCode::InvalidInput
+        );
+        require_feature(&ctx.accounts.platform_config, FEATURE_TOKENS)?;
+
+        // Amounts are in the mint's base units; a job can't escrow more than
+        // the mint has issued
+        let mint = &ctx.accounts.mint;
+        require!(mint.decimals <= MAX_TOKEN_DECIMALS, ErrorCode::InvalidMint);
+        require_ctx!(amount <= mint.supply, ErrorCode::InvalidAmount, "amount", mint.supply, amount);
+
         let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.payment_mint = Some(ctx.accounts.mint.key());
+        job_post.decimals = ctx.accounts.mint.decimals;
+        job_post.symbol = symbol;
+        job_post.published = false;
 
-        require!(
-            job_post.client == ctx.accounts.client.key(),
-            ErrorCode::Unauthorized
+        msg!(
+            "📝 Token job draft '{}' for {} {}. Approve {} as delegate, then publish.",
+            job_post.title,
+            amount,
+            job_post.symbol,
+            ctx.accounts.escrow.key()
         );
-        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
-        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        Ok(())
+    }
 
-        job_post.cancelled = true;
+    // Pull the job amount from the client's token account into the escrow vault,
+    // using the delegate approval the client granted to the escrow PDA. For
+    // Token-2022 mints with a transfer fee the client also covers the fee, so
+    // the vault receives the full job amount.
+    pub fn publish_job(ctx: Context<PublishJob>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_feature(&ctx.accounts.platform_config, FEATURE_TOKENS)?;
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(!job_post.published, ErrorCode::JobAlreadyPublished);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+
+        let mint = ctx.accounts.mint.to_account_info();
+        let gross = gross_for_net(&mint.try_borrow_data()?, Clock::get()?.epoch, job_post.amount.0)?;
+        let source = &ctx.accounts.client_token_account;
+        require!(
+            source.delegate == Some(ctx.accounts.escrow.key()).into()
+                && source.delegated_amount >= gross,
+            ErrorCode::DelegateApprovalMissing
+        );
 
-        // Refund client from escrow
         let job_post_key = job_post.key();
         let seeds = &[b"escrow", job_post_key.as_ref(), &[job_post.escrow_bump]];
-        let signer_seeds = &[&seeds[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: source.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.escrow_vault.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            gross,
+            job_post.decimals,
+        )?;
+        job_post.published = true;
+        if gross > job_post.amount.0 {
+            emit!(TransferFeeWithheld {
+                job_post: job_post_key,
+                recipient: ctx.accounts.escrow.key(),
+                gross: BaseUnits(gross),
+                fee: BaseUnits(gross - job_post.amount.0),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
 
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.escrow.to_account_info(),
-                to: ctx.accounts.client.to_account_info(),
-            },
-            signer_seeds,
-        );
-        system_program::transfer(cpi_ctx, job_post.amount)?;
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.jobs_posted = checked_add(operator_stats.jobs_posted, 1)?;
 
-        msg!("❌ Job cancelled and funds refunded to client");
+        emit!(JobPosted {
+            job_post: job_post_key,
+            client: job_post.client,
+            escrow: ctx.accounts.escrow_vault.key(),
+            amount: job_post.amount,
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post_key, None, DeadlineKind::JobEnd, job_post.end_date)?;
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!(
+            "✅ Job post published: '{}' for {} {}",
+            job_post.title,
+            job_post.amount,
+            job_post.symbol
+        );
         Ok(())
     }
 
-    // Fetch user statistics
-    pub fn get_user_stats(ctx: Context<GetUserStats>) -> Result<()> {
-        let stats = &ctx.accounts.user_stats;
-        msg!("📊 User Stats:");
-        msg!("Total Gigs Posted: {}", stats.total_gigs_posted);
-        msg!("Total Revenue Earned: {}", stats.total_revenue_earned);
-        msg!("Monthly Gigs: {}", stats.monthly_gigs);
-        msg!("Monthly Revenue: {}", stats.monthly_revenue);
-        msg!("Last Updated Month: {}", stats.last_updated_month);
+    // A treasury (typically a DAO's, signing through its governance program)
+    // posts a SOL bounty. Nobody is hired: anyone may claim it, and
+    // `threshold` of the `judges` voting for the same claim award it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_bounty(
+        ctx: Context<InitializeJobPost>,
+        title: String,
+        description: String,
+        amount: u64,
+        start_date: i64,
+        end_date: i64,
+        judges: Vec<Pubkey>,
+        threshold: u8,
+        governance: Option<GovernanceReceipt>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(
+            !judges.is_empty() && judges.len() <= MAX_BOUNTY_JUDGES,
+            ErrorCode::InvalidInput,
+            "judges",
+            MAX_BOUNTY_JUDGES,
+            judges.len()
+        );
+        require_ctx!(
+            threshold > 0 && usize::from(threshold) <= judges.len(),
+            ErrorCode::InvalidInput,
+            "threshold",
+            judges.len(),
+            threshold
+        );
+        for (i, judge) in judges.iter().enumerate() {
+            require!(
+                *judge != ctx.accounts.client.key() && !judges[..i].contains(judge),
+                ErrorCode::InvalidInput
+            );
+        }
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.governance = governance;
+        job_post.bounty = Some(BountyTerms {
+            judges,
+            threshold,
+            voted: 0,
+        });
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+        ctx.accounts.operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        if let Some(receipt) = governance {
+            msg!(
+                "🏛️ Authorized by proposal {} of realm {}",
+                receipt.proposal,
+                receipt.realm
+            );
+        }
+
+        msg!(
+            "🏆 Bounty posted: '{}' for {} lamports, {} of {} judges to award",
+            job_post.title,
+            amount,
+            threshold,
+            job_post.bounty.as_ref().map_or(0, |bounty| bounty.judges.len())
+        );
         Ok(())
     }
-}
+
+    // Anyone but the bounty's treasury and judges claims it with their work,
+    // until its end date. One claim per claimant.
+    pub fn submit_bounty_claim(ctx: Context<SubmitBountyClaim>, link: String) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        validate_link("link", &link)?;
+
+        let job_post = &ctx.accounts.job_post;
+        let bounty = job_post.bounty.as_ref().ok_or(ErrorCode::NotABounty)?;
+        let claimant = ctx.accounts.claimant.key();
+        require!(job_post.status() == JobStatus::Open, ErrorCode::BountyClosed);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now <= job_post.end_date, ErrorCode::BountyClosed);
+        require!(
+            claimant != job_post.client && !bounty.judges.contains(&claimant),
+            ErrorCode::SelfDealing
+        );
+
+        let claim = &mut ctx.accounts.claim;
+        claim.job_post = job_post.key();
+        claim.claimant = claimant;
+        claim.link = link;
+        claim.submitted_at = now;
+        claim.votes = 0;
+        claim.bump = ctx.bumps.claim;
+
+        emit!(BountyClaimSubmitted {
+            job_post: job_post.key(),
+            claim: claim.key(),
+            claimant,
+            timestamp: now,
+        });
+
+        msg!("📨 Bounty '{}' claimed by {}", job_post.title, claimant);
+        Ok(())
+    }
+
+    // A judge votes for the claim they think wins, once per bounty. The vote
+    // that brings a claim to the threshold pays its claimant, less the
+    // protocol fee, and settles the bounty.
+    pub fn vote_bounty_claim(ctx: Context<VoteBountyClaim>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.status() == JobStatus::Open, ErrorCode::BountyClosed);
+
+        let judge = ctx.accounts.judge.key();
+        let bounty = job_post.bounty.as_mut().ok_or(ErrorCode::NotABounty)?;
+        let seat = bounty
+            .judges
+            .iter()
+            .position(|key| *key == judge)
+            .ok_or(ErrorCode::Unauthorized)?;
+        require!(bounty.voted & (1 << seat) == 0, ErrorCode::AlreadyVoted);
+        bounty.voted |= 1 << seat;
+        let threshold = bounty.threshold;
+
+        let claim = &mut ctx.accounts.claim;
+        claim.votes = claim.votes.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+        emit!(BountyVoteCast {
+            job_post: job_post.key(),
+            claim: claim.key(),
+            judge,
+            votes: claim.votes,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        if claim.votes < threshold {
+            msg!("🗳️ Bounty claim of {} at {} of {} votes", claim.claimant, claim.votes, threshold);
+            return Ok(());
+        }
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        // The claim stands in for the application on a bounty
+        let hooks = SettlementHooks::load(&ctx.accounts.settlement_hooks)?;
+        let mut hook_payload = SettlementHookPayload {
+            job_post: job_post.key(),
+            application: claim.key(),
+            client: job_post.client,
+            freelancer: claim.claimant,
+            payment_mint: job_post.payment_mint,
+            gross: job_post.amount.0,
+            paid: 0,
+        };
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+            ctx.accounts.pre_hook_program.as_ref(),
+            "pre_settlement",
+            &hook_payload,
+            &[job_post.to_account_info(), claim.to_account_info()],
+        )?;
+
+        let fee = bps_of(job_post.amount.0, ctx.accounts.platform_config.fee_bps);
+        collect_fee(
+            &escrow,
+            &mut ctx.accounts.operator_stats,
+            ctx.accounts.insurance_pool.as_mut(),
+            None,
+            &ctx.accounts.platform_config,
+            fee,
+        )?;
+        let payout = job_post.amount.0 - fee;
+        require_independent_payee(job_post.client, claim.claimant, &ctx.accounts.payee_delegate)?;
+        escrow.pay(&ctx.accounts.claimant.to_account_info(), claim.claimant, payout)?;
+        record_project_spend(ctx.accounts.project.as_mut(), job_post, job_post.amount.0, true)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        job_post.freelancer = Some(claim.claimant);
+        job_post.hires_count = 1;
+        job_post.is_filled = true;
+        job_post.settled_at = now;
+        ctx.accounts.operator_stats.record_job_completed(job_post.amount.0)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        hook_payload.paid = payout;
+        job_post.exit(&crate::ID)?;
+        claim.exit(&crate::ID)?;
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+            ctx.accounts.post_hook_program.as_ref(),
+            "post_settlement",
+            &hook_payload,
+            &[job_post.to_account_info(), claim.to_account_info()],
+        )?;
+
+        emit!(BountyAwarded {
+            job_post: job_post.key(),
+            claim: claim.key(),
+            winner: claim.claimant,
+            payout: BaseUnits(payout),
+            fee: BaseUnits(fee),
+            timestamp: now,
+        });
+
+        msg!("🏆 Bounty '{}' awarded to {}", job_post.title, claim.claimant);
+        Ok(())
+    }
+
+    // Claimant closes their claim once the bounty is settled or cancelled
+    pub fn close_bounty_claim(ctx: Context<CloseBountyClaim>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(
+            job_post.settled_at != 0 || job_post.cancelled,
+            ErrorCode::ApplicationNotTerminal
+        );
+
+        msg!("🧹 Claim on bounty '{}' closed", job_post.title);
+        Ok(())
+    }
+
+    // Whitelisted fiat on-ramp funds the escrow for a client paying off-chain.
+    // The client stays the job authority, so refunds can only ever reach them.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_job_post_via_onramp(
+        ctx: Context<InitializeJobPostViaOnramp>,
+        title: String,
+        description: String,
+        amount: u64,
+        start_date: i64,
+        end_date: i64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            Some(ctx.accounts.onramp.key()),
+        ));
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.onramp.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        msg!(
+            "✅ Job post created via on-ramp {}: '{}' for {} lamports. Escrow: {}",
+            ctx.accounts.onramp.key(),
+            job_post.title,
+            amount,
+            escrow_key
+        );
+
+        Ok(())
+    }
+
+    // Freelancer applies to a job. The rent payer may be a relayer so freelancers
+    // with an empty wallet can still apply; the freelancer only signs as authority.
+    pub fn apply_to_job(
+        ctx: Context<ApplyToJob>,
+        resume_link: String,
+        expected_end_date: i64,
+        proposed_amount: Option<u64>,
+        screening_answers: Vec<[u8; 32]>,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        index_for_user(ctx.accounts.freelancer_index.as_mut(), ctx.accounts.application.key())?;
+        require!(ctx.accounts.job_post.bounty.is_none(), ErrorCode::BountyJob);
+        require!(!ctx.accounts.job_post.hidden, ErrorCode::JobHidden);
+        require_nda_acknowledged(
+            &ctx.accounts.job_post,
+            ctx.accounts.nda_acknowledgment.as_deref(),
+        )?;
+        require!(
+            ctx.accounts.job_post.visibility == JobVisibility::Public
+                || ctx.accounts.invitation.is_some(),
+            ErrorCode::NotInvited
+        );
+        // A profile stands in for the resume link
+        require_ctx!(
+            !resume_link.is_empty() || ctx.accounts.profile.is_some(),
+            ErrorCode::InvalidInput,
+            "resume_link",
+            1,
+            resume_link.len()
+        );
+        if !resume_link.is_empty() {
+            validate_link("resume_link", &resume_link)?;
+        }
+        require_ctx!(
+            expected_end_date >= 0,
+            ErrorCode::InvalidDates,
+            "expected_end_date",
+            0,
+            expected_end_date
+        );
+        require!(
+            !ctx.accounts.job_post.is_filled,
+            ErrorCode::JobAlreadyFilled
+        );
+        require!(!ctx.accounts.job_post.cancelled, ErrorCode::JobCancelled);
+        require!(ctx.accounts.job_post.published, ErrorCode::JobNotPublished);
+        require!(
+            ctx.accounts.job_post.accepts_applications(Clock::get()?.unix_timestamp),
+            ErrorCode::ApplicationsClosed
+        );
+        require!(
+            ctx.accounts.platform_config.allow_self_dealing
+                || ctx.accounts.freelancer.key() != ctx.accounts.job_post.client,
+            ErrorCode::SelfDealing
+        );
+
+        require!(
+            ctx.accounts.job_post.direct_offer.is_none(),
+            ErrorCode::DirectOfferJob
+        );
+        require!(
+            proposed_amount.is_none_or(|amount| amount > 0),
+            ErrorCode::InvalidAmount
+        );
+        // Neither party can refer the applicant and take part of the fee
+        require!(
+            referrer.is_none_or(|referrer| {
+                referrer != ctx.accounts.freelancer.key() && referrer != ctx.accounts.job_post.client
+            }),
+            ErrorCode::InvalidReferrer
+        );
+        let questions = ctx.accounts.job_post.screening_questions.len();
+        require_ctx!(
+            screening_answers.len() == questions,
+            ErrorCode::ScreeningAnswersMismatch,
+            "screening_answers",
+            questions,
+            screening_answers.len()
+        );
+
+        // Required languages the applicant's profile doesn't list; without a
+        // profile, all of them
+        let missing_languages: Vec<[u8; 2]> = ctx
+            .accounts
+            .job_post
+            .required_languages
+            .iter()
+            .filter(|code| {
+                ctx.accounts
+                    .freelancer_profile
+                    .as_ref()
+                    .is_none_or(|profile| !profile.languages.contains(code))
+            })
+            .copied()
+            .collect();
+        require_ctx!(
+            missing_languages.is_empty() || !ctx.accounts.platform_config.strict_language_match,
+            ErrorCode::LanguageRequirementNotMet,
+            "languages",
+            ctx.accounts.job_post.required_languages.len(),
+            ctx.accounts.job_post.required_languages.len() - missing_languages.len()
+        );
+
+        // Same for the applicant's UTC offset, treated as outside the range
+        // when they haven't declared one
+        let applicant_offset = ctx
+            .accounts
+            .freelancer_profile
+            .as_ref()
+            .and_then(|profile| profile.utc_offset_minutes);
+        let required_offsets = ctx.accounts.job_post.utc_offset_range;
+        let outside_offsets = required_offsets
+            .is_some_and(|range| !applicant_offset.is_some_and(|offset| range.contains(offset)));
+        require!(
+            !outside_offsets || !ctx.accounts.platform_config.strict_timezone_match,
+            ErrorCode::TimezoneOutsideRange
+        );
+
+        let application = &mut ctx.accounts.application;
+        application.set_inner(new_application(
+            ctx.accounts.freelancer.key(),
+            ctx.accounts.job_post.key(),
+            resume_link,
+            expected_end_date,
+            ctx.accounts.payer.key(),
+        ));
+        application.proposed_amount = proposed_amount;
+        application.screening_answers = screening_answers;
+        application.referrer = referrer;
+        application.profile = ctx.accounts.profile.as_ref().map(|profile| profile.key());
+        ctx.accounts.job_post.applications = ctx.accounts.job_post
+            .applications
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        ctx.accounts.job_post.applications_count =
+            ctx.accounts.job_post.applications_count.saturating_add(1);
+
+        // High-value listings, and jobs with their own stake, take a refundable
+        // stake with each application, held on the application until it is
+        // closed. A hired freelancer who abandons the job forfeits it.
+        let config = &ctx.accounts.platform_config;
+        let job_post = &ctx.accounts.job_post;
+        let stake = if job_post.application_stake > Lamports::ZERO {
+            job_post.application_stake.0
+        } else if !job_post.is_native() || job_post.amount.0 >= config.application_stake_threshold {
+            config.application_stake
+        } else {
+            0
+        };
+        if stake > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.freelancer.to_account_info(),
+                    to: application.to_account_info(),
+                },
+            );
+            system_program::transfer(cpi_ctx, stake)?;
+            application.stake = stake;
+        }
+
+        // First activity in the namespace starts the freelancer's account age
+        let freelancer_stats = &mut ctx.accounts.freelancer_stats;
+        if freelancer_stats.first_seen_at == 0 {
+            freelancer_stats.first_seen_at = Clock::get()?.unix_timestamp;
+        }
+
+        emit_deadline(
+            application.job_post,
+            Some(application.key()),
+            DeadlineKind::ExpectedDelivery,
+            expected_end_date,
+        )?;
+
+        emit!(ApplicationSubmitted {
+            job_post: application.job_post,
+            application: application.key(),
+            applicant: application.applicant,
+            expected_end_date,
+            proposed_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        if !missing_languages.is_empty() {
+            emit!(LanguageMismatch {
+                job_post: application.job_post,
+                application: application.key(),
+                applicant: application.applicant,
+                missing_languages,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+        if let Some(required_offsets) = required_offsets.filter(|_| outside_offsets) {
+            emit!(TimezoneMismatch {
+                job_post: application.job_post,
+                application: application.key(),
+                applicant: application.applicant,
+                required_offsets,
+                applicant_offset,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        msg!("📩 Application submitted by {}", application.applicant);
+        Ok(())
+    }
+
+    // Client hires a specific freelancer directly, funding the escrow up front.
+    // Unanswered offers can be refunded by anyone once `offer_expires_at` passes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_direct_offer(
+        ctx: Context<InitializeJobPost>,
+        title: String,
+        description: String,
+        amount: u64,
+        start_date: i64,
+        end_date: i64,
+        freelancer: Pubkey,
+        offer_expires_at: i64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        require!(
+            offer_expires_at > Clock::get()?.unix_timestamp && offer_expires_at <= end_date,
+            ErrorCode::InvalidDates
+        );
+        require_keys_neq!(freelancer, ctx.accounts.client.key(), ErrorCode::InvalidInput);
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.direct_offer = Some(DirectOffer {
+            freelancer,
+            expires_at: offer_expires_at,
+        });
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        emit_deadline(
+            job_post.key(),
+            None,
+            DeadlineKind::OfferExpiry,
+            offer_expires_at,
+        )?;
+
+        msg!(
+            "🤝 Direct offer '{}' for {} lamports sent to {}, expires at {}",
+            job_post.title,
+            amount,
+            freelancer,
+            offer_expires_at
+        );
+        Ok(())
+    }
+
+    // Invited freelancer accepts a direct offer, which fills the job immediately
+    pub fn accept_offer(
+        ctx: Context<AcceptOffer>,
+        resume_link: String,
+        expected_end_date: i64,
+    ) -> Result<()> {
+        index_for_user(ctx.accounts.freelancer_index.as_mut(), ctx.accounts.application.key())?;
+        let job_post = &mut ctx.accounts.job_post;
+        let offer = job_post.direct_offer.ok_or(ErrorCode::NotADirectOffer)?;
+
+        require_keys_eq!(
+            offer.freelancer,
+            ctx.accounts.freelancer.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            Clock::get()?.unix_timestamp < offer.expires_at,
+            ErrorCode::OfferExpired
+        );
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_ctx!(
+            expected_end_date >= 0,
+            ErrorCode::InvalidDates,
+            "expected_end_date",
+            0,
+            expected_end_date
+        );
+
+        let application = &mut ctx.accounts.application;
+        application.set_inner(new_application(
+            offer.freelancer,
+            job_post.key(),
+            resume_link,
+            expected_end_date,
+            ctx.accounts.payer.key(),
+        ));
+        application.approved = true;
+        application.approved_at = Clock::get()?.unix_timestamp;
+        job_post.applications = job_post
+            .applications
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        job_post.is_filled = true;
+        job_post.hires_count = 1;
+        job_post.freelancer = Some(offer.freelancer);
+        track_engagement(ctx.accounts.freelancer_profile.as_mut(), offer.freelancer, true)?;
+
+        emit_deadline(
+            job_post.key(),
+            Some(application.key()),
+            DeadlineKind::ExpectedDelivery,
+            expected_end_date,
+        )?;
+        emit!(ApplicationApproved {
+            job_post: job_post.key(),
+            application: application.key(),
+            client: job_post.client,
+            freelancer: offer.freelancer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!("🤝 Direct offer '{}' accepted by {}", job_post.title, offer.freelancer);
+        Ok(())
+    }
+
+    // Permissionless crank: refund the client once a direct offer expires unanswered
+    pub fn expire_offer(ctx: Context<ExpireOffer>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let offer = job_post.direct_offer.ok_or(ErrorCode::NotADirectOffer)?;
+
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= offer.expires_at, ErrorCode::OfferNotExpired);
+
+        job_post.cancelled = true;
+        job_post.settled_at = now;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        let client = ctx.accounts.client.to_account_info();
+        let refund = job_post.amount.0;
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+
+        ctx.accounts.operator_stats.jobs_cancelled =
+            checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        emit!(JobCancelled {
+            job_post: job_post.key(),
+            client: job_post.client,
+            refund: job_post.amount,
+            timestamp: now,
+        });
+
+        msg!("⌛ Direct offer '{}' expired and was refunded", job_post.title);
+        Ok(())
+    }
+
+    // Client posts a retainer: `periods` periods of `period_seconds`, each paid
+    // `period_amount`, all escrowed up front. The hired freelancer claims each
+    // period's pay once it has run.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_retainer_job(
+        ctx: Context<InitializeJobPost>,
+        title: String,
+        description: String,
+        period_amount: u64,
+        period_seconds: i64,
+        periods: u16,
+        start_date: i64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        require_ctx!(period_seconds > 0, ErrorCode::InvalidInput, "period_seconds", 1, period_seconds);
+        require_ctx!(
+            (1..=MAX_RETAINER_PERIODS).contains(&periods),
+            ErrorCode::InvalidInput,
+            "periods",
+            MAX_RETAINER_PERIODS,
+            periods
+        );
+        let amount = period_amount
+            .checked_mul(u64::from(periods))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let end_date = period_seconds
+            .checked_mul(i64::from(periods))
+            .and_then(|length| start_date.checked_add(length))
+            .ok_or(ErrorCode::InvalidDates)?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.retainer = Some(RetainerTerms {
+            period_amount,
+            period_seconds,
+            periods,
+            periods_paid: 0,
+            started_at: 0,
+        });
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        msg!(
+            "📆 Retainer '{}' posted: {} periods of {}s at {} lamports",
+            job_post.title,
+            periods,
+            period_seconds,
+            period_amount
+        );
+        Ok(())
+    }
+
+    // Retained freelancer collects one period's pay once that period has run;
+    // periods left unclaimed can be collected later, one call each
+    pub fn claim_period_payment(ctx: Context<ClaimPeriodPayment>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &mut ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let retainer = job_post.retainer.as_mut().ok_or(ErrorCode::NotARetainer)?;
+        require!(
+            retainer.periods_due(now) > retainer.periods_paid,
+            ErrorCode::PeriodNotDue
+        );
+        retainer.periods_paid = retainer
+            .periods_paid
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let period = retainer.periods_paid;
+        let amount = retainer.period_amount;
+        let last_period = retainer.periods_paid == retainer.periods;
+        check_release(&ctx.accounts.platform_config, job_post, application, None, amount)?;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        require!(escrow.balance()? >= amount, ErrorCode::InsufficientEscrowBalance);
+
+        // Each period pays the fee and withholding like a milestone
+        let split = release_partial(
+            &escrow,
+            job_post,
+            application,
+            ReleaseAccounts {
+                config: &ctx.accounts.platform_config,
+                operator_stats: &mut ctx.accounts.operator_stats,
+                insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                fee_treasury: None,
+                tax_vault: ctx.accounts.tax_vault.as_ref(),
+                payout_guardian: Some(&ctx.accounts.payout_guardian),
+                freelancer: &ctx.accounts.applicant,
+                parking: None,
+                settlement_hooks: &ctx.accounts.settlement_hooks,
+                pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                project: ctx.accounts.project.as_mut(),
+            },
+            amount,
+            "Period",
+        )?;
+        let (fee, withheld) = (split.fee, split.withheld);
+
+        if last_period {
+            application.completed = true;
+            job_post.settle_seat(now)?;
+            let (year, month) = current_period()?;
+            ctx.accounts
+                .freelancer_stats
+                .record_job_completed(year, month, job_post.amount.0)?;
+            ctx.accounts.operator_stats.record_job_completed(job_post.amount.0)?;
+        }
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(PeriodPaid {
+            job_post: job_post.key(),
+            application: application.key(),
+            period,
+            amount: BaseUnits(amount),
+            fee: BaseUnits(fee),
+            withheld: BaseUnits(withheld),
+            timestamp: now,
+        });
+
+        msg!(
+            "📆 Period {} of retainer '{}' paid: {} lamports",
+            period,
+            job_post.title,
+            amount
+        );
+        Ok(())
+    }
+
+    // Client ends a retainer early, taking back the pay of periods that haven't
+    // started running yet. Periods already run stay claimable by the freelancer.
+    pub fn cancel_retainer(ctx: Context<CancelRetainer>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+
+        let now = Clock::get()?.unix_timestamp;
+        let retainer = job_post.retainer.as_mut().ok_or(ErrorCode::NotARetainer)?;
+        // Before a hire the whole job can simply be cancelled
+        require!(retainer.started_at != 0, ErrorCode::RetainerNotStarted);
+        let kept = retainer.periods_due(now).max(retainer.periods_paid);
+        require!(kept < retainer.periods, ErrorCode::NoFuturePeriods);
+
+        let refund = retainer
+            .period_amount
+            .checked_mul(u64::from(retainer.periods - kept))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        retainer.periods = kept;
+        let fully_paid = retainer.periods_paid == kept;
+        job_post.amount = job_post.amount.checked_sub(BaseUnits(refund))?;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        let client = ctx.accounts.client.to_account_info();
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+        if fully_paid {
+            job_post.settle_seat(now)?;
+        }
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(RetainerCancelled {
+            job_post: job_post.key(),
+            periods_kept: kept,
+            refund: BaseUnits(refund),
+            timestamp: now,
+        });
+
+        msg!(
+            "📆 Retainer '{}' cut to {} periods; {} lamports refunded",
+            job_post.title,
+            kept,
+            refund
+        );
+        Ok(())
+    }
+
+    // Client posts an hourly job: up to `max_hours` at `hourly_rate`, the full
+    // budget escrowed up front. The hired freelancer logs hours as timesheet
+    // entries and is paid for each one the client approves.
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_hourly_job(
+        ctx: Context<InitializeJobPost>,
+        title: String,
+        description: String,
+        hourly_rate: u64,
+        max_hours: u32,
+        start_date: i64,
+        end_date: i64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        require_ctx!(hourly_rate > 0, ErrorCode::InvalidAmount, "hourly_rate", 1, hourly_rate);
+        require_ctx!(
+            (1..=MAX_HOURLY_HOURS).contains(&max_hours),
+            ErrorCode::InvalidInput,
+            "max_hours",
+            MAX_HOURLY_HOURS,
+            max_hours
+        );
+        let amount = hourly_rate
+            .checked_mul(u64::from(max_hours))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.hourly = Some(HourlyTerms {
+            hourly_rate,
+            max_hours,
+            hours_logged: 0,
+            hours_approved: 0,
+            entries: 0,
+        });
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        msg!(
+            "⏱️ Hourly job '{}' posted: up to {} hours at {} lamports",
+            job_post.title,
+            max_hours,
+            hourly_rate
+        );
+        Ok(())
+    }
+
+    // Hired freelancer logs hours worked as the job's next timesheet entry.
+    // Logged hours never exceed the job's `max_hours`, so every entry the
+    // client approves is covered by the escrow.
+    pub fn log_hours(
+        ctx: Context<LogHours>,
+        hours: u32,
+        note: String,
+        nonce: Option<u64>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(hours > 0, ErrorCode::InvalidInput, "hours", 1, hours);
+        require_ctx!(
+            note.len() <= MAX_TIMESHEET_NOTE_LEN,
+            ErrorCode::InvalidInput,
+            "note",
+            MAX_TIMESHEET_NOTE_LEN,
+            note.len()
+        );
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.recent_nonces.consume(nonce)?;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        let application = &ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+
+        let hourly = job_post.hourly.as_mut().ok_or(ErrorCode::NotHourly)?;
+        let hours_logged = hourly
+            .hours_logged
+            .checked_add(hours)
+            .filter(|logged| *logged <= hourly.max_hours)
+            .ok_or(ErrorCode::HoursExceeded)?;
+        hourly.hours_logged = hours_logged;
+        let index = hourly.entries;
+        hourly.entries = hourly.entries.checked_add(1).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let entry = &mut ctx.accounts.timesheet_entry;
+        entry.job_post = job_post.key();
+        entry.application = application.key();
+        entry.freelancer = application.applicant;
+        entry.index = index;
+        entry.hours = hours;
+        entry.note = note;
+        entry.logged_at = now;
+        entry.approved = false;
+        entry.bump = ctx.bumps.timesheet_entry;
+
+        emit!(HoursLogged {
+            job_post: job_post.key(),
+            application: application.key(),
+            entry: entry.key(),
+            hours,
+            hours_logged,
+            timestamp: now,
+        });
+
+        msg!(
+            "⏱️ {} hours logged on '{}' ({} in total)",
+            hours,
+            job_post.title,
+            hours_logged
+        );
+        Ok(())
+    }
+
+    // Client approves a timesheet entry, releasing `hours * hourly_rate` from
+    // the escrow less the fee and withholding, like a retainer period
+    pub fn approve_timesheet(ctx: Context<ApproveTimesheet>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        let application = &ctx.accounts.application;
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+        let entry = &mut ctx.accounts.timesheet_entry;
+        require!(!entry.approved, ErrorCode::TimesheetAlreadyApproved);
+
+        let hourly = job_post.hourly.as_mut().ok_or(ErrorCode::NotHourly)?;
+        let amount = hourly
+            .hourly_rate
+            .checked_mul(u64::from(entry.hours))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        hourly.hours_approved = hourly
+            .hours_approved
+            .checked_add(entry.hours)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        entry.approved = true;
+        check_release(
+            &ctx.accounts.platform_config,
+            job_post,
+            application,
+            Some(&ctx.accounts.release_limit),
+            amount,
+        )?;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        require!(escrow.balance()? >= amount, ErrorCode::InsufficientEscrowBalance);
+
+        let split = release_partial(
+            &escrow,
+            job_post,
+            application,
+            ReleaseAccounts {
+                config: &ctx.accounts.platform_config,
+                operator_stats: &mut ctx.accounts.operator_stats,
+                insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                fee_treasury: None,
+                tax_vault: ctx.accounts.tax_vault.as_ref(),
+                payout_guardian: Some(&ctx.accounts.payout_guardian),
+                freelancer: &ctx.accounts.applicant,
+                parking: None,
+                settlement_hooks: &ctx.accounts.settlement_hooks,
+                pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                project: ctx.accounts.project.as_mut(),
+            },
+            amount,
+            "Timesheet",
+        )?;
+        let (fee, withheld) = (split.fee, split.withheld);
+
+        emit!(TimesheetApproved {
+            job_post: job_post.key(),
+            application: application.key(),
+            entry: entry.key(),
+            hours: entry.hours,
+            amount: BaseUnits(amount),
+            fee: BaseUnits(fee),
+            withheld: BaseUnits(withheld),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "⏱️ {} hours on '{}' approved: {} lamports",
+            entry.hours,
+            job_post.title,
+            amount
+        );
+        Ok(())
+    }
+
+    // Client turns down a timesheet entry. Its hours no longer count against
+    // `max_hours` and its rent goes back to the freelancer.
+    pub fn reject_timesheet(ctx: Context<RejectTimesheet>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        let entry = &ctx.accounts.timesheet_entry;
+        require!(!entry.approved, ErrorCode::TimesheetAlreadyApproved);
+
+        let hourly = job_post.hourly.as_mut().ok_or(ErrorCode::NotHourly)?;
+        hourly.hours_logged = hourly
+            .hours_logged
+            .checked_sub(entry.hours)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        msg!("⏱️ {} hours on '{}' rejected", entry.hours, job_post.title);
+        Ok(())
+    }
+
+    // Client ends an hourly job once every logged entry is approved or
+    // rejected. The hired freelancer is done and the budget left over from
+    // unlogged hours is refunded to the client.
+    pub fn close_hourly_job(ctx: Context<CloseHourlyJob>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        let application = &mut ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::ApplicationNotApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+
+        let hourly = job_post.hourly.ok_or(ErrorCode::NotHourly)?;
+        require!(
+            hourly.hours_logged == hourly.hours_approved,
+            ErrorCode::TimesheetsPending
+        );
+        let released = job_post.milestones_released.0;
+        let refund = checked_sub(job_post.amount.0, released)?;
+        if refund > 0 {
+            let escrow = EscrowSource::new(
+                job_post,
+                &ctx.accounts.escrow,
+                &ctx.accounts.system_program,
+                None,
+                None,
+                None,
+            )?;
+            let client = ctx.accounts.client.to_account_info();
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+        }
+        job_post.amount = BaseUnits(released);
+
+        let now = Clock::get()?.unix_timestamp;
+        application.completed = true;
+        job_post.settle_seat(now)?;
+        let (year, month) = current_period()?;
+        ctx.accounts
+            .freelancer_stats
+            .record_job_completed(year, month, released)?;
+        ctx.accounts.operator_stats.record_job_completed(released)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(HourlyJobClosed {
+            job_post: job_post.key(),
+            application: application.key(),
+            hours_approved: hourly.hours_approved,
+            released: BaseUnits(released),
+            refund: BaseUnits(refund),
+            timestamp: now,
+        });
+
+        msg!(
+            "⏱️ Hourly job '{}' closed after {} hours; {} lamports refunded",
+            job_post.title,
+            hourly.hours_approved,
+            refund
+        );
+        Ok(())
+    }
+
+    // Freelancer closes an approved timesheet entry, or any entry once the
+    // job is settled, taking back its rent
+    pub fn close_timesheet_entry(ctx: Context<CloseTimesheetEntry>) -> Result<()> {
+        let entry = &ctx.accounts.timesheet_entry;
+        require!(
+            entry.approved || ctx.accounts.job_post.settled_at != 0,
+            ErrorCode::ApplicationNotTerminal
+        );
+
+        msg!("🧹 Timesheet entry {} closed", entry.index);
+        Ok(())
+    }
+
+    // Freelancer lists a block of `hours` at `hourly_rate` for one client to
+    // buy, usable for `validity` seconds from purchase
+    pub fn list_hour_block(
+        ctx: Context<ListHourBlock>,
+        block_id: u64,
+        hours: u32,
+        hourly_rate: u64,
+        validity: i64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(hourly_rate > 0, ErrorCode::InvalidAmount, "hourly_rate", 1, hourly_rate);
+        require_ctx!(
+            (1..=MAX_HOURLY_HOURS).contains(&hours),
+            ErrorCode::InvalidInput,
+            "hours",
+            MAX_HOURLY_HOURS,
+            hours
+        );
+        require_ctx!(validity > 0, ErrorCode::InvalidDates, "validity", 1, validity);
+
+        let hour_block = &mut ctx.accounts.hour_block;
+        hour_block.namespace = ctx.accounts.platform_config.namespace;
+        hour_block.freelancer = ctx.accounts.freelancer.key();
+        hour_block.block_id = block_id;
+        hour_block.hours = hours;
+        hour_block.hourly_rate = hourly_rate;
+        hour_block.validity = validity;
+        hour_block.client = None;
+        hour_block.expires_at = 0;
+        hour_block.hours_consumed = 0;
+        hour_block.entries = 0;
+        hour_block.bump = ctx.bumps.hour_block;
+        // Rejects a block whose price doesn't fit in a u64 before anyone buys it
+        hour_block.price()?;
+
+        emit!(HourBlockListed {
+            hour_block: hour_block.key(),
+            freelancer: hour_block.freelancer,
+            hours,
+            hourly_rate: Lamports(hourly_rate),
+            validity,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🧱 Block of {} hours listed at {} lamports an hour", hours, hourly_rate);
+        Ok(())
+    }
+
+    // Freelancer takes down a block nobody has bought, taking back its rent
+    pub fn delist_hour_block(ctx: Context<DelistHourBlock>) -> Result<()> {
+        require!(ctx.accounts.hour_block.client.is_none(), ErrorCode::HourBlockSold);
+
+        msg!("🧹 Hour block {} delisted", ctx.accounts.hour_block.block_id);
+        Ok(())
+    }
+
+    // Client buys a listed block, paying its full price into the block's
+    // account, which then holds it in escrow until hours are consumed or the
+    // block expires
+    pub fn purchase_hour_block(ctx: Context<PurchaseHourBlock>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let hour_block = &mut ctx.accounts.hour_block;
+        require!(hour_block.client.is_none(), ErrorCode::HourBlockSold);
+        let client = ctx.accounts.client.key();
+        require_keys_neq!(client, hour_block.freelancer, ErrorCode::SelfDealing);
+
+        let price = hour_block.price()?;
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.client.to_account_info(),
+                to: hour_block.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, price)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        hour_block.client = Some(client);
+        hour_block.expires_at = now
+            .checked_add(hour_block.validity)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        emit!(HourBlockPurchased {
+            hour_block: hour_block.key(),
+            client,
+            freelancer: hour_block.freelancer,
+            amount: Lamports(price),
+            expires_at: hour_block.expires_at,
+            timestamp: now,
+        });
+
+        msg!(
+            "🧱 {} bought {} hours from {} for {} lamports",
+            client,
+            hour_block.hours,
+            hour_block.freelancer,
+            price
+        );
+        Ok(())
+    }
+
+    // Client and freelancer co-sign an entry drawing `hours` down from a
+    // purchased block, paying `hours * hourly_rate` to the freelancer less
+    // the fee and withholding, between the namespace's settlement hooks.
+    // `note_hash` commits to the entry's off-chain description.
+    pub fn consume_hours(ctx: Context<ConsumeHours>, hours: u32, note_hash: [u8; 32]) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(hours > 0, ErrorCode::InvalidInput, "hours", 1, hours);
+        let hour_block = &mut ctx.accounts.hour_block;
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < hour_block.expires_at, ErrorCode::HourBlockExpired);
+        require!(hours <= hour_block.hours_left(), ErrorCode::HoursExceeded);
+        require_independent_payee(
+            ctx.accounts.client.key(),
+            hour_block.freelancer,
+            &ctx.accounts.payee_delegate,
+        )?;
+
+        let amount = hour_block
+            .hourly_rate
+            .checked_mul(u64::from(hours))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        hour_block.hours_consumed = hour_block
+            .hours_consumed
+            .checked_add(hours)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let entry = hour_block.entries;
+        hour_block.entries = hour_block
+            .entries
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let hooks = SettlementHooks::load(&ctx.accounts.settlement_hooks)?;
+        let mut hook_payload = SettlementHookPayload {
+            job_post: hour_block.key(),
+            application: Pubkey::default(),
+            client: ctx.accounts.client.key(),
+            freelancer: hour_block.freelancer,
+            payment_mint: None,
+            gross: amount,
+            paid: 0,
+        };
+        let hook_accounts = [hour_block.to_account_info()];
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+            ctx.accounts.pre_hook_program.as_ref(),
+            "pre_settlement",
+            &hook_payload,
+            &hook_accounts,
+        )?;
+
+        // Each entry pays the fee and withholding like a milestone
+        let config = &ctx.accounts.platform_config;
+        let split = split_payout(amount, config.fee_bps, config.withholding_bps, 0);
+        let source = hour_block.to_account_info();
+        collect_fee_from(
+            &source,
+            &mut ctx.accounts.operator_stats,
+            ctx.accounts.insurance_pool.as_mut(),
+            config,
+            split.fee,
+        )?;
+        if split.withheld > 0 {
+            let tax_vault = ctx
+                .accounts
+                .tax_vault
+                .as_ref()
+                .ok_or(ErrorCode::InvalidAccount)?;
+            require_keys_eq!(tax_vault.key(), config.tax_vault, ErrorCode::InvalidAccount);
+            source.sub_lamports(split.withheld)?;
+            tax_vault.add_lamports(split.withheld)?;
+        }
+        source.sub_lamports(split.net)?;
+        ctx.accounts.freelancer.add_lamports(split.net)?;
+
+        hook_payload.paid = split.net;
+        ctx.accounts.hour_block.exit(&crate::ID)?;
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+            ctx.accounts.post_hook_program.as_ref(),
+            "post_settlement",
+            &hook_payload,
+            &hook_accounts,
+        )?;
+
+        let hour_block = &ctx.accounts.hour_block;
+        emit!(HoursConsumed {
+            hour_block: hour_block.key(),
+            client: ctx.accounts.client.key(),
+            freelancer: hour_block.freelancer,
+            entry,
+            hours,
+            amount: Lamports(amount),
+            fee: Lamports(split.fee),
+            withheld: Lamports(split.withheld),
+            note_hash,
+            hours_left: hour_block.hours_left(),
+            timestamp: now,
+        });
+
+        msg!(
+            "🧱 {} hours consumed, {} left; {} lamports paid",
+            hours,
+            hour_block.hours_left(),
+            split.net
+        );
+        Ok(())
+    }
+
+    // Client takes back the price of unused hours once the block has expired
+    // or been used up. The block closes and its rent returns to the freelancer.
+    pub fn refund_hour_block(ctx: Context<RefundHourBlock>) -> Result<()> {
+        let hour_block = &ctx.accounts.hour_block;
+        let now = Clock::get()?.unix_timestamp;
+        let hours_unused = hour_block.hours_left();
+        require!(
+            now >= hour_block.expires_at || hours_unused == 0,
+            ErrorCode::HourBlockNotExpired
+        );
+
+        let refund = hour_block
+            .hourly_rate
+            .checked_mul(u64::from(hours_unused))
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if refund > 0 {
+            ctx.accounts.hour_block.sub_lamports(refund)?;
+            ctx.accounts.client.add_lamports(refund)?;
+        }
+
+        emit!(HourBlockRefunded {
+            hour_block: ctx.accounts.hour_block.key(),
+            client: ctx.accounts.client.key(),
+            freelancer: ctx.accounts.freelancer.key(),
+            hours_unused,
+            refund: Lamports(refund),
+            timestamp: now,
+        });
+
+        msg!("🧱 {} unused hours refunded: {} lamports", hours_unused, refund);
+        Ok(())
+    }
+
+    // Client switches an open SOL job to streaming pay: once a freelancer is
+    // hired, the amount vests by the second between the job's start and end
+    // dates and can be withdrawn at any time
+    pub fn set_streaming(ctx: Context<SetMaxHires>, enabled: bool) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.hires_count == 0, ErrorCode::JobAlreadyFilled);
+        require!(job_post.is_native(), ErrorCode::NativeOnly);
+        require!(
+            job_post.repricing.is_none()
+                && job_post.retainer.is_none()
+                && job_post.hourly.is_none()
+                && !job_post.trial,
+            ErrorCode::PriceLocked
+        );
+        require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+        require!(job_post.credits_applied == Lamports::ZERO, ErrorCode::CreditFunded);
+        require!(
+            JobMilestones::load(&ctx.accounts.job_milestones)?.is_none(),
+            ErrorCode::StreamingJob
+        );
+
+        job_post.stream = enabled.then(PaymentStream::default);
+
+        msg!("🚰 Job '{}' streaming pay: {}", job_post.title, enabled);
+        Ok(())
+    }
+
+    // Hired freelancer pulls whatever has vested since their last withdrawal
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &mut ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let vested = job_post.vested_amount(now).ok_or(ErrorCode::NotStreaming)?;
+        let total = job_post.amount;
+        let stream = job_post.stream.as_mut().ok_or(ErrorCode::NotStreaming)?;
+        let amount = vested.saturating_sub(stream.released_so_far);
+        require!(amount > BaseUnits::ZERO, ErrorCode::NothingVested);
+        stream.released_so_far = stream.released_so_far.checked_add(amount)?;
+        let fully_released = stream.released_so_far == total;
+        check_release(&ctx.accounts.platform_config, job_post, application, None, amount.0)?;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        require!(escrow.balance()? >= amount.0, ErrorCode::InsufficientEscrowBalance);
+
+        // Each withdrawal pays the fee and withholding like a milestone
+        let split = release_partial(
+            &escrow,
+            job_post,
+            application,
+            ReleaseAccounts {
+                config: &ctx.accounts.platform_config,
+                operator_stats: &mut ctx.accounts.operator_stats,
+                insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                fee_treasury: None,
+                tax_vault: ctx.accounts.tax_vault.as_ref(),
+                payout_guardian: Some(&ctx.accounts.payout_guardian),
+                freelancer: &ctx.accounts.applicant,
+                parking: None,
+                settlement_hooks: &ctx.accounts.settlement_hooks,
+                pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                project: ctx.accounts.project.as_mut(),
+            },
+            amount.0,
+            "Vested",
+        )?;
+        let (fee, withheld) = (split.fee, split.withheld);
+
+        if fully_released {
+            application.completed = true;
+            job_post.settle_seat(now)?;
+            let (year, month) = current_period()?;
+            ctx.accounts
+                .freelancer_stats
+                .record_job_completed(year, month, job_post.amount.0)?;
+            ctx.accounts.operator_stats.record_job_completed(job_post.amount.0)?;
+        }
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(VestedWithdrawn {
+            job_post: job_post.key(),
+            application: application.key(),
+            amount,
+            released_so_far: vested,
+            fee: BaseUnits(fee),
+            withheld: BaseUnits(withheld),
+            timestamp: now,
+        });
+
+        msg!(
+            "🚰 {} lamports vested from '{}' withdrawn",
+            amount,
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Client stops a stream and takes back what hasn't vested yet; what has
+    // vested stays withdrawable by the freelancer
+    pub fn cancel_stream(ctx: Context<CancelRetainer>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.hires_count > 0, ErrorCode::StreamNotStarted);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+
+        let now = Clock::get()?.unix_timestamp;
+        let vested = job_post.vested_amount(now).ok_or(ErrorCode::NotStreaming)?;
+        let refund = job_post.amount.checked_sub(vested)?;
+        require!(refund > BaseUnits::ZERO, ErrorCode::StreamFullyVested);
+        let start_date = job_post.start_date;
+        let stream = job_post.stream.as_mut().ok_or(ErrorCode::NotStreaming)?;
+        require!(stream.stopped_at == 0, ErrorCode::StreamStopped);
+        stream.stopped_at = now.max(start_date);
+        let fully_released = stream.released_so_far == vested;
+        job_post.amount = vested;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        let client = ctx.accounts.client.to_account_info();
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund.0)?;
+        if fully_released {
+            job_post.settle_seat(now)?;
+        }
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(StreamCancelled {
+            job_post: job_post.key(),
+            vested,
+            refund,
+            timestamp: now,
+        });
+
+        msg!(
+            "🚰 Stream on '{}' stopped at {} lamports vested; {} refunded",
+            job_post.title,
+            vested,
+            refund
+        );
+        Ok(())
+    }
+
+    // Client hires a freelancer for a short paid trial: the operator's fixed trial
+    // amount, offered directly, expiring TRIAL_DURATION after creation
+    pub fn initialize_trial_job(
+        ctx: Context<InitializeJobPost>,
+        title: String,
+        description: String,
+        freelancer: Pubkey,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        let amount = ctx.accounts.platform_config.trial_amount;
+        require!(amount > 0, ErrorCode::TrialsDisabled);
+        require_keys_neq!(freelancer, ctx.accounts.client.key(), ErrorCode::InvalidInput);
+
+        let now = Clock::get()?.unix_timestamp;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date: now,
+            end_date: now + TRIAL_DURATION,
+        };
+        validate_job_terms(&spec)?;
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.direct_offer = Some(DirectOffer {
+            freelancer,
+            expires_at: job_post.end_date,
+        });
+        job_post.trial = true;
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: now,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        emit_deadline(
+            job_post.key(),
+            None,
+            DeadlineKind::OfferExpiry,
+            job_post.end_date,
+        )?;
+
+        msg!(
+            "🧪 Trial '{}' for {} lamports offered to {}, expires at {}",
+            job_post.title,
+            amount,
+            freelancer,
+            job_post.end_date
+        );
+        Ok(())
+    }
+
+    // Permissionless crank: refund a trial once it expires without submitted work
+    pub fn expire_trial(ctx: Context<ExpireTrial>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+
+        require!(job_post.trial, ErrorCode::NotATrial);
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= job_post.end_date, ErrorCode::OfferNotExpired);
+
+        // Once the freelancer has accepted, only an unsubmitted trial can lapse
+        if job_post.is_filled {
+            let application = ctx
+                .accounts
+                .application
+                .as_ref()
+                .ok_or(ErrorCode::InvalidAccount)?;
+            require!(
+                application.job_post == job_post.key(),
+                ErrorCode::InvalidAccount
+            );
+            require!(!application.submitted, ErrorCode::WorkAlreadySubmitted);
+        }
+
+        job_post.cancelled = true;
+        job_post.settled_at = now;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        let client = ctx.accounts.client.to_account_info();
+        let refund = job_post.amount.0;
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+
+        ctx.accounts.operator_stats.jobs_cancelled =
+            checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        emit!(JobCancelled {
+            job_post: job_post.key(),
+            client: job_post.client,
+            refund: job_post.amount,
+            timestamp: now,
+        });
+
+        msg!("⌛ Trial '{}' expired and was refunded", job_post.title);
+        Ok(())
+    }
+
+    // Client turns a completed trial into a full, funded job already filled by
+    // the same freelancer
+    pub fn convert_trial_to_job(
+        ctx: Context<ConvertTrialToJob>,
+        title: String,
+        description: String,
+        amount: u64,
+        start_date: i64,
+        end_date: i64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        ctx.accounts.job_counter.record_post(ctx.accounts.client.key(), ctx.bumps.job_counter)?;
+        index_for_user(ctx.accounts.client_index.as_mut(), ctx.accounts.job_post.key())?;
+        index_for_user(ctx.accounts.freelancer_index.as_mut(), ctx.accounts.application.key())?;
+        let spec = JobSpec {
+            title,
+            description,
+            amount,
+            start_date,
+            end_date,
+        };
+        validate_job_terms(&spec)?;
+        validate_job_bounds(&ctx.accounts.platform_config, &spec, true)?;
+
+        let trial_job = &mut ctx.accounts.trial_job;
+        require!(trial_job.trial, ErrorCode::NotATrial);
+        require!(trial_job.converted_to.is_none(), ErrorCode::TrialAlreadyConverted);
+        require!(
+            ctx.accounts.trial_application.completed,
+            ErrorCode::TrialNotCompleted
+        );
+
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.set_inner(new_job_post(
+            ctx.accounts.platform_config.namespace,
+            ctx.accounts.client.key(),
+            spec,
+            ctx.bumps.escrow,
+            None,
+        ));
+        job_post.funding_price = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?;
+        job_post.is_filled = true;
+        job_post.hires_count = 1;
+        job_post.freelancer = Some(ctx.accounts.freelancer.key());
+        trial_job.converted_to = Some(job_post.key());
+
+        let trial_application = &ctx.accounts.trial_application;
+        let application = &mut ctx.accounts.application;
+        application.set_inner(new_application(
+            trial_application.applicant,
+            job_post.key(),
+            trial_application.resume_link.clone(),
+            end_date,
+            ctx.accounts.client.key(),
+        ));
+        application.approved = true;
+        application.approved_at = Clock::get()?.unix_timestamp;
+        job_post.applications = job_post
+            .applications
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let escrow_key = ctx.accounts.escrow.key();
+        job_post.escrow_rent = fund_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            job_post.key(),
+            ctx.bumps.escrow,
+            amount,
+        )?;
+
+        record_gig_posted(
+            &mut ctx.accounts.client_stats,
+            ctx.accounts.client_monthly_stats.as_mut(),
+        )?;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.record_job_posted(amount)?;
+
+        emit!(JobPosted {
+            job_post: job_post.key(),
+            client: job_post.client,
+            escrow: escrow_key,
+            amount: BaseUnits(amount),
+            decimals: job_post.decimals,
+            symbol: job_post.symbol.clone(),
+            review_window_days: job_post.review_window(&ctx.accounts.platform_config),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, job_post.end_date)?;
+
+        emit_deadline(
+            job_post.key(),
+            Some(application.key()),
+            DeadlineKind::ExpectedDelivery,
+            end_date,
+        )?;
+        emit!(ApplicationApproved {
+            job_post: job_post.key(),
+            application: application.key(),
+            client: job_post.client,
+            freelancer: application.applicant,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "🎓 Trial '{}' converted into job '{}' for {}",
+            trial_job.title,
+            job_post.title,
+            application.applicant
+        );
+        Ok(())
+    }
+
+    // Client requires applicants to acknowledge an NDA, identified by its hash,
+    // before applying. `None` drops the requirement.
+    pub fn set_job_nda(ctx: Context<SetJobNda>, nda_hash: Option<[u8; 32]>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+
+        job_post.nda_hash = nda_hash;
+
+        msg!("🔏 NDA for job '{}' set: {}", job_post.title, nda_hash.is_some());
+        Ok(())
+    }
+
+    // Client sets hashes of up to MAX_SCREENING_QUESTIONS questions that every
+    // application must answer with a hash, before anyone has applied
+    pub fn set_screening_questions(
+        ctx: Context<SetJobNda>,
+        questions: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+        require_ctx!(
+            questions.len() <= MAX_SCREENING_QUESTIONS,
+            ErrorCode::InvalidInput,
+            "questions",
+            MAX_SCREENING_QUESTIONS,
+            questions.len()
+        );
+
+        job_post.screening_questions = questions;
+
+        msg!(
+            "❓ Job '{}' screens with {} questions",
+            job_post.title,
+            job_post.screening_questions.len()
+        );
+        Ok(())
+    }
+
+    // Client lists the languages the work needs, matched against applicants'
+    // language profiles
+    pub fn set_job_languages(ctx: Context<SetJobNda>, languages: Vec<[u8; 2]>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        validate_language_codes(&languages, MAX_LANGUAGES)?;
+
+        job_post.required_languages = languages;
+
+        msg!(
+            "🌐 Job '{}' requires {} languages",
+            job_post.title,
+            job_post.required_languages.len()
+        );
+        Ok(())
+    }
+
+    // Client limits how many applications an open job takes and until when.
+    // Zero for either leaves it unlimited.
+    pub fn set_application_limits(
+        ctx: Context<SetJobNda>,
+        max_applicants: u32,
+        application_deadline: i64,
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_ctx!(
+            application_deadline == 0 || application_deadline > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidDates,
+            "application_deadline",
+            Clock::get()?.unix_timestamp,
+            application_deadline
+        );
+
+        job_post.max_applicants = max_applicants;
+        job_post.application_deadline = application_deadline;
+
+        msg!(
+            "🚦 Job '{}' takes up to {} applicants until {}",
+            job_post.title,
+            max_applicants,
+            application_deadline
+        );
+        Ok(())
+    }
+
+    // Client stops taking applications; those already in stay reviewable
+    pub fn close_applications(ctx: Context<SetJobNda>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.applications_closed, ErrorCode::ApplicationsClosed);
+        job_post.applications_closed = true;
+
+        emit!(ApplicationsClosed {
+            job_post: job_post.key(),
+            applications_count: job_post.applications_count,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🚫 Job '{}' no longer takes applications", job_post.title);
+        Ok(())
+    }
+
+    // Client requires applicants to work from a range of UTC offsets, or
+    // clears the requirement with `None`
+    pub fn set_utc_offset_range(
+        ctx: Context<SetJobNda>,
+        range: Option<UtcOffsetRange>,
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        if let Some(range) = range {
+            validate_utc_offset(range.min_minutes)?;
+            validate_utc_offset(range.max_minutes)?;
+            require_ctx!(
+                range.min_minutes <= range.max_minutes,
+                ErrorCode::InvalidInput,
+                "max_minutes",
+                range.min_minutes,
+                range.max_minutes
+            );
+        }
+
+        job_post.utc_offset_range = range;
+
+        msg!("🕒 Job '{}' UTC offset range: {:?}", job_post.title, range);
+        Ok(())
+    }
+
+    // Client sets the lamports each applicant stakes on this job, overriding
+    // the namespace's application stake. Zero defers to the namespace again.
+    pub fn set_job_application_stake(ctx: Context<SetJobNda>, stake: u64) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+
+        job_post.application_stake = Lamports(stake);
+
+        msg!("🪙 Job '{}' application stake: {} lamports", job_post.title, stake);
+        Ok(())
+    }
+
+    // Client caps how many times rejected work may be resubmitted, before
+    // anyone has applied. Zero removes the cap.
+    pub fn set_job_revision_limit(ctx: Context<SetJobNda>, max_revisions: u8) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+
+        job_post.max_revisions = max_revisions;
+
+        msg!("🔁 Job '{}' allows {} revisions", job_post.title, max_revisions);
+        Ok(())
+    }
+
+    // Client sets how many days they take to review submissions on this job,
+    // overriding the namespace's review window, before anyone has applied.
+    // Zero defers to the namespace again.
+    pub fn set_job_review_window(ctx: Context<SetJobNda>, days: u16) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+
+        job_post.review_window_days = days;
+
+        emit!(ReviewWindowSet {
+            job_post: job_post.key(),
+            review_window_days: days,
+            review_period_seconds: job_post.review_period_seconds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("⏳ Job '{}' review window: {} days", job_post.title, days);
+        Ok(())
+    }
+
+    // Client sets an exact review period in seconds, after which unreviewed
+    // work may be auto-released, before anyone has applied. It takes
+    // precedence over the review window in days; zero clears it.
+    pub fn set_job_review_period(ctx: Context<SetJobNda>, seconds: i64) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+        require_ctx!(seconds >= 0, ErrorCode::InvalidInput, "seconds", 0, seconds);
+
+        job_post.review_period_seconds = seconds;
+
+        emit!(ReviewWindowSet {
+            job_post: job_post.key(),
+            review_window_days: job_post.review_window_days,
+            review_period_seconds: seconds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("⏳ Job '{}' review period: {} seconds", job_post.title, seconds);
+        Ok(())
+    }
+
+    // Client opens a job to everyone or limits it to invited freelancers
+    pub fn set_job_visibility(ctx: Context<SetJobNda>, visibility: JobVisibility) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+
+        job_post.visibility = visibility;
+
+        msg!("👁️ Job '{}' visibility: {:?}", job_post.title, visibility);
+        Ok(())
+    }
+
+    // Client invites a freelancer to apply to their job, required to apply
+    // once the job is invite-only
+    pub fn invite_freelancer(ctx: Context<InviteFreelancer>, freelancer: Pubkey) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_keys_neq!(freelancer, job_post.client, ErrorCode::InvalidInput);
+
+        let invitation = &mut ctx.accounts.invitation;
+        invitation.job_post = job_post.key();
+        invitation.freelancer = freelancer;
+        invitation.invited_at = Clock::get()?.unix_timestamp;
+        invitation.bump = ctx.bumps.invitation;
+
+        emit!(FreelancerInvited {
+            job_post: invitation.job_post,
+            client: job_post.client,
+            freelancer,
+            timestamp: invitation.invited_at,
+        });
+
+        msg!("💌 {} invited to apply to '{}'", freelancer, job_post.title);
+        Ok(())
+    }
+
+    // Prospective applicant signs the job's NDA. The client shares the
+    // description's decryption key off-chain against this record, and its
+    // timestamp is the evidence if confidential material leaks.
+    pub fn acknowledge_nda(ctx: Context<AcknowledgeNda>, nda_hash: [u8; 32]) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(job_post.nda_hash == Some(nda_hash), ErrorCode::NdaMismatch);
+
+        let acknowledgment = &mut ctx.accounts.nda_acknowledgment;
+        acknowledgment.job_post = job_post.key();
+        acknowledgment.signer = ctx.accounts.signer.key();
+        acknowledgment.nda_hash = nda_hash;
+        acknowledgment.acknowledged_at = Clock::get()?.unix_timestamp;
+        acknowledgment.bump = ctx.bumps.nda_acknowledgment;
+
+        msg!("🔏 NDA for job '{}' acknowledged by {}", job_post.title, acknowledgment.signer);
+        Ok(())
+    }
+
+    // Client tags an open job with a category for list views, filing it in
+    // the category's index when one is passed
+    pub fn set_job_category(ctx: Context<SetJobCategory>, category: u8) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_ctx!(category < MAX_CATEGORIES, ErrorCode::InvalidInput, "category", MAX_CATEGORIES, category);
+
+        job_post.category = category;
+        ctx.accounts.job_card.refresh(job_post);
+        if let Some(category_index) = ctx.accounts.category_index.as_mut() {
+            require!(category_index.category == category, ErrorCode::InvalidAccount);
+            category_index.record(job_post.key())?;
+        }
+
+        msg!("🏷️ Job '{}' categorized as {}", job_post.title, category);
+        Ok(())
+    }
+
+    // Client labels an open job with up to MAX_JOB_TAGS short tags
+    pub fn set_job_tags(ctx: Context<SetJobCategory>, tags: Vec<String>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_ctx!(tags.len() <= MAX_JOB_TAGS, ErrorCode::InvalidInput, "tags", MAX_JOB_TAGS, tags.len());
+        for tag in &tags {
+            require_ctx!(
+                !tag.is_empty() && tag.len() <= MAX_TAG_LEN,
+                ErrorCode::InvalidInput,
+                "tag",
+                MAX_TAG_LEN,
+                tag.len()
+            );
+        }
+
+        job_post.tags = tags;
+
+        msg!("🏷️ Job '{}' tagged {:?}", job_post.title, job_post.tags);
+        Ok(())
+    }
+
+    // Client replaces the custom fields on a job (PO number, cost center, ...)
+    // kept in its extension account. Frozen once the job is filled.
+    pub fn set_job_metadata(ctx: Context<SetJobMetadata>, entries: Vec<MetadataEntry>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_ctx!(
+            entries.len() <= MAX_METADATA_ENTRIES,
+            ErrorCode::InvalidInput,
+            "entries",
+            MAX_METADATA_ENTRIES,
+            entries.len()
+        );
+        for (i, entry) in entries.iter().enumerate() {
+            require_ctx!(
+                !entry.key.is_empty() && entry.key.len() <= MAX_METADATA_KEY_LEN,
+                ErrorCode::InvalidInput,
+                "key",
+                MAX_METADATA_KEY_LEN,
+                entry.key.len()
+            );
+            require_ctx!(
+                entry.value.len() <= MAX_METADATA_VALUE_LEN,
+                ErrorCode::InvalidInput,
+                "value",
+                MAX_METADATA_VALUE_LEN,
+                entry.value.len()
+            );
+            require!(
+                entries[..i].iter().all(|other| other.key != entry.key),
+                ErrorCode::InvalidInput
+            );
+        }
+
+        let extension = &mut ctx.accounts.job_extension;
+        extension.job_post = job_post.key();
+        extension.entries = entries;
+        extension.bump = ctx.bumps.job_extension;
+
+        msg!(
+            "🗂️ Job '{}' has {} custom fields",
+            job_post.title,
+            extension.entries.len()
+        );
+        Ok(())
+    }
+
+    // Anyone opens the index of a category, where set_job_category files jobs
+    pub fn open_category_index(
+        ctx: Context<OpenCategoryIndex>,
+        namespace: Pubkey,
+        category: u8,
+    ) -> Result<()> {
+        require_ctx!(category < MAX_CATEGORIES, ErrorCode::InvalidInput, "category", MAX_CATEGORIES, category);
+
+        let category_index = &mut ctx.accounts.category_index;
+        category_index.namespace = namespace;
+        category_index.category = category;
+        category_index.bump = ctx.bumps.category_index;
+
+        msg!("🗂️ Index of category {} opened", category);
+        Ok(())
+    }
+
+    // User opens a page of their jobs (INDEX_JOBS) or applications
+    // (INDEX_APPLICATIONS) index. Creation instructions list new accounts on
+    // the page passed to them and closing ones drop them, so frontends read
+    // pages from zero up instead of scanning the program.
+    pub fn open_user_index_page(ctx: Context<OpenUserIndexPage>, kind: u8, page: u32) -> Result<()> {
+        require_ctx!(kind <= INDEX_APPLICATIONS, ErrorCode::InvalidInput, "kind", INDEX_APPLICATIONS, kind);
+
+        let index_page = &mut ctx.accounts.index_page;
+        index_page.owner = ctx.accounts.owner.key();
+        index_page.kind = kind;
+        index_page.page = page;
+        index_page.bump = ctx.bumps.index_page;
+
+        msg!("🗂️ Index page {} of kind {} opened for {}", page, kind, index_page.owner);
+        Ok(())
+    }
+
+    // Client makes an open job wait on another job's completion, for projects
+    // split into phases across freelancers. `None` clears the dependency.
+    pub fn set_job_dependency(
+        ctx: Context<SetJobDependency>,
+        depends_on: Option<Pubkey>,
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(depends_on != Some(job_post.key()), ErrorCode::InvalidInput);
+
+        job_post.depends_on = depends_on;
+
+        match depends_on {
+            Some(dependency) => msg!("🔗 Job '{}' now waits on {}", job_post.title, dependency),
+            None => msg!("🔗 Job '{}' dependency cleared", job_post.title),
+        }
+        Ok(())
+    }
+
+    // Permissionless: create or refresh a job's compact card from its JobPost
+    pub fn sync_job_card(ctx: Context<SyncJobCard>) -> Result<()> {
+        let job_card = &mut ctx.accounts.job_card;
+        job_card.bump = ctx.bumps.job_card;
+        job_card.refresh(&ctx.accounts.job_post);
+
+        msg!("🗂️ Job card synced for '{}'", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // Admin features or unfeatures a job in their namespace
+    pub fn set_job_featured(ctx: Context<SetJobFeatured>, featured: bool) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.featured = featured;
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!("⭐ Job '{}' featured: {}", job_post.title, featured);
+        Ok(())
+    }
+
+    // Permissionless: append a job to the namespace's paged registry, or refresh
+    // its entry if it is already listed
+    pub fn register_job(ctx: Context<RegisterJob>, page: u32) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let registry_page = &mut ctx.accounts.registry_page;
+        let entry = RegistryEntry::from_job(job_post);
+
+        if let Some(index) = job_post.registry_index {
+            require!(
+                index / REGISTRY_PAGE_SIZE as u64 == u64::from(page),
+                ErrorCode::InvalidRegistryPage
+            );
+            let slot = (index % REGISTRY_PAGE_SIZE as u64) as usize;
+            registry_page.entries[slot] = entry;
+            msg!("🗂️ Registry entry {} refreshed", index);
+            return Ok(());
+        }
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        let index = operator_stats.registered_jobs;
+        require!(
+            index / REGISTRY_PAGE_SIZE as u64 == u64::from(page),
+            ErrorCode::InvalidRegistryPage
+        );
+        if registry_page.entries.is_empty() {
+            registry_page.namespace = job_post.namespace;
+            registry_page.page = page;
+            registry_page.bump = ctx.bumps.registry_page;
+        }
+
+        registry_page.entries.push(entry);
+        job_post.registry_index = Some(index);
+        operator_stats.registered_jobs = checked_add(operator_stats.registered_jobs, 1)?;
+
+        msg!("🗂️ Job '{}' registered at index {}", job_post.title, index);
+        Ok(())
+    }
+
+    // Read-only: page through the registry from `cursor` and return up to `limit`
+    // entries matching `filter`. Registry pages are passed in order as remaining
+    // accounts, starting with the page holding `cursor`. Meant for simulation.
+    pub fn list_jobs<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ListJobs<'info>>,
+        cursor: u64,
+        limit: u8,
+        filter: JobFilter,
+    ) -> Result<JobListing> {
+        require!(
+            limit > 0 && usize::from(limit) <= MAX_LIST_LIMIT,
+            ErrorCode::InvalidInput
+        );
+
+        let namespace = ctx.accounts.platform_config.namespace;
+        let mut entries = Vec::with_capacity(usize::from(limit));
+        let mut next = cursor;
+        let mut expected_page = cursor / REGISTRY_PAGE_SIZE as u64;
+
+        'pages: for info in ctx.remaining_accounts.iter() {
+            let registry_page: Account<RegistryPage> = Account::try_from(info)?;
+            require_keys_eq!(registry_page.namespace, namespace, ErrorCode::InvalidRegistryPage);
+            require!(
+                u64::from(registry_page.page) == expected_page,
+                ErrorCode::InvalidRegistryPage
+            );
+
+            let first = expected_page * REGISTRY_PAGE_SIZE as u64;
+            for (slot, entry) in registry_page.entries.iter().enumerate() {
+                let index = first + slot as u64;
+                if index < next {
+                    continue;
+                }
+                if entries.len() == usize::from(limit) {
+                    break 'pages;
+                }
+                next = index + 1;
+                if filter.matches(entry) {
+                    entries.push(*entry);
+                }
+            }
+            expected_page += 1;
+        }
+
+        Ok(JobListing {
+            next_cursor: next,
+            entries,
+        })
+    }
+
+    // Client attaches an oracle-linked repricing clause to an open SOL job. The job
+    // amount tracks the USD value at funding, bounded to [min_bps, max_bps] of
+    // the original amount; the client escrows the headroom up to the upper bound.
+    pub fn set_repricing_clause(
+        ctx: Context<SetRepricingClause>,
+        min_bps: u16,
+        max_bps: u16,
+    ) -> Result<()> {
+        require_feature(&ctx.accounts.platform_config, FEATURE_REPRICING)?;
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.is_native(), ErrorCode::NativeOnly);
+        require!(
+            job_post.auction.is_none()
+                && job_post.direct_offer.is_none()
+                && job_post.retainer.is_none()
+                && job_post.hourly.is_none()
+                && job_post.stream.is_none(),
+            ErrorCode::RepricingJob
+        );
+        require!(job_post.repricing.is_none(), ErrorCode::RepricingJob);
+        require!(
+            min_bps <= MAX_BPS && (MAX_BPS..=MAX_REPRICING_BPS).contains(&max_bps),
+            ErrorCode::InvalidBps
+        );
+        let base_price = job_post.funding_price.ok_or(ErrorCode::OraclePriceRequired)?;
+
+        let max_amount = bps_of(job_post.amount.0, max_bps);
+        let buffer = max_amount - job_post.amount.0;
+        if buffer > 0 {
+            deposit_to_escrow(
+                &ctx.accounts.client.to_account_info(),
+                &ctx.accounts.escrow.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                buffer,
+            )?;
+        }
+
+        job_post.repricing = Some(RepricingClause {
+            base_amount: job_post.amount.0,
+            base_price,
+            min_bps,
+            max_bps,
+            buffer,
+        });
+
+        msg!(
+            "📈 Repricing clause set on '{}': {}–{} bps of {} lamports",
+            job_post.title,
+            min_bps,
+            max_bps,
+            job_post.amount
+        );
+        Ok(())
+    }
+
+    // Permissionless: recompute a repriced job's amount from the current oracle
+    // price, moving the difference between the job amount and the buffer. Only
+    // while the job is open and nobody was hired at the old price.
+    pub fn reprice_job(ctx: Context<RepriceJob>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.published, ErrorCode::JobNotPublished);
+        require!(
+            !job_post.is_filled && job_post.hires_count == 0,
+            ErrorCode::JobAlreadyFilled
+        );
+        let mut clause = job_post.repricing.ok_or(ErrorCode::NotRepricing)?;
+        let current = observe_price(
+            &ctx.accounts.platform_config,
+            job_post,
+            ctx.accounts.price_update.as_ref(),
+        )?
+        .ok_or(ErrorCode::OraclePriceRequired)?;
+
+        let new_amount = repriced_amount(&clause, &current).ok_or(ErrorCode::InvalidOraclePrice)?;
+        let total = job_post.amount.0 + clause.buffer;
+        clause.buffer = total - new_amount;
+        job_post.amount = BaseUnits(new_amount);
+        job_post.repricing = Some(clause);
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!("📈 Job '{}' repriced to {} lamports", job_post.title, new_amount);
+        Ok(())
+    }
+
+    // Client edits an unfilled job's description and dates. The title and
+    // amount stay; budget changes go through increase/decrease_job_budget.
+    pub fn update_job_post(
+        ctx: Context<UpdateJobPost>,
+        description: String,
+        start_date: i64,
+        end_date: i64,
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(
+            !description.is_empty() && description.len() <= MAX_JOB_DESCRIPTION_LEN,
+            ErrorCode::InvalidInput
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require_ctx!(start_date <= end_date, ErrorCode::InvalidDates, "start_date", end_date, start_date);
+        require_ctx!(end_date >= now, ErrorCode::InvalidDates, "end_date", now, end_date);
+        let latest_end = now + MAX_JOB_DURATION;
+        require_ctx!(end_date <= latest_end, ErrorCode::InvalidDates, "end_date", latest_end, end_date);
+        require!(
+            start_date == job_post.start_date || start_date >= now,
+            ErrorCode::InvalidDates
+        );
+
+        let end_changed = end_date != job_post.end_date;
+        job_post.description = description;
+        job_post.start_date = start_date;
+        job_post.end_date = end_date;
+        ctx.accounts.job_card.refresh(job_post);
+        if end_changed {
+            emit_deadline(job_post.key(), None, DeadlineKind::JobEnd, end_date)?;
+        }
+
+        msg!("✏️ Job '{}' updated", job_post.title);
+        Ok(())
+    }
+
+    // Client accepts an applicant's proposed price, moving the difference
+    // between it and the job amount into or out of the escrow. The applicant
+    // still needs approve_application to be hired.
+    pub fn accept_counter_offer(ctx: Context<AcceptCounterOffer>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+        require_budget_adjustable(job_post)?;
+        require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+
+        let proposed = application
+            .proposed_amount
+            .ok_or(ErrorCode::NoCounterOffer)?;
+        if let Some(job_milestones) = JobMilestones::load(&ctx.accounts.job_milestones)? {
+            let planned: u64 = job_milestones.milestones.iter().map(|m| m.amount.0).sum();
+            require!(planned <= proposed, ErrorCode::InvalidMilestone);
+        }
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        if proposed > job_post.amount.0 {
+            let top_up = proposed - job_post.amount.0;
+            deposit_to_escrow(
+                &ctx.accounts.client.to_account_info(),
+                &ctx.accounts.escrow.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                top_up,
+            )?;
+            operator_stats.volume_escrowed = checked_add(operator_stats.volume_escrowed, top_up)?;
+        } else if proposed < job_post.amount.0 {
+            let refund = job_post.amount.0 - proposed;
+            let escrow = EscrowSource::new(
+                job_post,
+                &ctx.accounts.escrow,
+                &ctx.accounts.system_program,
+                None,
+                None,
+                None,
+            )?;
+            let client = ctx.accounts.client.to_account_info();
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+            operator_stats.volume_escrowed = operator_stats.volume_escrowed.saturating_sub(refund);
+        }
+
+        job_post.amount = BaseUnits(proposed);
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!(
+            "🤝 Counter-offer of {} lamports from {} accepted",
+            proposed,
+            application.applicant
+        );
+        Ok(())
+    }
+
+    // Client opens an unhired job to `max_hires` freelancers, each paid an
+    // equal share of the escrow. Offers, auctions, trials, repricing and
+    // milestones stay single-seat.
+    pub fn set_max_hires(ctx: Context<SetMaxHires>, max_hires: u8) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.hires_count == 0, ErrorCode::JobAlreadyFilled);
+        require!(
+            job_post.auction.is_none()
+                && job_post.direct_offer.is_none()
+                && job_post.repricing.is_none()
+                && job_post.retainer.is_none()
+                && job_post.hourly.is_none()
+                && !job_post.trial,
+            ErrorCode::PriceLocked
+        );
+        require!(job_post.stream.is_none(), ErrorCode::StreamingJob);
+        require!(
+            JobMilestones::load(&ctx.accounts.job_milestones)?.is_none()
+                && job_post.sol_leg == Lamports::ZERO,
+            ErrorCode::MultiSeatJob
+        );
+        require!(
+            (1..=MAX_HIRES).contains(&max_hires)
+                && job_post.amount.0.is_multiple_of(u64::from(max_hires)),
+            ErrorCode::InvalidInput
+        );
+
+        job_post.max_hires = max_hires;
+
+        msg!(
+            "🪑 Job '{}' now hires {} freelancers at {} each",
+            job_post.title,
+            max_hires,
+            job_post.seat_payout()
+        );
+        Ok(())
+    }
+
+    // Client tops up an unfilled SOL job's escrow, raising its amount
+    pub fn increase_job_budget(
+        ctx: Context<AdjustJobBudget>,
+        amount: u64,
+        nonce: Option<u64>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.recent_nonces.consume(nonce)?;
+        require_budget_adjustable(job_post)?;
+        require!(
+            amount.is_multiple_of(u64::from(job_post.max_hires)),
+            ErrorCode::InvalidAmount
+        );
+
+        deposit_to_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            amount,
+        )?;
+
+        job_post.amount = job_post.amount.checked_add(BaseUnits(amount))?;
+        ctx.accounts.operator_stats.volume_escrowed =
+            checked_add(ctx.accounts.operator_stats.volume_escrowed, amount)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        msg!("💰 Job '{}' budget raised to {} lamports", job_post.title, job_post.amount);
+        Ok(())
+    }
+
+    // Client adds lamports to a SOL job's escrow once work is under way, e.g.
+    // for extra scope. The hired freelancer is paid the larger amount on approval.
+    pub fn top_up_escrow(
+        ctx: Context<AdjustJobBudget>,
+        amount: u64,
+        nonce: Option<u64>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.recent_nonces.consume(nonce)?;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(job_post.hires_count > 0, ErrorCode::ApplicationNotApproved);
+        require!(job_post.is_native(), ErrorCode::NativeOnly);
+        require!(
+            job_post.repricing.is_none()
+                && job_post.retainer.is_none()
+                && job_post.hourly.is_none()
+                && job_post.stream.is_none(),
+            ErrorCode::PriceLocked
+        );
+        require!(
+            amount.is_multiple_of(u64::from(job_post.max_hires)),
+            ErrorCode::InvalidAmount
+        );
+
+        deposit_to_escrow(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            amount,
+        )?;
+
+        job_post.amount = job_post.amount.checked_add(BaseUnits(amount))?;
+        ctx.accounts.operator_stats.volume_escrowed =
+            checked_add(ctx.accounts.operator_stats.volume_escrowed, amount)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(EscrowToppedUp {
+            job_post: job_post.key(),
+            amount: BaseUnits(amount),
+            total: job_post.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("💰 Job '{}' escrow topped up to {} lamports", job_post.title, job_post.amount);
+        Ok(())
+    }
+
+    // Freelancer mints a non-transferable Token-2022 badge for a completed
+    // application, a credential wallets can show and nobody can sell. Its
+    // metadata names the job post, client and the client's review. Kept out
+    // of approval so settling work doesn't pay for the mint.
+    pub fn mint_completion_badge(ctx: Context<MintCompletionBadge>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+        require!(application.completed, ErrorCode::WorkNotCompleted);
+
+        let badge_mint = ctx.accounts.badge_mint.to_account_info();
+        require_keys_eq!(*badge_mint.owner, system_program::ID, ErrorCode::BadgeAlreadyMinted);
+        let application_key = application.key();
+        let seeds = &[b"badge", application_key.as_ref(), &[ctx.bumps.badge_mint]];
+        let signer_seeds = &[&seeds[..]];
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let applicant = ctx.accounts.applicant.to_account_info();
+
+        let metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(badge_mint.key()))?,
+            mint: badge_mint.key(),
+            name: format!("Completed: {}", job_post.title),
+            symbol: BADGE_SYMBOL.to_string(),
+            uri: String::new(),
+            additional_metadata: vec![
+                ("job_post".to_string(), job_post.key().to_string()),
+                ("client".to_string(), job_post.client.to_string()),
+                ("client_review".to_string(), application.client_review.clone()),
+            ],
+        };
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::NonTransferable,
+            ExtensionType::MetadataPointer,
+        ])?;
+        // Token-2022 grows the mint for its metadata, so fund that up front
+        let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+        // Allocated in place, so lamports sent to the address beforehand
+        // can't block the badge
+        let shortfall = lamports.saturating_sub(badge_mint.lamports());
+        if shortfall > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: applicant.clone(),
+                    to: badge_mint.clone(),
+                },
+            );
+            system_program::transfer(cpi_ctx, shortfall)?;
+        }
+        let system = ctx.accounts.system_program.to_account_info();
+        invoke_signed(
+            &system_instruction::allocate(badge_mint.key, space as u64),
+            &[badge_mint.clone(), system.clone()],
+            signer_seeds,
+        )?;
+        invoke_signed(
+            &system_instruction::assign(badge_mint.key, &Token2022::id()),
+            &[badge_mint.clone(), system.clone()],
+            signer_seeds,
+        )?;
+
+        token_extensions::non_transferable_mint_initialize(CpiContext::new(
+            token_program.clone(),
+            NonTransferableMintInitialize {
+                token_program_id: token_program.clone(),
+                mint: badge_mint.clone(),
+            },
+        ))?;
+        token_extensions::metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: badge_mint.clone(),
+                },
+            ),
+            Some(badge_mint.key()),
+            Some(badge_mint.key()),
+        )?;
+        token_2022::initialize_mint2(
+            CpiContext::new(
+                token_program.clone(),
+                token_2022::InitializeMint2 {
+                    mint: badge_mint.clone(),
+                },
+            ),
+            0,
+            badge_mint.key,
+            None,
+        )?;
+
+        token_extensions::token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataInitialize {
+                    program_id: token_program.clone(),
+                    metadata: badge_mint.clone(),
+                    update_authority: badge_mint.clone(),
+                    mint_authority: badge_mint.clone(),
+                    mint: badge_mint.clone(),
+                },
+                signer_seeds,
+            ),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        )?;
+        for (key, value) in metadata.additional_metadata {
+            token_extensions::token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    token_program.clone(),
+                    TokenMetadataUpdateField {
+                        program_id: token_program.clone(),
+                        metadata: badge_mint.clone(),
+                        update_authority: badge_mint.clone(),
+                    },
+                    signer_seeds,
+                ),
+                Field::Key(key),
+                value,
+            )?;
+        }
+
+        anchor_spl::associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            anchor_spl::associated_token::Create {
+                payer: applicant.clone(),
+                associated_token: ctx.accounts.badge_account.to_account_info(),
+                authority: applicant.clone(),
+                mint: badge_mint.clone(),
+                system_program: system,
+                token_program: token_program.clone(),
+            },
+        ))?;
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                token_2022::MintTo {
+                    mint: badge_mint.clone(),
+                    to: ctx.accounts.badge_account.to_account_info(),
+                    authority: badge_mint.clone(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+        // One badge per application, for good
+        token_2022::set_authority(
+            CpiContext::new_with_signer(
+                token_program,
+                token_2022::SetAuthority {
+                    current_authority: badge_mint.clone(),
+                    account_or_mint: badge_mint.clone(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        emit!(BadgeMinted {
+            job_post: job_post.key(),
+            application: application_key,
+            freelancer: application.applicant,
+            mint: badge_mint.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🏅 Completion badge for '{}' minted to {}", job_post.title, application.applicant);
+        Ok(())
+    }
+
+    // Client adds a SOL leg to a published single-seat token job, e.g. to cover
+    // the freelancer's gas or as a bonus. It sits in the escrow PDA beside the
+    // token vault and is released with the token payout on approval, or
+    // refunded with the tokens on cancellation. Disputes and partial
+    // settlements split it the way they split the tokens.
+    pub fn add_sol_leg(
+        ctx: Context<AdjustJobBudget>,
+        amount: u64,
+        nonce: Option<u64>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+        let job_post = &mut ctx.accounts.job_post;
+        job_post.recent_nonces.consume(nonce)?;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!job_post.is_native(), ErrorCode::TokenJobOnly);
+        require!(job_post.published, ErrorCode::JobNotPublished);
+        require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+
+        let client = ctx.accounts.client.to_account_info();
+        let escrow = ctx.accounts.escrow.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        // A token job's escrow PDA holds no lamports until its first SOL leg
+        if escrow.lamports() == 0 {
+            job_post.escrow_rent = fund_escrow(
+                &client,
+                &escrow,
+                &system_program,
+                job_post.key(),
+                job_post.escrow_bump,
+                amount,
+            )?;
+        } else {
+            deposit_to_escrow(&client, &escrow, &system_program, amount)?;
+        }
+        job_post.sol_leg = job_post.sol_leg.checked_add(Lamports(amount))?;
+
+        emit!(SolLegFunded {
+            job_post: job_post.key(),
+            amount: Lamports(amount),
+            total: job_post.sol_leg,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!(
+            "💰 Job '{}' carries {} lamports beside its {} escrow",
+            job_post.title,
+            job_post.sol_leg,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Client pays a bonus for approved work straight from their wallet (or
+    // token account for token jobs), outside the escrow and free of fees
+    pub fn tip_freelancer(
+        ctx: Context<TipFreelancer>,
+        amount: u64,
+        nonce: Option<u64>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+        ctx.accounts.job_post.recent_nonces.consume(nonce)?;
+        let job_post = &ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+        require!(application.completed, ErrorCode::WorkNotCompleted);
+
+        match job_post.payment_mint {
+            None => {
+                require_keys_eq!(
+                    ctx.accounts.freelancer.key(),
+                    application.applicant,
+                    ErrorCode::InvalidAccount
+                );
+                let cpi_ctx = CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.client.to_account_info(),
+                        to: ctx.accounts.freelancer.to_account_info(),
+                    },
+                );
+                system_program::transfer(cpi_ctx, amount)?;
+
+                let (year, month) = current_period()?;
+                ctx.accounts
+                    .freelancer_stats
+                    .record_tip(year, month, amount)?;
+            }
+            Some(payment_mint) => {
+                require_feature(&ctx.accounts.platform_config, FEATURE_TOKENS)?;
+                let (Some(source), Some(mint), Some(token_program)) = (
+                    &ctx.accounts.client_token_account,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.token_program,
+                ) else {
+                    return err!(ErrorCode::InvalidAccount);
+                };
+                require_keys_eq!(mint.key(), payment_mint, ErrorCode::InvalidAccount);
+                let to = ctx.accounts.freelancer.to_account_info();
+                require_keys_eq!(*to.owner, token_program.key(), ErrorCode::InvalidAccount);
+                let destination = TokenAccount::try_deserialize(&mut &to.try_borrow_data()?[..])?;
+                require_keys_eq!(destination.owner, application.applicant, ErrorCode::InvalidAccount);
+
+                token_interface::transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: source.to_account_info(),
+                            mint: mint.to_account_info(),
+                            to,
+                            authority: ctx.accounts.client.to_account_info(),
+                        },
+                    ),
+                    amount,
+                    mint.decimals,
+                )?;
+            }
+        }
+
+        emit!(TipPaid {
+            job_post: job_post.key(),
+            application: application.key(),
+            client: job_post.client,
+            freelancer: application.applicant,
+            amount: BaseUnits(amount),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("🎁 {} tipped {} {} for '{}'", application.applicant, amount, job_post.symbol, job_post.title);
+        Ok(())
+    }
+
+    // Client lowers an unfilled SOL job's amount and takes the difference back.
+    // The amount can't drop below the job's milestone plan.
+    pub fn decrease_job_budget(ctx: Context<AdjustJobBudget>, amount: u64) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require_budget_adjustable(job_post)?;
+        require!(amount > 0 && amount < job_post.amount.0, ErrorCode::InvalidAmount);
+        require!(
+            amount.is_multiple_of(u64::from(job_post.max_hires)),
+            ErrorCode::InvalidAmount
+        );
+
+        let new_amount = job_post.amount.0 - amount;
+        if let Some(job_milestones) = JobMilestones::load(&ctx.accounts.job_milestones)? {
+            let planned: u64 = job_milestones.milestones.iter().map(|m| m.amount.0).sum();
+            require!(planned <= new_amount, ErrorCode::InvalidMilestone);
+        }
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            None,
+            None,
+            None,
+        )?;
+        let client = ctx.accounts.client.to_account_info();
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, amount)?;
+
+        job_post.amount = BaseUnits(new_amount);
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.volume_escrowed = operator_stats.volume_escrowed.saturating_sub(amount);
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        msg!("💰 Job '{}' budget lowered to {} lamports", job_post.title, new_amount);
+        Ok(())
+    }
+
+    // Client keeps the price of an unfilled SOL job private. The escrow then
+    // only shows a cap; the price is committed as
+    // sha256(amount_le || salt || job_post) and is shared with the hired
+    // freelancer off-chain, to be revealed before the work is paid.
+    pub fn hide_job_amount(ctx: Context<SetJobNda>, commitment: [u8; 32]) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require_budget_adjustable(job_post)?;
+        require!(job_post.amount_commitment.is_none(), ErrorCode::AmountHidden);
+
+        job_post.amount_commitment = Some(commitment);
+
+        msg!("🙈 Price of job '{}' hidden below its escrow", job_post.title);
+        Ok(())
+    }
+
+    // Client or the hired freelancer opens a hidden price. The escrow above
+    // it goes back to the client and the job pays the revealed amount.
+    pub fn reveal_job_amount(
+        ctx: Context<RevealJobAmount>,
+        amount: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let revealer = ctx.accounts.revealer.key();
+        require!(
+            revealer == job_post.client
+                || ctx.accounts.application.as_ref().is_some_and(|application| {
+                    application.applicant == revealer && job_post.is_hired(application)
+                }),
+            ErrorCode::Unauthorized
+        );
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+
+        let commitment = job_post
+            .amount_commitment
+            .ok_or(ErrorCode::AmountCommitmentMismatch)?;
+        let expected = hashv(&[&amount.to_le_bytes(), &salt, job_post.key().as_ref()]);
+        require!(
+            expected.to_bytes() == commitment,
+            ErrorCode::AmountCommitmentMismatch
+        );
+        require!(
+            amount > 0
+                && amount <= job_post.amount.0
+                && amount >= job_post.milestones_released.0
+                && amount.is_multiple_of(u64::from(job_post.max_hires)),
+            ErrorCode::InvalidAmount
+        );
+        if let Some(job_milestones) = JobMilestones::load(&ctx.accounts.job_milestones)? {
+            let planned: u64 = job_milestones.milestones.iter().map(|m| m.amount.0).sum();
+            require!(planned <= amount, ErrorCode::InvalidMilestone);
+        }
+
+        let refund = job_post.amount.0 - amount;
+        if refund > 0 {
+            let escrow = EscrowSource::new(
+                job_post,
+                &ctx.accounts.escrow,
+                &ctx.accounts.system_program,
+                None,
+                None,
+                None,
+            )?;
+            let client = ctx.accounts.client.to_account_info();
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+        }
+
+        job_post.amount = BaseUnits(amount);
+        job_post.amount_commitment = None;
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.volume_escrowed = operator_stats.volume_escrowed.saturating_sub(refund);
+        ctx.accounts.job_card.refresh(job_post);
+
+        emit!(JobAmountRevealed {
+            job_post: job_post.key(),
+            amount: BaseUnits(amount),
+            refunded: BaseUnits(refund),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🔓 Job '{}' price revealed: {} lamports", job_post.title, amount);
+        Ok(())
+    }
+
+    // Read-only: status bits (APP_* flags) of each Application passed as a
+    // remaining account, in the same order. Meant for simulation.
+    pub fn get_applications_summary<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetApplicationsSummary<'info>>,
+    ) -> Result<Vec<u8>> {
+        let job_post_key = ctx.accounts.job_post.key();
+
+        ctx.remaining_accounts
+            .iter()
+            .map(|info| {
+                let application: Account<Application> = Account::try_from(info)?;
+                require_keys_eq!(application.job_post, job_post_key, ErrorCode::InvalidAccount);
+                Ok(application.status_flags())
+            })
+            .collect()
+    }
+
+    // Freelancer sets which jobs notification relayers should push to them
+    pub fn set_feed_prefs(
+        ctx: Context<SetFeedPrefs>,
+        categories: u64,
+        min_amount: u64,
+        token_preference: TokenPreference,
+    ) -> Result<()> {
+        let feed_prefs = &mut ctx.accounts.feed_prefs;
+        feed_prefs.freelancer = ctx.accounts.freelancer.key();
+        feed_prefs.categories = categories;
+        feed_prefs.min_amount = min_amount;
+        feed_prefs.token_preference = token_preference;
+        feed_prefs.bump = ctx.bumps.feed_prefs;
+
+        msg!("🔔 Feed preferences updated for {}", feed_prefs.freelancer);
+        Ok(())
+    }
+
+    // Freelancer lists the languages they work in, the UTC offset they work
+    // from and how many jobs they take on at once
+    pub fn set_freelancer_profile(
+        ctx: Context<SetFreelancerProfile>,
+        languages: Vec<[u8; 2]>,
+        utc_offset_minutes: Option<i16>,
+        max_active_jobs: u16,
+    ) -> Result<()> {
+        validate_language_codes(&languages, MAX_PROFILE_LANGUAGES)?;
+        if let Some(offset) = utc_offset_minutes {
+            validate_utc_offset(offset)?;
+        }
+
+        let freelancer_profile = &mut ctx.accounts.freelancer_profile;
+        freelancer_profile.freelancer = ctx.accounts.freelancer.key();
+        freelancer_profile.languages = languages;
+        freelancer_profile.utc_offset_minutes = utc_offset_minutes;
+        freelancer_profile.max_active_jobs = max_active_jobs;
+        freelancer_profile.available = freelancer_profile.is_available();
+        freelancer_profile.bump = ctx.bumps.freelancer_profile;
+
+        msg!(
+            "🌐 {} lists {} languages, UTC offset {:?}",
+            freelancer_profile.freelancer,
+            freelancer_profile.languages.len(),
+            freelancer_profile.utc_offset_minutes
+        );
+        Ok(())
+    }
+
+    // Read-only: whether a freelancer has capacity for another job
+    pub fn is_available(ctx: Context<FreelancerAvailability>) -> Result<bool> {
+        Ok(ctx.accounts.freelancer_profile.is_available())
+    }
+
+    // Wallet publishes a profile applications can point to instead of a
+    // resume link. Unlike the freelancer profile it isn't tied to a namespace.
+    pub fn create_profile(
+        ctx: Context<CreateProfile>,
+        display_name: String,
+        skills: Vec<String>,
+        portfolio_link: String,
+        hourly_rate: u64,
+    ) -> Result<()> {
+        validate_profile(&display_name, &skills, &portfolio_link)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let profile = &mut ctx.accounts.profile;
+        profile.owner = ctx.accounts.owner.key();
+        profile.display_name = display_name;
+        profile.skills = skills;
+        profile.portfolio_link = portfolio_link;
+        profile.hourly_rate = hourly_rate;
+        profile.created_at = now;
+        profile.updated_at = now;
+        profile.bump = ctx.bumps.profile;
+
+        msg!("🪪 Profile created for {} ({})", profile.owner, profile.display_name);
+        Ok(())
+    }
+
+    pub fn update_profile(
+        ctx: Context<UpdateProfile>,
+        display_name: String,
+        skills: Vec<String>,
+        portfolio_link: String,
+        hourly_rate: u64,
+    ) -> Result<()> {
+        validate_profile(&display_name, &skills, &portfolio_link)?;
+
+        let profile = &mut ctx.accounts.profile;
+        profile.display_name = display_name;
+        profile.skills = skills;
+        profile.portfolio_link = portfolio_link;
+        profile.hourly_rate = hourly_rate;
+        profile.updated_at = Clock::get()?.unix_timestamp;
+
+        msg!("🪪 Profile of {} updated", profile.owner);
+        Ok(())
+    }
+
+    // Read-only: whether a job matches a freelancer's feed preferences, so
+    // relayers and users can check the same matching rule
+    pub fn feed_matches(ctx: Context<FeedMatches>) -> Result<bool> {
+        Ok(ctx.accounts.feed_prefs.matches(&ctx.accounts.job_post))
+    }
+
+    // Client approves a freelancer's application on the given IP terms, optionally
+    // with a checklist of item hashes that must all be signed off before the
+    // work can be approved
+    pub fn approve_application(
+        ctx: Context<ApproveApplication>,
+        checklist: Vec<[u8; 32]>,
+        ip_terms: IpTerms,
+    ) -> Result<()> {
+        require_ctx!(
+            checklist.len() <= MAX_CHECKLIST_ITEMS,
+            ErrorCode::InvalidInput,
+            "checklist",
+            MAX_CHECKLIST_ITEMS,
+            checklist.len()
+        );
+
+        let job_post = &mut ctx.accounts.job_post;
+        require_client_or_delegate(
+            job_post,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_approve,
+        )?;
+        require_dependency_settled(job_post, ctx.accounts.dependency.as_ref())?;
+
+        hire_applicant(
+            job_post,
+            &mut ctx.accounts.application,
+            &ctx.accounts.platform_config,
+            ctx.accounts.freelancer_stats.as_deref(),
+            ctx.accounts.freelancer_profile.as_mut(),
+            checklist,
+            ip_terms,
+        )?;
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!("✅ Application approved for job '{}'", job_post.title);
+        Ok(())
+    }
+
+    // Client (or a delegate allowed to approve) hires several applicants of a
+    // multi-hire job at once, on the same IP terms and without checklists.
+    // Each applicant is passed as remaining accounts in threes: the
+    // application, their freelancer stats and their freelancer profile PDA,
+    // either of which may be uninitialized.
+    pub fn approve_applications_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReviewApplicationsBatch<'info>>,
+        ip_terms: IpTerms,
+    ) -> Result<()> {
+        let accounts = ctx.remaining_accounts;
+        require!(
+            !accounts.is_empty()
+                && accounts.len().is_multiple_of(3)
+                && accounts.len() / 3 <= usize::from(MAX_HIRES),
+            ErrorCode::InvalidBatchSize
+        );
+
+        let job_post = &mut ctx.accounts.job_post;
+        require_client_or_delegate(
+            job_post,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_approve,
+        )?;
+        require_dependency_settled(job_post, ctx.accounts.dependency.as_ref())?;
+
+        let namespace = job_post.namespace;
+        for accounts in accounts.chunks(3) {
+            let mut application: Account<Application> = Account::try_from(&accounts[0])?;
+            let applicant = application.applicant;
+            let (stats_key, _) = pda::stats(&namespace, ROLE_FREELANCER, &applicant);
+            require_keys_eq!(accounts[1].key(), stats_key, ErrorCode::InvalidAccount);
+            let (profile_key, _) = pda::freelancer_profile(&namespace, &applicant);
+            require_keys_eq!(accounts[2].key(), profile_key, ErrorCode::InvalidAccount);
+
+            let freelancer_stats = if accounts[1].owner == &crate::ID {
+                Some(Account::<UserStats>::try_from(&accounts[1])?)
+            } else {
+                None
+            };
+            let mut freelancer_profile = if accounts[2].owner == &crate::ID {
+                Some(Account::<FreelancerProfile>::try_from(&accounts[2])?)
+            } else {
+                None
+            };
+
+            hire_applicant(
+                job_post,
+                &mut application,
+                &ctx.accounts.platform_config,
+                freelancer_stats.as_deref(),
+                freelancer_profile.as_mut(),
+                Vec::new(),
+                ip_terms,
+            )?;
+            application.exit(&crate::ID)?;
+            if let Some(profile) = freelancer_profile {
+                profile.exit(&crate::ID)?;
+            }
+        }
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!(
+            "✅ {} applications approved for job '{}'",
+            accounts.len() / 3,
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Client (or a delegate allowed to approve) declines the applications
+    // passed as remaining accounts, leaving each the same review note.
+    // Declined applications can't be hired and may be closed right away.
+    pub fn reject_applications_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReviewApplicationsBatch<'info>>,
+        note: String,
+    ) -> Result<()> {
+        require_ctx!(
+            note.len() <= MAX_REVIEW_LEN,
+            ErrorCode::InvalidInput,
+            "note",
+            MAX_REVIEW_LEN,
+            note.len()
+        );
+        let accounts = ctx.remaining_accounts;
+        require!(
+            !accounts.is_empty() && accounts.len() <= MAX_BATCH_APPLICATIONS,
+            ErrorCode::InvalidBatchSize
+        );
+
+        let job_post = &ctx.accounts.job_post;
+        require_client_or_delegate(
+            job_post,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_approve,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        for info in accounts {
+            let mut application: Account<Application> = Account::try_from(info)?;
+            require_keys_eq!(application.job_post, job_post.key(), ErrorCode::InvalidAccount);
+            require!(!application.approved, ErrorCode::ApplicationAlreadyApproved);
+            require!(!application.declined, ErrorCode::ApplicationDeclined);
+
+            application.declined = true;
+            application.client_review = note.clone();
+            application.exit(&crate::ID)?;
+
+            emit!(ApplicationDeclined {
+                job_post: job_post.key(),
+                application: application.key(),
+                applicant: application.applicant,
+                timestamp: now,
+            });
+        }
+
+        msg!(
+            "🚫 {} applications declined for job '{}'",
+            accounts.len(),
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Client keeps a hash of their private triage notes on an application, so
+    // they can later prove what they noted (e.g. in a dispute over why it was
+    // declined) without publishing the notes. Never emitted. `None` clears it.
+    pub fn set_client_private_note(
+        ctx: Context<SetClientPrivateNote>,
+        note_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+        application.client_private_note_hash = note_hash;
+
+        msg!(
+            "🗒️ Private note on {}'s application for '{}' set: {}",
+            application.applicant,
+            ctx.accounts.job_post.title,
+            note_hash.is_some()
+        );
+        Ok(())
+    }
+
+    // Client turns an open job into a reverse auction awarded automatically to
+    // the lowest bid from a freelancer with at least `reputation_floor` completed jobs.
+    // With `reveal_ends_at` set, bids are sealed: committed before `closes_at` and
+    // revealed until `reveal_ends_at`.
+    pub fn start_auction(
+        ctx: Context<StartAuction>,
+        closes_at: i64,
+        reputation_floor: u64,
+        reveal_ends_at: Option<i64>,
+    ) -> Result<()> {
+        require_feature(&ctx.accounts.platform_config, FEATURE_AUCTIONS)?;
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.auction.is_none(), ErrorCode::AuctionJob);
+        require!(job_post.is_native(), ErrorCode::NativeOnly);
+        require!(job_post.repricing.is_none(), ErrorCode::RepricingJob);
+        require!(job_post.retainer.is_none(), ErrorCode::RetainerJob);
+        require!(job_post.hourly.is_none(), ErrorCode::HourlyJob);
+        require!(
+            closes_at > Clock::get()?.unix_timestamp && closes_at <= job_post.end_date,
+            ErrorCode::InvalidDates
+        );
+        if let Some(reveal_ends_at) = reveal_ends_at {
+            require!(
+                reveal_ends_at > closes_at && reveal_ends_at <= job_post.end_date,
+                ErrorCode::InvalidDates
+            );
+        }
+
+        job_post.auction = Some(Auction {
+            closes_at,
+            reputation_floor,
+            best_bid: None,
+            closed: false,
+            reveal_ends_at,
+        });
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!("🔨 Auction opened for '{}' until {}", job_post.title, closes_at);
+        Ok(())
+    }
+
+    // Applicant bids on an auction job; only a new lowest bid is recorded
+    pub fn submit_bid(ctx: Context<SubmitBid>, amount: u64) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require_ctx!(
+            amount > 0 && amount <= job_post.amount.0,
+            ErrorCode::InvalidAmount,
+            "amount",
+            job_post.amount.0,
+            amount
+        );
+        require!(
+            application.bid_amount.is_none_or(|bid| amount < bid),
+            ErrorCode::InvalidAmount
+        );
+
+        let completed_jobs = ctx
+            .accounts
+            .freelancer_stats
+            .as_ref()
+            .map_or(0, |stats| stats.reputation_score / REPUTATION_UNIT);
+        let auction = job_post.auction.as_mut().ok_or(ErrorCode::NotAnAuction)?;
+        require!(auction.reveal_ends_at.is_none(), ErrorCode::SealedAuction);
+        require!(
+            Clock::get()?.unix_timestamp < auction.closes_at,
+            ErrorCode::AuctionClosed
+        );
+        require!(
+            completed_jobs >= auction.reputation_floor,
+            ErrorCode::ReputationTooLow
+        );
+
+        application.bid_amount = Some(amount);
+        auction.record_bid(application.key(), amount);
+
+        msg!("🔨 Bid of {} lamports placed by {}", amount, application.applicant);
+        Ok(())
+    }
+
+    // Applicant commits to a sealed bid: hash(amount_le || salt || applicant)
+    pub fn commit_bid(ctx: Context<SubmitBid>, commitment: [u8; 32]) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+
+        let completed_jobs = ctx
+            .accounts
+            .freelancer_stats
+            .as_ref()
+            .map_or(0, |stats| stats.reputation_score / REPUTATION_UNIT);
+        let auction = job_post.auction.as_ref().ok_or(ErrorCode::NotAnAuction)?;
+        require!(auction.reveal_ends_at.is_some(), ErrorCode::NotSealedAuction);
+        require!(
+            Clock::get()?.unix_timestamp < auction.closes_at,
+            ErrorCode::AuctionClosed
+        );
+        require!(
+            completed_jobs >= auction.reputation_floor,
+            ErrorCode::ReputationTooLow
+        );
+
+        application.bid_commitment = Some(commitment);
+
+        msg!("🔒 Sealed bid committed by {}", application.applicant);
+        Ok(())
+    }
+
+    // Applicant reveals a sealed bid after bidding closes; bids never revealed
+    // are simply never considered by `close_auction`
+    pub fn reveal_bid(ctx: Context<SubmitBid>, amount: u64, salt: [u8; 32]) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+
+        require_ctx!(
+            amount > 0 && amount <= job_post.amount.0,
+            ErrorCode::InvalidAmount,
+            "amount",
+            job_post.amount.0,
+            amount
+        );
+
+        let auction = job_post.auction.as_mut().ok_or(ErrorCode::NotAnAuction)?;
+        let reveal_ends_at = auction.reveal_ends_at.ok_or(ErrorCode::NotSealedAuction)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= auction.closes_at && now < reveal_ends_at,
+            ErrorCode::NotRevealPhase
+        );
+        require!(application.bid_amount.is_none(), ErrorCode::BidAlreadyRevealed);
+
+        let commitment = application
+            .bid_commitment
+            .ok_or(ErrorCode::BidCommitmentMismatch)?;
+        let expected = hashv(&[
+            &amount.to_le_bytes(),
+            &salt,
+            application.applicant.as_ref(),
+        ]);
+        require!(
+            expected.to_bytes() == commitment,
+            ErrorCode::BidCommitmentMismatch
+        );
+
+        application.bid_amount = Some(amount);
+        auction.record_bid(application.key(), amount);
+
+        msg!("🔓 Sealed bid of {} lamports revealed by {}", amount, application.applicant);
+        Ok(())
+    }
+
+    // Permissionless crank: after the close time, award the job to the lowest bid
+    // and refund the difference between the budget and the winning price
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let auction = job_post.auction.as_mut().ok_or(ErrorCode::NotAnAuction)?;
+
+        require!(!auction.closed, ErrorCode::AuctionClosed);
+        require!(
+            Clock::get()?.unix_timestamp >= auction.reveal_ends_at.unwrap_or(auction.closes_at),
+            ErrorCode::AuctionStillOpen
+        );
+        auction.closed = true;
+
+        let Some(best_bid) = auction.best_bid else {
+            ctx.accounts.job_card.refresh(job_post);
+            msg!("🔨 Auction for '{}' closed without bids", job_post.title);
+            return Ok(());
+        };
+
+        let application = ctx
+            .accounts
+            .application
+            .as_mut()
+            .ok_or(ErrorCode::InvalidAccount)?;
+        require_keys_eq!(application.key(), best_bid.application, ErrorCode::InvalidAccount);
+
+        if !job_post.cancelled && !job_post.is_filled {
+            let refund = job_post.amount.0 - best_bid.amount;
+            if refund > 0 {
+                let escrow = EscrowSource::new(
+                    job_post,
+                    &ctx.accounts.escrow,
+                    &ctx.accounts.system_program,
+                    None,
+                    None,
+                    None,
+                )?;
+                let client = ctx.accounts.client.to_account_info();
+                escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, refund)?;
+            }
+
+            application.approved = true;
+            application.approved_at = Clock::get()?.unix_timestamp;
+            job_post.amount = BaseUnits(best_bid.amount);
+            job_post.is_filled = true;
+            job_post.hires_count = 1;
+            job_post.freelancer = Some(application.applicant);
+            track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, true)?;
+
+            emit!(ApplicationApproved {
+                job_post: job_post.key(),
+                application: application.key(),
+                client: job_post.client,
+                freelancer: application.applicant,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        ctx.accounts.job_card.refresh(job_post);
+
+        msg!(
+            "🔨 Auction for '{}' awarded to {} at {} lamports",
+            job_post.title,
+            application.applicant,
+            best_bid.amount
+        );
+        Ok(())
+    }
+
+    // Either party of an engagement proposes a new scope document hash; it only
+    // takes effect once the other party accepts it
+    pub fn amend_scope(ctx: Context<AmendScope>, new_hash: [u8; 32]) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let party = ctx.accounts.party.key();
+
+        require!(job_post.is_filled, ErrorCode::ApplicationNotApproved);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(
+            party == job_post.client || Some(party) == job_post.freelancer,
+            ErrorCode::Unauthorized
+        );
+        require!(
+            job_post.scope_history.len() < MAX_SCOPE_HISTORY,
+            ErrorCode::ScopeHistoryFull
+        );
+
+        job_post.pending_scope = Some(ScopeProposal {
+            hash: new_hash,
+            proposed_by: party,
+        });
+
+        msg!("📝 Scope amendment proposed by {}", party);
+        Ok(())
+    }
+
+    // The counterparty accepts the pending scope amendment, appending it to the history
+    pub fn accept_scope_amendment(ctx: Context<AmendScope>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let party = ctx.accounts.party.key();
+        let proposal = job_post.pending_scope.ok_or(ErrorCode::NoPendingScope)?;
+
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(
+            party == job_post.client || Some(party) == job_post.freelancer,
+            ErrorCode::Unauthorized
+        );
+        require_keys_neq!(party, proposal.proposed_by, ErrorCode::Unauthorized);
+        require!(
+            job_post.scope_history.len() < MAX_SCOPE_HISTORY,
+            ErrorCode::ScopeHistoryFull
+        );
+
+        job_post.scope_history.push(ScopeEntry {
+            hash: proposal.hash,
+            agreed_at: Clock::get()?.unix_timestamp,
+        });
+        job_post.pending_scope = None;
+
+        msg!(
+            "📝 Scope amendment {} agreed for '{}'",
+            job_post.scope_history.len(),
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Freelancer submits their completed work
+    pub fn submit_work(
+        ctx: Context<SubmitWork>,
+        submission_link: String,
+        narration: String,
+    ) -> Result<()> {
+        require_ctx!(
+            !submission_link.is_empty(),
+            ErrorCode::InvalidInput,
+            "submission_link",
+            1,
+            submission_link.len()
+        );
+        validate_link("submission_link", &submission_link)?;
+        require_ctx!(!narration.is_empty(), ErrorCode::InvalidInput, "narration", 1, narration.len());
+
+        let application = &mut ctx.accounts.application;
+
+        require!(
+            application.applicant == ctx.accounts.freelancer.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(application.approved, ErrorCode::ApplicationNotApproved);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require!(
+            !ctx.accounts.job_post.revisions_exhausted(application),
+            ErrorCode::RevisionLimitReached
+        );
+        require_dependency_settled(&ctx.accounts.job_post, ctx.accounts.dependency.as_ref())?;
+
+        // ✅ allow resubmission if rejected; earlier revisions keep their own
+        // accounts
+        let submission = &mut ctx.accounts.submission;
+        submission.job_post = application.job_post;
+        submission.application = application.key();
+        submission.freelancer = application.applicant;
+        submission.revision = application.submissions;
+        submission.link = submission_link;
+        submission.narration = narration;
+        submission.submitted_at = Clock::get()?.unix_timestamp;
+        submission.status = SubmissionStatus::Pending;
+        submission.bump = ctx.bumps.submission;
+
+        application.submissions = application
+            .submissions
+            .checked_add(1)
+            .ok_or(ErrorCode::RevisionLimitReached)?;
+        application.submitted = true;
+        application.submitted_at = submission.submitted_at;
+        application.rejected = false; // reset rejection flag
+        application.co_signed_by = None;
+
+        emit!(WorkSubmitted {
+            job_post: application.job_post,
+            application: application.key(),
+            freelancer: application.applicant,
+            revision: submission.revision,
+            timestamp: application.submitted_at,
+        });
+
+        msg!("📤 Work submitted by {}", application.applicant);
+        Ok(())
+    }
+
+    // Hired freelancer records one deliverable of their work, reviewed by the
+    // client on its own
+    pub fn add_deliverable(
+        ctx: Context<AddDeliverable>,
+        hash: [u8; 32],
+        uri: String,
+        label: String,
+    ) -> Result<()> {
+        let application = &ctx.accounts.application;
+        require!(
+            ctx.accounts.job_post.is_hired(application),
+            ErrorCode::ApplicationNotApproved
+        );
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_ctx!(
+            !uri.is_empty() && uri.len() <= MAX_ATTACHMENT_URI_LEN,
+            ErrorCode::InvalidInput,
+            "uri",
+            MAX_ATTACHMENT_URI_LEN,
+            uri.len()
+        );
+        require_ctx!(
+            label.len() <= MAX_ATTACHMENT_LABEL_LEN,
+            ErrorCode::InvalidInput,
+            "label",
+            MAX_ATTACHMENT_LABEL_LEN,
+            label.len()
+        );
+
+        let deliverables = &mut ctx.accounts.deliverables;
+        require_ctx!(
+            deliverables.items.len() < MAX_DELIVERABLES,
+            ErrorCode::InvalidInput,
+            "deliverables",
+            MAX_DELIVERABLES,
+            deliverables.items.len() + 1
+        );
+        deliverables.application = application.key();
+        deliverables.bump = ctx.bumps.deliverables;
+        deliverables.items.push(Deliverable {
+            file: Attachment { hash, uri, label },
+            share_bps: 0,
+            status: DeliverableStatus::Pending,
+        });
+
+        emit!(DeliverableAdded {
+            job_post: application.job_post,
+            application: application.key(),
+            index: (deliverables.items.len() - 1) as u8,
+            hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("📦 Deliverable {} added by {}", deliverables.items.len(), application.applicant);
+        Ok(())
+    }
+
+    // Client (or a delegate allowed to approve) splits the job's escrow across
+    // the deliverables, in basis points of the job amount, one share per
+    // deliverable. Shares of accepted deliverables are fixed.
+    pub fn set_deliverable_shares(ctx: Context<ReviewDeliverable>, shares: Vec<u16>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require_client_or_delegate(
+            job_post,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_approve,
+        )?;
+        require!(!ctx.accounts.application.completed, ErrorCode::WorkAlreadyApproved);
+        require_partial_release(job_post)?;
+
+        let deliverables = &mut ctx.accounts.deliverables;
+        require_ctx!(
+            shares.len() == deliverables.items.len(),
+            ErrorCode::InvalidInput,
+            "shares",
+            deliverables.items.len(),
+            shares.len()
+        );
+        let total_bps = shares.iter().map(|&bps| u32::from(bps)).sum::<u32>();
+        require_ctx!(total_bps <= u32::from(MAX_BPS), ErrorCode::InvalidInput, "bps", MAX_BPS, total_bps);
+        for (item, &share_bps) in deliverables.items.iter_mut().zip(&shares) {
+            if item.status == DeliverableStatus::Accepted {
+                require!(item.share_bps == share_bps, ErrorCode::DeliverableReviewed);
+            }
+            item.share_bps = share_bps;
+        }
+
+        msg!("📦 Escrow split across {} deliverables", shares.len());
+        Ok(())
+    }
+
+    // Client accepts one deliverable, releasing its share of the escrow to the
+    // freelancer less the fee and withholding, as a milestone would. Whatever
+    // the shares don't cover is paid on final approval.
+    pub fn accept_deliverable(ctx: Context<AcceptDeliverable>, index: u8) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &ctx.accounts.application;
+
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+
+        let deliverable = ctx
+            .accounts
+            .deliverables
+            .items
+            .get_mut(usize::from(index))
+            .ok_or(ErrorCode::InvalidInput)?;
+        require!(
+            deliverable.status == DeliverableStatus::Pending,
+            ErrorCode::DeliverableReviewed
+        );
+        deliverable.status = DeliverableStatus::Accepted;
+
+        let amount = bps_of(job_post.amount.0, deliverable.share_bps);
+        let mut fee = 0;
+        let mut withheld = 0;
+        if amount > 0 {
+            require_partial_release(job_post)?;
+            check_release(
+                &ctx.accounts.platform_config,
+                job_post,
+                application,
+                Some(&ctx.accounts.release_limit),
+                amount,
+            )?;
+            let released = job_post
+                .milestones_released
+                .0
+                .checked_add(amount)
+                .ok_or(ErrorCode::InvalidAmount)?;
+            require!(released <= job_post.amount.0, ErrorCode::InsufficientEscrowBalance);
+
+            let escrow = EscrowSource::new(
+                job_post,
+                &ctx.accounts.escrow,
+                &ctx.accounts.system_program,
+                ctx.accounts.escrow_vault.as_ref(),
+                ctx.accounts.mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            )?;
+            require!(escrow.balance()? >= amount, ErrorCode::InsufficientEscrowBalance);
+
+            let split = release_partial(
+                &escrow,
+                job_post,
+                application,
+                ReleaseAccounts {
+                    config: &ctx.accounts.platform_config,
+                    operator_stats: &mut ctx.accounts.operator_stats,
+                    insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                    fee_treasury: ctx.accounts.fee_treasury.as_ref(),
+                    tax_vault: ctx.accounts.tax_vault.as_ref(),
+                    payout_guardian: Some(&ctx.accounts.payout_guardian),
+                    freelancer: &ctx.accounts.freelancer,
+                    parking: ctx.accounts.claimable_vault.as_ref().map(|vault| Parking {
+                        vault,
+                        namespace: job_post.namespace,
+                        claimant: application.applicant,
+                        rent_payer: &ctx.accounts.client,
+                    }),
+                    settlement_hooks: &ctx.accounts.settlement_hooks,
+                    pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                    post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                    project: ctx.accounts.project.as_mut(),
+                },
+                amount,
+                "Deliverable",
+            )?;
+            fee = split.fee;
+            withheld = split.withheld;
+        }
+
+        emit!(DeliverableAccepted {
+            job_post: job_post.key(),
+            application: application.key(),
+            index,
+            amount: BaseUnits(amount),
+            fee: BaseUnits(fee),
+            withheld: BaseUnits(withheld),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!(
+            "✅ Deliverable {} of job '{}' accepted: {} {} released",
+            index,
+            job_post.title,
+            amount,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Client, reviewer or a delegate allowed to review sends one deliverable
+    // back for rework
+    pub fn reject_deliverable(ctx: Context<ReviewDeliverable>, index: u8) -> Result<()> {
+        let application = &ctx.accounts.application;
+        require_reviewer(
+            &ctx.accounts.job_post,
+            application,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_review,
+        )?;
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+
+        let deliverable = ctx
+            .accounts
+            .deliverables
+            .items
+            .get_mut(usize::from(index))
+            .ok_or(ErrorCode::InvalidInput)?;
+        require!(
+            deliverable.status == DeliverableStatus::Pending,
+            ErrorCode::DeliverableReviewed
+        );
+        deliverable.status = DeliverableStatus::Rejected;
+
+        emit!(DeliverableRejected {
+            job_post: application.job_post,
+            application: application.key(),
+            index,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("↩️ Deliverable {} sent back for rework", index);
+        Ok(())
+    }
+
+    // Freelancer replaces a rejected deliverable with reworked files, putting
+    // it back up for review
+    pub fn resubmit_deliverable(
+        ctx: Context<AddDeliverable>,
+        index: u8,
+        hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        let application = &ctx.accounts.application;
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_ctx!(
+            !uri.is_empty() && uri.len() <= MAX_ATTACHMENT_URI_LEN,
+            ErrorCode::InvalidInput,
+            "uri",
+            MAX_ATTACHMENT_URI_LEN,
+            uri.len()
+        );
+
+        let deliverable = ctx
+            .accounts
+            .deliverables
+            .items
+            .get_mut(usize::from(index))
+            .ok_or(ErrorCode::InvalidInput)?;
+        require!(
+            deliverable.status == DeliverableStatus::Rejected,
+            ErrorCode::DeliverableNotRejected
+        );
+        deliverable.file.hash = hash;
+        deliverable.file.uri = uri;
+        deliverable.status = DeliverableStatus::Pending;
+
+        emit!(DeliverableAdded {
+            job_post: application.job_post,
+            application: application.key(),
+            index,
+            hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        msg!("📦 Deliverable {} resubmitted by {}", index, application.applicant);
+        Ok(())
+    }
+
+    // Freelancer attaches an invoice reference to their submission; it is echoed
+    // in the approval event so payouts can be matched to invoices
+    pub fn request_payment(ctx: Context<SubmitWork>, note_hash: [u8; 32]) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+
+        require!(application.submitted, ErrorCode::WorkNotCompleted);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+
+        application.invoice_hash = Some(note_hash);
+
+        msg!("🧾 Payment requested by {}", application.applicant);
+        Ok(())
+    }
+
+    // Client splits an open job's escrow into milestones, each paid out on its
+    // own approval. Whatever the milestones don't cover is paid on final approval.
+    pub fn set_milestones(ctx: Context<SetMilestones>, milestones: Vec<MilestoneSpec>) -> Result<()> {
+        require_feature(&ctx.accounts.platform_config, FEATURE_MILESTONES)?;
+
+        let job_post = &ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(job_post.published, ErrorCode::JobNotPublished);
+        require!(job_post.repricing.is_none(), ErrorCode::RepricingJob);
+        require!(job_post.retainer.is_none(), ErrorCode::RetainerJob);
+        require!(job_post.hourly.is_none(), ErrorCode::HourlyJob);
+        require!(job_post.stream.is_none(), ErrorCode::StreamingJob);
+        require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+        require!(
+            (1..=MAX_MILESTONES).contains(&milestones.len()),
+            ErrorCode::InvalidInput
+        );
+
+        let mut total: u64 = 0;
+        for spec in &milestones {
+            require!(
+                !spec.description.is_empty()
+                    && spec.description.len() <= MAX_MILESTONE_DESCRIPTION_LEN,
+                ErrorCode::InvalidInput
+            );
+            require_ctx!(spec.amount > BaseUnits::ZERO, ErrorCode::InvalidAmount, "amount", 1, spec.amount.0);
+            require!(
+                (job_post.start_date..=job_post.end_date).contains(&spec.deadline),
+                ErrorCode::InvalidDates
+            );
+            total = total
+                .checked_add(spec.amount.0)
+                .ok_or(ErrorCode::InvalidAmount)?;
+        }
+        require_ctx!(total <= job_post.amount.0, ErrorCode::InvalidAmount, "milestones", job_post.amount.0, total);
+
+        let job_milestones = &mut ctx.accounts.job_milestones;
+        job_milestones.job_post = job_post.key();
+        job_milestones.bump = ctx.bumps.job_milestones;
+        job_milestones.milestones = milestones
+            .into_iter()
+            .map(|spec| Milestone {
+                description: spec.description,
+                amount: spec.amount,
+                deadline: spec.deadline,
+                submission_link: String::new(),
+                submitted: false,
+                approved: false,
+            })
+            .collect();
+
+        for milestone in &job_milestones.milestones {
+            emit_deadline(job_post.key(), None, DeadlineKind::Milestone, milestone.deadline)?;
+        }
+
+        msg!(
+            "🪜 Job '{}' split into {} milestones totalling {} {}",
+            job_post.title,
+            job_milestones.milestones.len(),
+            total,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Freelancer submits the work for one milestone; resubmitting replaces the link
+    pub fn submit_milestone(
+        ctx: Context<SubmitMilestone>,
+        index: u8,
+        submission_link: String,
+    ) -> Result<()> {
+        require_ctx!(
+            !submission_link.is_empty(),
+            ErrorCode::InvalidInput,
+            "submission_link",
+            1,
+            submission_link.len()
+        );
+        require_ctx!(
+            submission_link.len() <= 200,
+            ErrorCode::InvalidInput,
+            "submission_link",
+            200,
+            submission_link.len()
+        );
+
+        let application = &ctx.accounts.application;
+        require!(application.approved, ErrorCode::ApplicationNotApproved);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require_dependency_settled(&ctx.accounts.job_post, ctx.accounts.dependency.as_ref())?;
+
+        let milestone = ctx
+            .accounts
+            .job_milestones
+            .milestones
+            .get_mut(usize::from(index))
+            .ok_or(ErrorCode::InvalidMilestone)?;
+        require!(!milestone.approved, ErrorCode::MilestoneAlreadyApproved);
+
+        milestone.submission_link = submission_link;
+        milestone.submitted = true;
+
+        msg!("📤 Milestone {} submitted by {}", index, application.applicant);
+        Ok(())
+    }
+
+    // Client approves a submitted milestone and releases its slice of the escrow
+    pub fn approve_milestone(
+        ctx: Context<ApproveMilestone>,
+        index: u8,
+        freshness: Option<SlotBound>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        SlotBound::require_fresh(freshness)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &ctx.accounts.application;
+
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+
+        let milestone = ctx
+            .accounts
+            .job_milestones
+            .milestones
+            .get_mut(usize::from(index))
+            .ok_or(ErrorCode::InvalidMilestone)?;
+        require!(milestone.submitted, ErrorCode::WorkNotCompleted);
+        require!(!milestone.approved, ErrorCode::MilestoneAlreadyApproved);
+
+        let amount = milestone.amount.0;
+        check_release(
+            &ctx.accounts.platform_config,
+            job_post,
+            application,
+            Some(&ctx.accounts.release_limit),
+            amount,
+        )?;
+        let released = job_post
+            .milestones_released
+            .0
+            .checked_add(amount)
+            .ok_or(ErrorCode::InvalidAmount)?;
+        require!(released <= job_post.amount.0, ErrorCode::InsufficientEscrowBalance);
+        milestone.approved = true;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        require!(escrow.balance()? >= amount, ErrorCode::InsufficientEscrowBalance);
+
+        // Milestone payouts pay the fee and withholding at the same rates as the
+        // final payout
+        let split = release_partial(
+            &escrow,
+            job_post,
+            application,
+            ReleaseAccounts {
+                config: &ctx.accounts.platform_config,
+                operator_stats: &mut ctx.accounts.operator_stats,
+                insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                fee_treasury: ctx.accounts.fee_treasury.as_ref(),
+                tax_vault: ctx.accounts.tax_vault.as_ref(),
+                payout_guardian: Some(&ctx.accounts.payout_guardian),
+                freelancer: &ctx.accounts.freelancer,
+                parking: ctx.accounts.claimable_vault.as_ref().map(|vault| Parking {
+                    vault,
+                    namespace: job_post.namespace,
+                    claimant: application.applicant,
+                    rent_payer: &ctx.accounts.client,
+                }),
+                settlement_hooks: &ctx.accounts.settlement_hooks,
+                pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                project: ctx.accounts.project.as_mut(),
+            },
+            amount,
+            "Milestone",
+        )?;
+        let (fee, withheld) = (split.fee, split.withheld);
+
+        emit!(MilestoneApproved {
+            job_post: job_post.key(),
+            application: application.key(),
+            index,
+            amount: BaseUnits(amount),
+            fee: BaseUnits(fee),
+            withheld: BaseUnits(withheld),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "💸 Milestone {} of job '{}' released: {} {}",
+            index,
+            job_post.title,
+            amount,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Client (or the job's reviewer) approves work, rates the freelancer 1–5 and
+    // releases escrow funds to them. A freelancer team's accounts are passed as
+    // remaining accounts, see `pay_team_shares`. `freshness` bounds how long
+    // after signing the approval may still land, see `SlotBound`.
+    pub fn approve_submission<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveSubmission<'info>>,
+        tax_year: u16,
+        client_review: String,
+        rating: u8,
+        freshness: Option<SlotBound>,
+    ) -> Result<()> {
+        SlotBound::require_fresh(freshness)?;
+        require_reviewer(
+            &ctx.accounts.job_post,
+            &ctx.accounts.application,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_approve,
+        )?;
+        require!(
+            ctx.accounts.application.checklist_complete(),
+            ErrorCode::ChecklistIncomplete
+        );
+        require_ctx!((1..=MAX_RATING).contains(&rating), ErrorCode::InvalidRating, "rating", MAX_RATING, rating);
+
+        ctx.accounts.application.freelancer_rating = Some(rating);
+        ctx.accounts.freelancer_stats.record_rating(rating)?;
+        settle_submission(ctx, client_review, tax_year, false)
+    }
+
+    // Second approval for releasing a high-value job: the job's co-signer, or
+    // the namespace's platform signer when the client named none. Covers the
+    // seat's partial releases too, so it doesn't wait for a submission, and is
+    // cleared when the freelancer (re)submits.
+    pub fn co_sign_release(ctx: Context<CoSignRelease>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+        let co_signer = ctx.accounts.co_signer.key();
+
+        let expected = job_post
+            .co_signer
+            .unwrap_or(ctx.accounts.platform_config.co_signer);
+        require!(expected != Pubkey::default(), ErrorCode::Unauthorized);
+        require_keys_eq!(co_signer, expected, ErrorCode::Unauthorized);
+        require_keys_neq!(co_signer, job_post.client, ErrorCode::Unauthorized);
+        require_keys_neq!(co_signer, application.applicant, ErrorCode::Unauthorized);
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+
+        application.co_signed_by = Some(co_signer);
+
+        emit!(ReleaseCoSigned {
+            job_post: job_post.key(),
+            application: application.key(),
+            co_signer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("✍️ Release for {} co-signed by {}", application.applicant, co_signer);
+        Ok(())
+    }
+
+    // Freelancer collects payment for a submission the client left unreviewed
+    // for the job's review window
+    pub fn claim_unreviewed_work<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveSubmission<'info>>,
+        tax_year: u16,
+    ) -> Result<()> {
+        let application = &ctx.accounts.application;
+        require_keys_eq!(
+            ctx.accounts.approver.key(),
+            application.applicant,
+            ErrorCode::Unauthorized
+        );
+        require_review_lapsed(&ctx.accounts.job_post, application, &ctx.accounts.platform_config)?;
+
+        msg!("⏰ Review window lapsed; settling for {}", application.applicant);
+        settle_submission(ctx, String::new(), tax_year, true)
+    }
+
+    // Permissionless crank: once a submission has sat unreviewed past the
+    // job's review period, anyone may release it to the freelancer. The
+    // caller pays the rent of any accounts the payout opens.
+    pub fn auto_release_payment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApproveSubmission<'info>>,
+        tax_year: u16,
+    ) -> Result<()> {
+        let application = &ctx.accounts.application;
+        require_review_lapsed(&ctx.accounts.job_post, application, &ctx.accounts.platform_config)?;
+
+        msg!(
+            "⏰ Review period lapsed; {} releasing payment to {}",
+            ctx.accounts.approver.key(),
+            application.applicant
+        );
+        settle_submission(ctx, String::new(), tax_year, true)
+    }
+
+    // Client funds a buffer paying the hired freelancer `daily_fee` for each
+    // full day a submission waits past the review window. The fee is fixed by
+    // the first call; later calls top up the buffer.
+    pub fn fund_review_sla(
+        ctx: Context<FundReviewSla>,
+        daily_fee: u64,
+        amount: u64,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &ctx.accounts.job_post;
+        require!(job_post.is_native(), ErrorCode::NativeOnly);
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(ctx.accounts.application.approved, ErrorCode::ApplicationNotApproved);
+        require_ctx!(daily_fee > 0, ErrorCode::InvalidAmount, "daily_fee", 1, daily_fee);
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+
+        let sla = &mut ctx.accounts.review_sla;
+        require!(
+            sla.daily_fee == 0 || sla.daily_fee == daily_fee,
+            ErrorCode::InvalidInput
+        );
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.client.to_account_info(),
+                to: sla.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, amount)?;
+
+        sla.job_post = job_post.key();
+        sla.application = ctx.accounts.application.key();
+        sla.client = ctx.accounts.client.key();
+        sla.daily_fee = daily_fee;
+        sla.buffer = checked_add(sla.buffer, amount)?;
+        sla.bump = ctx.bumps.review_sla;
+
+        msg!("⏱️ Review SLA for '{}': {} lamports/day, {} lamports buffered", job_post.title, daily_fee, sla.buffer);
+        Ok(())
+    }
+
+    // Freelancer collects the late fee accrued on their latest submission:
+    // one daily fee per full day between the end of the review window and the
+    // client's review (or now, if still unreviewed), up to what is buffered.
+    // Days on an earlier submission must be claimed before resubmitting.
+    pub fn claim_review_late_fee(ctx: Context<ClaimReviewLateFee>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let application = &ctx.accounts.application;
+        require!(application.submitted_at > 0, ErrorCode::WorkNotCompleted);
+        let late_from = ctx
+            .accounts
+            .job_post
+            .review_deadline(&ctx.accounts.platform_config, application.submitted_at)
+            .ok_or(ErrorCode::ClaimsDisabled)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let sla = &mut ctx.accounts.review_sla;
+        let (days_late, fee) = sla.accrue(application, late_from, now);
+        require!(fee > 0, ErrorCode::NothingToClaim);
+
+        pay_review_late_fee(sla, application, &ctx.accounts.freelancer, days_late, fee, now)
+    }
+
+    // Client closes the review SLA once the job is settled or cancelled,
+    // reclaiming whatever is left of the buffer with the rent. Late days the
+    // freelancer hasn't claimed yet are paid to them first.
+    pub fn close_review_sla(ctx: Context<CloseReviewSla>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(
+            job_post.settled_at != 0 || job_post.cancelled,
+            ErrorCode::JobNotSettled
+        );
+
+        let application = &ctx.accounts.application;
+        let late_from = job_post.review_deadline(&ctx.accounts.platform_config, application.submitted_at);
+        if let Some(late_from) = late_from.filter(|_| application.submitted_at > 0) {
+            let now = Clock::get()?.unix_timestamp;
+            let sla = &mut ctx.accounts.review_sla;
+            let (days_late, fee) = sla.accrue(application, late_from, now);
+            if fee > 0 {
+                pay_review_late_fee(sla, application, &ctx.accounts.freelancer, days_late, fee, now)?;
+            }
+        }
+
+        msg!("⏱️ Review SLA closed; {} lamports unused", ctx.accounts.review_sla.buffer);
+        Ok(())
+    }
+
+    // Client takes back the escrow of a job whose end date passed (plus a grace
+    // period) without any work being submitted
+    pub fn reclaim_expired_escrow(ctx: Context<ReclaimExpiredEscrow>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(
+            job_post.max_hires <= 1 || job_post.hires_count == 0,
+            ErrorCode::MultiSeatJob
+        );
+        require!(job_post.published, ErrorCode::JobNotPublished);
+        let now = Clock::get()?.unix_timestamp;
+        let reclaimable_at = job_post
+            .end_date
+            .checked_add(RECLAIM_GRACE_PERIOD)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(now >= reclaimable_at, ErrorCode::ReclaimTooEarly);
+
+        if let Some(freelancer) = job_post.freelancer {
+            let application = ctx
+                .accounts
+                .application
+                .as_ref()
+                .ok_or(ErrorCode::InvalidAccount)?;
+            require!(
+                application.job_post == job_post.key() && application.applicant == freelancer,
+                ErrorCode::InvalidAccount
+            );
+            require!(
+                !application.submitted && !application.completed,
+                ErrorCode::WorkSubmitted
+            );
+            require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        }
+        if let Some(job_milestones) = JobMilestones::load(&ctx.accounts.job_milestones)? {
+            require!(
+                job_milestones
+                    .milestones
+                    .iter()
+                    .all(|milestone| !milestone.submitted || milestone.approved),
+                ErrorCode::WorkSubmitted
+            );
+        }
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let refund_to = match &ctx.accounts.client_token_account {
+            Some(token_account) if !job_post.is_native() => token_account.to_account_info(),
+            _ => ctx.accounts.client.to_account_info(),
+        };
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        let refund = checked_add(checked_sub(job_post.amount.0, job_post.milestones_released.0)?, buffer)?;
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &refund_to, refund)?;
+        let client = ctx.accounts.client.to_account_info();
+        settle_sol_leg(&escrow, job_post, Pubkey::default(), None, Some(&client), 0)?;
+
+        job_post.cancelled = true;
+        job_post.settled_at = now;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+
+        // The hired freelancer abandoned the job: their stake goes to the client
+        if job_post.freelancer.is_some() {
+            if let Some(application) = ctx.accounts.application.as_mut() {
+                forfeit_application_stake(application, &ctx.accounts.client)?;
+            }
+        }
+
+        ctx.accounts.operator_stats.jobs_cancelled =
+            checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        emit!(JobCancelled {
+            job_post: job_post.key(),
+            client: job_post.client,
+            refund: BaseUnits(refund),
+            timestamp: now,
+        });
+
+        msg!(
+            "⌛ Expired job '{}' reclaimed: {} {} refunded",
+            job_post.title,
+            refund,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Permissionless crank: once a job that never hired anyone is well past its
+    // end date, mark it expired and refund its escrow to the client. The escrow
+    // PDA is emptied too; its rent reserve pays the cranker a small reward and
+    // the rest goes back to the client.
+    pub fn expire_job(ctx: Context<ExpireJob>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(job_post.published, ErrorCode::JobNotPublished);
+        require!(
+            !job_post.is_filled && job_post.hires_count == 0 && job_post.freelancer.is_none(),
+            ErrorCode::JobAlreadyFilled
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let expirable_at = job_post
+            .end_date
+            .checked_add(EXPIRY_GRACE_PERIOD)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        require!(now >= expirable_at, ErrorCode::ReclaimTooEarly);
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let client = ctx.accounts.client.to_account_info();
+        let refund_to = match &ctx.accounts.client_token_account {
+            Some(token_account) if !job_post.is_native() => token_account.to_account_info(),
+            _ => client.clone(),
+        };
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        let refund = checked_add(checked_sub(job_post.amount.0, job_post.milestones_released.0)?, buffer)?;
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &refund_to, refund)?;
+        if job_post.sol_leg > Lamports::ZERO {
+            escrow.pay_lamports(&client, job_post.client, job_post.sol_leg, EscrowLeg::Refund)?;
+            job_post.sol_leg = Lamports::ZERO;
+        }
+
+        // Whatever is left in the escrow PDA is its rent reserve
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let cranker = ctx.accounts.cranker.to_account_info();
+        let residual = Lamports(escrow_info.lamports());
+        let reward = residual.min(Lamports(EXPIRY_CRANK_REWARD));
+        let job_post_key = job_post.key();
+        let seeds = &[b"escrow", job_post_key.as_ref(), &[job_post.escrow_bump]];
+        match escrow_shape(&escrow_info)? {
+            EscrowShape::Legacy if residual > Lamports::ZERO => {
+                let system_program = ctx.accounts.system_program.to_account_info();
+                for (to, amount) in [(&cranker, reward), (&client, residual.checked_sub(reward)?)] {
+                    move_escrow_lamports(
+                        &system_program,
+                        &escrow_info,
+                        to,
+                        EscrowShape::Legacy,
+                        &[&seeds[..]],
+                        amount,
+                        EscrowLeg::Refund,
+                    )?;
+                }
+            }
+            EscrowShape::Legacy => {}
+            EscrowShape::Typed => {
+                escrow_info.sub_lamports(reward.0)?;
+                cranker.add_lamports(reward.0)?;
+                close_raw_account(&escrow_info, &client)?;
+            }
+        }
+        job_post.escrow_rent = Lamports::ZERO;
+
+        job_post.cancelled = true;
+        job_post.expired = true;
+        job_post.settled_at = now;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+
+        ctx.accounts.operator_stats.jobs_cancelled =
+            checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        emit!(JobCancelled {
+            job_post: job_post_key,
+            client: job_post.client,
+            refund: BaseUnits(refund),
+            timestamp: now,
+        });
+        emit!(JobExpired {
+            job_post: job_post_key,
+            cranker: cranker.key(),
+            reward,
+            timestamp: now,
+        });
+
+        msg!(
+            "⌛ Stale job '{}' expired: {} {} refunded, {} lamports to the cranker",
+            job_post.title,
+            refund,
+            job_post.symbol,
+            reward
+        );
+        Ok(())
+    }
+
+    // Client takes back the escrow of a job whose work they rejected, once the
+    // namespace's dispute window lapsed without a dispute or resubmission
+    pub fn reclaim_rejected_escrow(ctx: Context<ReclaimRejectedEscrow>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+
+        let application = &ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(application.rejected, ErrorCode::SubmissionNotRejected);
+        require!(
+            !application.submitted && !application.completed,
+            ErrorCode::WorkSubmitted
+        );
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+
+        let dispute_window_days = ctx.accounts.platform_config.dispute_window_days;
+        require!(dispute_window_days > 0, ErrorCode::DisputeWindowUnset);
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= application.rejected_at + i64::from(dispute_window_days) * SECONDS_PER_DAY,
+            ErrorCode::DisputeWindowOpen
+        );
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let refund_to = match &ctx.accounts.client_token_account {
+            Some(token_account) if !job_post.is_native() => token_account.to_account_info(),
+            _ => ctx.accounts.client.to_account_info(),
+        };
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        let refund = checked_add(checked_sub(job_post.amount.0, job_post.milestones_released.0)?, buffer)?;
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &refund_to, refund)?;
+        let client = ctx.accounts.client.to_account_info();
+        settle_sol_leg(&escrow, job_post, Pubkey::default(), None, Some(&client), 0)?;
+
+        job_post.cancelled = true;
+        job_post.settled_at = now;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+        track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+
+        ctx.accounts.operator_stats.jobs_cancelled =
+            checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        emit!(JobCancelled {
+            job_post: job_post.key(),
+            client: job_post.client,
+            refund: BaseUnits(refund),
+            timestamp: now,
+        });
+
+        msg!(
+            "🚫 Rejected job '{}' reclaimed: {} {} refunded",
+            job_post.title,
+            refund,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Client removes a hired freelancer who let their expected end date pass
+    // without submitting. Their seat reopens to new applicants, the job can be
+    // cancelled again, and their stake goes to the client.
+    pub fn remove_freelancer(ctx: Context<RemoveFreelancer>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(job_post.retainer.is_none(), ErrorCode::RetainerJob);
+        require!(job_post.hourly.is_none(), ErrorCode::HourlyJob);
+        require!(job_post.stream.is_none(), ErrorCode::StreamingJob);
+
+        let application = &mut ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(
+            !application.submitted && !application.completed,
+            ErrorCode::WorkSubmitted
+        );
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+
+        // Freelancers who gave no date of their own are held to the job's end
+        let due = if application.expected_end_date > 0 {
+            application.expected_end_date
+        } else {
+            job_post.end_date
+        };
+        let now = Clock::get()?.unix_timestamp;
+        require!(now > due, ErrorCode::DeliveryNotOverdue);
+
+        application.approved = false;
+        application.abandoned = true;
+        job_post.hires_count = job_post
+            .hires_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        job_post.is_filled = false;
+        if job_post.freelancer == Some(application.applicant) {
+            job_post.freelancer = None;
+        }
+        forfeit_application_stake(application, &ctx.accounts.client)?;
+        track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+        ctx.accounts.job_card.refresh(job_post);
+
+        emit!(FreelancerRemoved {
+            job_post: job_post.key(),
+            application: application.key(),
+            freelancer: application.applicant,
+            timestamp: now,
+        });
+        msg!(
+            "🚪 Freelancer {} removed from job '{}'; the seat is open again",
+            application.applicant,
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Client or hired freelancer proposes unwinding the engagement with a full
+    // refund to the client; the other party accepts with accept_mutual_cancel
+    pub fn request_mutual_cancel(ctx: Context<RequestMutualCancel>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+        require_mutually_cancellable(job_post, application)?;
+
+        let signer = ctx.accounts.signer.key();
+        let by_client = signer == job_post.client;
+        require!(
+            by_client || signer == application.applicant,
+            ErrorCode::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let request = &mut ctx.accounts.cancellation_request;
+        request.job_post = job_post.key();
+        request.application = application.key();
+        request.requested_by = signer;
+        request.client_signed = by_client;
+        request.freelancer_signed = !by_client;
+        request.requested_at = now;
+        request.accepted_at = 0;
+        request.bump = ctx.bumps.cancellation_request;
+
+        emit!(MutualCancelRequested {
+            job_post: job_post.key(),
+            application: application.key(),
+            requested_by: signer,
+            timestamp: now,
+        });
+        msg!("🤝 Mutual cancellation of job '{}' requested by {}", job_post.title, signer);
+        Ok(())
+    }
+
+    // The party that didn't request the cancellation signs it: the job is
+    // cancelled and everything left in escrow goes back to the client
+    pub fn accept_mutual_cancel(ctx: Context<AcceptMutualCancel>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+        require_mutually_cancellable(job_post, application)?;
+        // The split is of the real price, so a hidden one is revealed first
+        require!(job_post.amount_commitment.is_none(), ErrorCode::AmountHidden);
+
+        let request = &mut ctx.accounts.cancellation_request;
+        require!(request.accepted_at == 0, ErrorCode::CancellationAccepted);
+        let signer = ctx.accounts.signer.key();
+        if request.client_signed {
+            require_keys_eq!(signer, application.applicant, ErrorCode::Unauthorized);
+            request.freelancer_signed = true;
+        } else {
+            require_keys_eq!(signer, job_post.client, ErrorCode::Unauthorized);
+            request.client_signed = true;
+        }
+        let now = Clock::get()?.unix_timestamp;
+        request.accepted_at = now;
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let refund_to = match &ctx.accounts.client_token_account {
+            Some(token_account) if !job_post.is_native() => token_account.to_account_info(),
+            _ => ctx.accounts.client.to_account_info(),
+        };
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        let refund = checked_add(checked_sub(job_post.amount.0, job_post.milestones_released.0)?, buffer)?;
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &refund_to, refund)?;
+        let client = ctx.accounts.client.to_account_info();
+        settle_sol_leg(&escrow, job_post, Pubkey::default(), None, Some(&client), 0)?;
+
+        job_post.cancelled = true;
+        job_post.settled_at = now;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+        track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+
+        ctx.accounts.operator_stats.jobs_cancelled =
+            checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        emit!(JobCancelled {
+            job_post: job_post.key(),
+            client: job_post.client,
+            refund: BaseUnits(refund),
+            timestamp: now,
+        });
+
+        msg!(
+            "🤝 Job '{}' cancelled by both parties: {} {} refunded",
+            job_post.title,
+            refund,
+            job_post.symbol
+        );
+        Ok(())
+    }
+
+    // Client or reviewer signs off one checklist item of the hired application
+    pub fn check_item(ctx: Context<RejectSubmission>, index: u8) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+        require_reviewer(
+            &ctx.accounts.job_post,
+            application,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_review,
+        )?;
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(
+            usize::from(index) < application.checklist.len(),
+            ErrorCode::InvalidInput
+        );
+
+        application.checked_items |= 1 << index;
+
+        msg!(
+            "☑️ Checklist item {} signed off ({}/{})",
+            index,
+            application.checked_items.count_ones(),
+            application.checklist.len()
+        );
+        Ok(())
+    }
+
+    pub fn reject_submission(ctx: Context<RejectSubmission>, client_review: String) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+
+        require_reviewer(
+            job_post,
+            application,
+            ctx.accounts.approver.key(),
+            ctx.accounts.delegate.as_deref(),
+            |delegate| delegate.can_review,
+        )?;
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(application.submitted, ErrorCode::WorkNotCompleted);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+
+        application.client_review = client_review;
+        ctx.accounts.submission.status = SubmissionStatus::Rejected;
+        application.rejected = true;
+        application.rejected_at = Clock::get()?.unix_timestamp;
+        application.reviewed_at = application.rejected_at;
+        application.rejections = application.rejections.saturating_add(1);
+        application.submitted = false; // Allow resubmission
+
+        emit!(SubmissionRejected {
+            job_post: job_post.key(),
+            application: application.key(),
+            reviewer: ctx.accounts.approver.key(),
+            revision: ctx.accounts.submission.revision,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("❌ Work rejected. Feedback: {}", application.client_review);
+        Ok(())
+    }
+
+    // Client offers to close out submitted or rejected work for part of the
+    // pay (a kill fee). Nothing moves until the freelancer accepts; a new offer
+    // replaces the old one.
+    pub fn settle_partial(ctx: Context<RejectSubmission>, freelancer_bps: u16) -> Result<()> {
+        require_ctx!(freelancer_bps <= MAX_BPS, ErrorCode::InvalidInput, "freelancer_bps", MAX_BPS, freelancer_bps);
+
+        let job_post = &ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+        require_keys_eq!(ctx.accounts.approver.key(), job_post.client, ErrorCode::Unauthorized);
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require!(
+            application.submitted || application.rejected,
+            ErrorCode::WorkNotCompleted
+        );
+
+        application.settlement_offer = Some(freelancer_bps);
+
+        emit!(SettlementOffered {
+            job_post: job_post.key(),
+            application: application.key(),
+            freelancer_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "🤝 Settlement offered on '{}': {} bps to the freelancer",
+            job_post.title,
+            freelancer_bps
+        );
+        Ok(())
+    }
+
+    // Freelancer accepts the client's settlement offer, echoing its share so a
+    // replaced offer can't be accepted unseen. The seat's pay is split like a
+    // dispute resolution and the job closes without approval.
+    pub fn accept_settlement(
+        ctx: Context<AcceptSettlement>,
+        freelancer_bps: u16,
+        freshness: Option<SlotBound>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        SlotBound::require_fresh(freshness)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(job_post.amount_commitment.is_none(), ErrorCode::AmountHidden);
+        let application = &mut ctx.accounts.application;
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require!(
+            application.settlement_offer == Some(freelancer_bps),
+            ErrorCode::SettlementMismatch
+        );
+        if freelancer_bps > 0 {
+            require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+        }
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+
+        let remaining = job_post.seat_payout().0;
+        let freelancer_share = bps_of(remaining, freelancer_bps);
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        let client_share = remaining - freelancer_share + buffer;
+
+        check_release(&ctx.accounts.platform_config, job_post, application, None, freelancer_share)?;
+        release_to_freelancer(
+            &escrow,
+            job_post,
+            application,
+            ReleaseAccounts {
+                config: &ctx.accounts.platform_config,
+                operator_stats: &mut ctx.accounts.operator_stats,
+                insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                fee_treasury: ctx.accounts.fee_treasury.as_ref(),
+                tax_vault: ctx.accounts.tax_vault.as_ref(),
+                payout_guardian: None,
+                freelancer: &ctx.accounts.freelancer,
+                parking: None,
+                settlement_hooks: &ctx.accounts.settlement_hooks,
+                pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                project: ctx.accounts.project.as_mut(),
+            },
+            freelancer_share,
+            "Settlement",
+            false,
+        )?;
+        if client_share > 0 {
+            let client = ctx.accounts.client.to_account_info();
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, client_share)?;
+        }
+        settle_sol_leg(
+            &escrow,
+            job_post,
+            application.applicant,
+            ctx.accounts.freelancer_wallet.as_deref(),
+            ctx.accounts.client_wallet.as_deref(),
+            freelancer_bps,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        application.completed = true;
+        application.submitted = false;
+        application.settlement_offer = None;
+        job_post.settle_seat(now)?;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+        flag_large_payout(
+            &ctx.accounts.platform_config,
+            job_post,
+            application.key(),
+            application.applicant,
+            freelancer_share,
+        )?;
+        track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(SettlementAccepted {
+            job_post: job_post.key(),
+            application: application.key(),
+            freelancer_bps,
+            freelancer_amount: BaseUnits(freelancer_share),
+            client_amount: BaseUnits(client_share),
+            timestamp: now,
+        });
+
+        msg!(
+            "🤝 Job '{}' settled: {} to the freelancer, {} back to the client",
+            job_post.title,
+            freelancer_share,
+            client_share
+        );
+        Ok(())
+    }
+
+    // Client lets a team member manage their job posts with the given
+    // permissions; calling it again for the same member replaces them
+    pub fn add_delegate(
+        ctx: Context<AddDelegate>,
+        member: Pubkey,
+        can_approve: bool,
+        can_cancel: bool,
+        can_review: bool,
+    ) -> Result<()> {
+        require_keys_neq!(member, ctx.accounts.client.key(), ErrorCode::InvalidInput);
+
+        let delegate = &mut ctx.accounts.delegate;
+        delegate.client = ctx.accounts.client.key();
+        delegate.member = member;
+        delegate.can_approve = can_approve;
+        delegate.can_cancel = can_cancel;
+        delegate.can_review = can_review;
+        delegate.bump = ctx.bumps.delegate;
+
+        msg!(
+            "👥 Delegate {} added (approve: {}, cancel: {}, review: {})",
+            member,
+            can_approve,
+            can_cancel,
+            can_review
+        );
+        Ok(())
+    }
+
+    // Client revokes a team member's delegation, reclaiming its rent
+    pub fn remove_delegate(ctx: Context<RemoveDelegate>) -> Result<()> {
+        msg!("👥 Delegate {} removed", ctx.accounts.delegate.member);
+        Ok(())
+    }
+
+    // Client registers a reminder for one of their jobs, funding a bounty for
+    // whoever cranks it once `at` passes. One reminder per job and kind.
+    pub fn register_reminder(
+        ctx: Context<RegisterReminder>,
+        kind: ReminderKind,
+        at: i64,
+        bounty: u64,
+    ) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+        require!(bounty > 0, ErrorCode::InvalidInput);
+        let now = Clock::get()?.unix_timestamp;
+        require!(at > now, ErrorCode::InvalidDates);
+        if kind == ReminderKind::RetainerDue {
+            require!(job_post.retainer.is_some(), ErrorCode::NotARetainer);
+        }
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.client.to_account_info(),
+                to: ctx.accounts.reminder.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, bounty)?;
+
+        let reminder = &mut ctx.accounts.reminder;
+        reminder.job_post = job_post.key();
+        reminder.client = ctx.accounts.client.key();
+        reminder.kind = kind;
+        reminder.fire_at = at;
+        reminder.bounty = bounty;
+        reminder.bump = ctx.bumps.reminder;
+
+        msg!("⏰ Reminder registered for job '{}' at {}", job_post.title, at);
+        Ok(())
+    }
+
+    // Permissionless crank: fire a due reminder as an event, paying the cranker
+    // its bounty and returning the rent to the client
+    pub fn fire_reminder(ctx: Context<FireReminder>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let reminder = &ctx.accounts.reminder;
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= reminder.fire_at, ErrorCode::ReminderNotDue);
+
+        let bounty = reminder.bounty;
+        reminder.to_account_info().sub_lamports(bounty)?;
+        ctx.accounts.cranker.to_account_info().add_lamports(bounty)?;
+
+        emit!(ReminderFired {
+            job_post: reminder.job_post,
+            kind: reminder.kind,
+            fire_at: reminder.fire_at,
+            cranker: ctx.accounts.cranker.key(),
+            bounty: Lamports(bounty),
+            timestamp: now,
+        });
+        msg!("⏰ Reminder fired; {} lamports paid to the cranker", bounty);
+        Ok(())
+    }
+
+    // Client withdraws a reminder that hasn't fired, reclaiming rent and bounty
+    pub fn cancel_reminder(_ctx: Context<CancelReminder>) -> Result<()> {
+        msg!("⏰ Reminder cancelled");
+        Ok(())
+    }
+
+    // Client caps the lamports their approvals can release per UTC day. Lowering
+    // the cap applies at once; raising or removing it (zero) waits out a
+    // RELEASE_CAP_TIMELOCK so a stolen approval key can't lift it.
+    pub fn set_release_cap(ctx: Context<SetReleaseCap>, daily_cap: u64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let release_limit = &mut ctx.accounts.release_limit;
+
+        if release_limit.client == Pubkey::default() {
+            release_limit.client = ctx.accounts.client.key();
+            release_limit.bump = ctx.bumps.release_limit;
+        }
+
+        let current = release_limit.effective_cap(now);
+        if current != 0 && (daily_cap == 0 || daily_cap > current) {
+            release_limit.daily_cap = current;
+            release_limit.pending_cap = Some(daily_cap);
+            release_limit.pending_effective_at = now + RELEASE_CAP_TIMELOCK;
+            msg!(
+                "⏳ Release cap change to {} effective at {}",
+                daily_cap,
+                release_limit.pending_effective_at
+            );
+        } else {
+            release_limit.daily_cap = daily_cap;
+            release_limit.pending_cap = None;
+            release_limit.pending_effective_at = 0;
+            msg!("🚦 Daily release cap set to {}", daily_cap);
+        }
+        Ok(())
+    }
+
+    // Client or freelancer pays an allowlisted expert the operator's fee for an
+    // advisory opinion on the hired application's work. The fee is held in the
+    // request until the opinion is posted.
+    pub fn request_second_opinion(ctx: Context<RequestSecondOpinion>, expert: Pubkey) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let config = &ctx.accounts.platform_config;
+        let job_post = &ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+        let party = ctx.accounts.party.key();
+
+        require!(
+            party == job_post.client || party == application.applicant,
+            ErrorCode::Unauthorized
+        );
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require!(config.expert_reviewers.contains(&expert), ErrorCode::ExpertNotAllowed);
+        require!(
+            expert != job_post.client && expert != application.applicant,
+            ErrorCode::ExpertNotAllowed
+        );
+
+        let fee = config.second_opinion_fee;
+        if fee > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.party.to_account_info(),
+                        to: ctx.accounts.second_opinion.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+
+        let second_opinion = &mut ctx.accounts.second_opinion;
+        second_opinion.application = application.key();
+        second_opinion.requested_by = party;
+        second_opinion.expert = expert;
+        second_opinion.fee = fee;
+        second_opinion.opinion_hash = None;
+        second_opinion.requested_at = Clock::get()?.unix_timestamp;
+        second_opinion.bump = ctx.bumps.second_opinion;
+
+        msg!("🧐 Second opinion on job '{}' requested from {}", job_post.title, expert);
+        Ok(())
+    }
+
+    // Expert posts the hash of their opinion and collects the fee
+    pub fn post_second_opinion(
+        ctx: Context<PostSecondOpinion>,
+        opinion_hash: [u8; 32],
+    ) -> Result<()> {
+        let second_opinion = &mut ctx.accounts.second_opinion;
+        require!(second_opinion.opinion_hash.is_none(), ErrorCode::OpinionAlreadyPosted);
+
+        second_opinion.opinion_hash = Some(opinion_hash);
+        let fee = second_opinion.fee;
+        second_opinion.to_account_info().sub_lamports(fee)?;
+        ctx.accounts.expert.add_lamports(fee)?;
+
+        msg!("🧐 Second opinion posted by {}", ctx.accounts.expert.key());
+        Ok(())
+    }
+
+    // Requester takes back the fee and rent of an opinion never posted in time
+    pub fn cancel_second_opinion(ctx: Context<CancelSecondOpinion>) -> Result<()> {
+        let second_opinion = &ctx.accounts.second_opinion;
+        require!(second_opinion.opinion_hash.is_none(), ErrorCode::OpinionAlreadyPosted);
+        require!(
+            Clock::get()?.unix_timestamp >= second_opinion.requested_at + SECOND_OPINION_TIMEOUT,
+            ErrorCode::OpinionPending
+        );
+
+        msg!("🧐 Second opinion request from {} cancelled", second_opinion.requested_by);
+        Ok(())
+    }
+
+    // Client lets a technical reviewer approve or reject submissions on their
+    // behalf. Funds still only move between escrow, freelancer and client.
+    pub fn set_job_reviewer(ctx: Context<SetJobReviewer>, reviewer: Option<Pubkey>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(
+            reviewer.is_none() || reviewer != job_post.freelancer,
+            ErrorCode::InvalidInput
+        );
+
+        job_post.reviewer = reviewer;
+
+        msg!("🔍 Reviewer for job '{}' set to {:?}", job_post.title, reviewer);
+        Ok(())
+    }
+
+    // Client names the arbiter for disputes on an open job, overriding the
+    // namespace default. `None` falls back to the default.
+    pub fn set_job_arbiter(ctx: Context<SetJobArbiter>, arbiter: Option<Pubkey>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(arbiter != Some(job_post.client), ErrorCode::InvalidInput);
+
+        job_post.arbiter = arbiter;
+
+        msg!("⚖️ Arbiter for job '{}' set to {:?}", job_post.title, arbiter);
+        Ok(())
+    }
+
+    // Client names a second key of theirs to co-sign releases of this job
+    // instead of the namespace's platform signer. `None` falls back to it.
+    pub fn set_job_co_signer(ctx: Context<SetJobArbiter>, co_signer: Option<Pubkey>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+        require!(!job_post.cancelled, ErrorCode::JobCancelled);
+        require!(co_signer != Some(job_post.client), ErrorCode::InvalidInput);
+
+        job_post.co_signer = co_signer;
+
+        msg!("✍️ Co-signer for job '{}' set to {:?}", job_post.title, co_signer);
+        Ok(())
+    }
+
+    // Client or freelancer of a filled job escalates to the arbiter, freezing
+    // approvals and submissions until it is resolved
+    pub fn open_dispute(ctx: Context<OpenDispute>, reason: String) -> Result<()> {
+        require_feature(&ctx.accounts.platform_config, FEATURE_DISPUTES)?;
+        require!(
+            !reason.is_empty() && reason.len() <= MAX_DISPUTE_TEXT_LEN,
+            ErrorCode::InvalidInput
+        );
+
+        let job_post = &ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+        let party = ctx.accounts.party.key();
+
+        require!(
+            party == job_post.client || party == application.applicant,
+            ErrorCode::Unauthorized
+        );
+        require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+
+        let now = Clock::get()?.unix_timestamp;
+        let dispute_window_days = ctx.accounts.platform_config.dispute_window_days;
+        if party == application.applicant && application.rejected && dispute_window_days > 0 {
+            require!(
+                now < application.rejected_at + i64::from(dispute_window_days) * SECONDS_PER_DAY,
+                ErrorCode::DisputeWindowClosed
+            );
+        }
+
+        let arbiter = job_post
+            .arbiter
+            .unwrap_or(ctx.accounts.platform_config.default_arbiter);
+        require!(arbiter != Pubkey::default(), ErrorCode::NoArbiter);
+        require!(
+            arbiter != job_post.client && arbiter != application.applicant,
+            ErrorCode::NoArbiter
+        );
+
+        application.disputed = true;
+        ctx.accounts.operator_stats.disputes_opened =
+            checked_add(ctx.accounts.operator_stats.disputes_opened, 1)?;
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.job_post = job_post.key();
+        dispute.application = application.key();
+        dispute.arbiter = arbiter;
+        dispute.opened_by = party;
+        dispute.reason = reason;
+        dispute.response = String::new();
+        dispute.opened_at = now;
+        dispute.responded_at = 0;
+        dispute.resolved_at = 0;
+        dispute.freelancer_bps = 0;
+        dispute.second_opinion = SecondOpinion::load(&ctx.accounts.second_opinion)?
+            .and_then(|second_opinion| second_opinion.opinion_hash);
+        dispute.submission_link =
+            latest_submission_link(application, ctx.accounts.submission.as_deref())?;
+        dispute.content_hash = application.content_hash;
+        dispute.submitted_at = application.submitted_at;
+        dispute.client_review = application.client_review.clone();
+        dispute.rejected_at = if application.rejected { application.rejected_at } else { 0 };
+        dispute.rejections = application.rejections;
+        dispute.bump = ctx.bumps.dispute;
+        dispute.insurance_paid = Lamports::ZERO;
+        dispute.shortfall = 0;
+
+        emit!(DisputeOpened {
+            job_post: job_post.key(),
+            application: application.key(),
+            opened_by: party,
+            arbiter,
+            timestamp: now,
+        });
+
+        msg!("⚖️ Dispute opened on job '{}' by {}", job_post.title, party);
+        Ok(())
+    }
+
+    // Freelancer contests a rejection once the job's revisions are used up,
+    // so the work can no longer be resubmitted
+    pub fn dispute_rejection(ctx: Context<OpenDispute>, reason: String) -> Result<()> {
+        let application = &ctx.accounts.application;
+        require_keys_eq!(
+            ctx.accounts.party.key(),
+            application.applicant,
+            ErrorCode::Unauthorized
+        );
+        require!(application.rejected, ErrorCode::SubmissionNotRejected);
+        require!(
+            ctx.accounts.job_post.revisions_exhausted(application),
+            ErrorCode::RevisionsRemaining
+        );
+        open_dispute(ctx, reason)
+    }
+
+    // Client disputes work that was released without their review, within the
+    // namespace's window. Only the holdback is at stake; what the freelancer
+    // was already paid is never clawed back.
+    pub fn dispute_auto_release(ctx: Context<OpenDispute>, reason: String) -> Result<()> {
+        require_feature(&ctx.accounts.platform_config, FEATURE_DISPUTES)?;
+        require!(
+            !reason.is_empty() && reason.len() <= MAX_DISPUTE_TEXT_LEN,
+            ErrorCode::InvalidInput
+        );
+
+        let job_post = &ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+        let party = ctx.accounts.party.key();
+        require_keys_eq!(party, job_post.client, ErrorCode::Unauthorized);
+        require!(application.holdback > 0, ErrorCode::NoHeldPayout);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < application.holdback_until, ErrorCode::DisputeWindowClosed);
+
+        let arbiter = job_post
+            .arbiter
+            .unwrap_or(ctx.accounts.platform_config.default_arbiter);
+        require!(arbiter != Pubkey::default(), ErrorCode::NoArbiter);
+        require!(
+            arbiter != job_post.client && arbiter != application.applicant,
+            ErrorCode::NoArbiter
+        );
+
+        application.disputed = true;
+        ctx.accounts.operator_stats.disputes_opened =
+            checked_add(ctx.accounts.operator_stats.disputes_opened, 1)?;
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.job_post = job_post.key();
+        dispute.application = application.key();
+        dispute.arbiter = arbiter;
+        dispute.opened_by = party;
+        dispute.reason = reason;
+        dispute.response = String::new();
+        dispute.opened_at = now;
+        dispute.responded_at = 0;
+        dispute.resolved_at = 0;
+        dispute.freelancer_bps = 0;
+        dispute.second_opinion = SecondOpinion::load(&ctx.accounts.second_opinion)?
+            .and_then(|second_opinion| second_opinion.opinion_hash);
+        dispute.submission_link =
+            latest_submission_link(application, ctx.accounts.submission.as_deref())?;
+        dispute.content_hash = application.content_hash;
+        dispute.submitted_at = application.submitted_at;
+        dispute.client_review = String::new();
+        dispute.rejected_at = 0;
+        dispute.rejections = application.rejections;
+        dispute.bump = ctx.bumps.dispute;
+        dispute.insurance_paid = Lamports::ZERO;
+        dispute.shortfall = 0;
+
+        emit!(DisputeOpened {
+            job_post: job_post.key(),
+            application: application.key(),
+            opened_by: party,
+            arbiter,
+            timestamp: now,
+        });
+
+        msg!("⚖️ Auto-release on job '{}' disputed; {} held back", job_post.title, application.holdback);
+        Ok(())
+    }
+
+    // Arbiter splits a disputed holdback: `freelancer_bps` of it to the
+    // freelancer, the rest back to the client
+    pub fn resolve_holdback_dispute(
+        ctx: Context<SettleHoldback>,
+        freelancer_bps: u16,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(freelancer_bps <= MAX_BPS, ErrorCode::InvalidInput, "freelancer_bps", MAX_BPS, freelancer_bps);
+        let dispute = ctx.accounts.dispute.as_mut().ok_or(ErrorCode::InvalidAccount)?;
+        require_keys_eq!(ctx.accounts.authority.key(), dispute.arbiter, ErrorCode::Unauthorized);
+        require!(dispute.resolved_at == 0, ErrorCode::DisputeAlreadyResolved);
+
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &mut ctx.accounts.application;
+        let holdback = application.holdback;
+        require!(holdback > 0, ErrorCode::NoHeldPayout);
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let hooks = SettlementHooks::load(&ctx.accounts.settlement_hooks)?;
+        let mut hook_payload = SettlementHookPayload {
+            job_post: job_post.key(),
+            application: application.key(),
+            client: job_post.client,
+            freelancer: application.applicant,
+            payment_mint: job_post.payment_mint,
+            gross: holdback,
+            paid: 0,
+        };
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+            ctx.accounts.pre_hook_program.as_ref(),
+            "pre_settlement",
+            &hook_payload,
+            &[job_post.to_account_info(), application.to_account_info()],
+        )?;
+
+        escrow.release_holdback(holdback)?;
+        let freelancer_amount = bps_of(holdback, freelancer_bps);
+        let client_amount = holdback - freelancer_amount;
+        if freelancer_amount > 0 {
+            escrow.pay(&ctx.accounts.freelancer, application.applicant, freelancer_amount)?;
+        }
+        record_project_spend(ctx.accounts.project.as_mut(), job_post, freelancer_amount, false)?;
+        if client_amount > 0 {
+            let client = ctx.accounts.client.to_account_info();
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, client_amount)?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        dispute.freelancer_bps = freelancer_bps;
+        dispute.resolved_at = now;
+        application.holdback = 0;
+        job_post.holdbacks = job_post.holdbacks.checked_sub(BaseUnits(holdback))?;
+        ctx.accounts.operator_stats.disputes_resolved =
+            checked_add(ctx.accounts.operator_stats.disputes_resolved, 1)?;
+
+        hook_payload.paid = freelancer_amount;
+        job_post.exit(&crate::ID)?;
+        application.exit(&crate::ID)?;
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+            ctx.accounts.post_hook_program.as_ref(),
+            "post_settlement",
+            &hook_payload,
+            &[job_post.to_account_info(), application.to_account_info()],
+        )?;
+
+        emit!(DisputeResolved {
+            job_post: job_post.key(),
+            application: application.key(),
+            arbiter: dispute.arbiter,
+            freelancer_bps,
+            freelancer_amount: BaseUnits(freelancer_amount),
+            client_amount: BaseUnits(client_amount),
+            shortfall: BaseUnits::ZERO,
+            timestamp: now,
+        });
+        msg!("⚖️ Holdback on job '{}' resolved: {} bps to the freelancer", job_post.title, freelancer_bps);
+        Ok(())
+    }
+
+    // Permissionless crank: pays an undisputed holdback to the freelancer once
+    // the client's dispute window has passed
+    pub fn release_holdback(ctx: Context<SettleHoldback>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &mut ctx.accounts.application;
+        let holdback = application.holdback;
+        require!(holdback > 0, ErrorCode::NoHeldPayout);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require!(
+            Clock::get()?.unix_timestamp >= application.holdback_until,
+            ErrorCode::DisputeWindowOpen
+        );
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let hooks = SettlementHooks::load(&ctx.accounts.settlement_hooks)?;
+        let mut hook_payload = SettlementHookPayload {
+            job_post: job_post.key(),
+            application: application.key(),
+            client: job_post.client,
+            freelancer: application.applicant,
+            payment_mint: job_post.payment_mint,
+            gross: holdback,
+            paid: 0,
+        };
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+            ctx.accounts.pre_hook_program.as_ref(),
+            "pre_settlement",
+            &hook_payload,
+            &[job_post.to_account_info(), application.to_account_info()],
+        )?;
+
+        escrow.release_holdback(holdback)?;
+        escrow.pay(&ctx.accounts.freelancer, application.applicant, holdback)?;
+        record_project_spend(ctx.accounts.project.as_mut(), job_post, holdback, false)?;
+        application.holdback = 0;
+        job_post.holdbacks = job_post.holdbacks.checked_sub(BaseUnits(holdback))?;
+
+        hook_payload.paid = holdback;
+        job_post.exit(&crate::ID)?;
+        application.exit(&crate::ID)?;
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+            ctx.accounts.post_hook_program.as_ref(),
+            "post_settlement",
+            &hook_payload,
+            &[job_post.to_account_info(), application.to_account_info()],
+        )?;
+
+        msg!("🔓 Holdback of {} {} released to {}", holdback, job_post.symbol, application.applicant);
+        Ok(())
+    }
+
+    // The other party answers the dispute before the arbiter rules
+    pub fn respond_to_dispute(ctx: Context<RespondToDispute>, response: String) -> Result<()> {
+        require!(
+            !response.is_empty() && response.len() <= MAX_DISPUTE_TEXT_LEN,
+            ErrorCode::InvalidInput
+        );
+
+        let dispute = &mut ctx.accounts.dispute;
+        let party = ctx.accounts.party.key();
+
+        require!(dispute.resolved_at == 0, ErrorCode::DisputeAlreadyResolved);
+        require!(dispute.responded_at == 0, ErrorCode::DisputeAlreadyAnswered);
+        require!(party != dispute.opened_by, ErrorCode::Unauthorized);
+        require!(
+            party == ctx.accounts.job_post.client || party == ctx.accounts.application.applicant,
+            ErrorCode::Unauthorized
+        );
+
+        dispute.response = response;
+        dispute.responded_at = Clock::get()?.unix_timestamp;
+
+        msg!("⚖️ Dispute on job '{}' answered", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // Arbiter settles a dispute, paying `freelancer_bps` of what remains in
+    // escrow to the freelancer (less withholding) and the rest to the client
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        freelancer_bps: u16,
+        freshness: Option<SlotBound>,
+    ) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        SlotBound::require_fresh(freshness)?;
+        require_ctx!(freelancer_bps <= MAX_BPS, ErrorCode::InvalidInput, "freelancer_bps", MAX_BPS, freelancer_bps);
+
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let application = &ctx.accounts.application;
+        let dispute = &mut ctx.accounts.dispute;
+        require!(dispute.resolved_at == 0, ErrorCode::DisputeAlreadyResolved);
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+        require!(job_post.amount_commitment.is_none(), ErrorCode::AmountHidden);
+        // Disputes over auto-released work only reach the holdback
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        if freelancer_bps > 0 {
+            require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+        }
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+
+        let remaining = job_post.seat_payout().0;
+        let award = bps_of(remaining, freelancer_bps);
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        // An escrow drained below what's owed pays the freelancer first; what
+        // it can't cover is left for an insurance claim
+        let available = escrow.balance()?;
+        let freelancer_share = award.min(available);
+        let shortfall = award - freelancer_share;
+        let client_share = (remaining - award + buffer).min(available - freelancer_share);
+
+        release_to_freelancer(
+            &escrow,
+            job_post,
+            application,
+            ReleaseAccounts {
+                config: &ctx.accounts.platform_config,
+                operator_stats: &mut ctx.accounts.operator_stats,
+                insurance_pool: ctx.accounts.insurance_pool.as_mut(),
+                fee_treasury: ctx.accounts.fee_treasury.as_ref(),
+                tax_vault: ctx.accounts.tax_vault.as_ref(),
+                payout_guardian: None,
+                freelancer: &ctx.accounts.freelancer,
+                parking: ctx.accounts.claimable_vault.as_ref().map(|vault| Parking {
+                    vault,
+                    namespace: job_post.namespace,
+                    claimant: application.applicant,
+                    rent_payer: &ctx.accounts.arbiter,
+                }),
+                settlement_hooks: &ctx.accounts.settlement_hooks,
+                pre_hook_program: ctx.accounts.pre_hook_program.as_ref(),
+                post_hook_program: ctx.accounts.post_hook_program.as_ref(),
+                project: ctx.accounts.project.as_mut(),
+            },
+            freelancer_share,
+            "Dispute",
+            false,
+        )?;
+        if client_share > 0 {
+            let client = ctx.accounts.client.to_account_info();
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, client_share)?;
+        }
+        settle_sol_leg(
+            &escrow,
+            job_post,
+            application.applicant,
+            ctx.accounts.freelancer_wallet.as_deref(),
+            ctx.accounts.client_wallet.as_deref(),
+            freelancer_bps,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        dispute.freelancer_bps = freelancer_bps;
+        dispute.resolved_at = now;
+        dispute.shortfall = shortfall;
+        job_post.settle_seat(now)?;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+        ctx.accounts.operator_stats.disputes_resolved =
+            checked_add(ctx.accounts.operator_stats.disputes_resolved, 1)?;
+        let half = MAX_BPS / 2;
+        if freelancer_bps != half {
+            let freelancer_won = freelancer_bps > half;
+            if let Some(freelancer_stats) = ctx.accounts.freelancer_stats.as_mut() {
+                freelancer_stats.record_dispute(freelancer_won)?;
+            }
+            if let Some(client_stats) = ctx.accounts.client_stats.as_mut() {
+                client_stats.record_dispute(!freelancer_won)?;
+            }
+        }
+        flag_large_payout(
+            &ctx.accounts.platform_config,
+            job_post,
+            application.key(),
+            application.applicant,
+            freelancer_share,
+        )?;
+        track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        emit!(DisputeResolved {
+            job_post: job_post.key(),
+            application: application.key(),
+            arbiter: dispute.arbiter,
+            freelancer_bps,
+            freelancer_amount: BaseUnits(freelancer_share),
+            client_amount: BaseUnits(client_share),
+            shortfall: BaseUnits(shortfall),
+            timestamp: now,
+        });
+
+        msg!(
+            "⚖️ Dispute on job '{}' resolved: {} bps to the freelancer",
+            job_post.title,
+            freelancer_bps
+        );
+        Ok(())
+    }
+
+    // Freelancer rates the client 1–5, once per completed job
+    pub fn rate_client(ctx: Context<RateClient>, rating: u8) -> Result<()> {
+        require_ctx!((1..=MAX_RATING).contains(&rating), ErrorCode::InvalidRating, "rating", MAX_RATING, rating);
+
+        let application = &mut ctx.accounts.application;
+        require!(application.completed, ErrorCode::JobNotSettled);
+        require!(application.client_rating.is_none(), ErrorCode::AlreadyRated);
+
+        application.client_rating = Some(rating);
+        ctx.accounts.client_stats.record_rating(rating)?;
+
+        msg!("⭐ Client of '{}' rated {}", ctx.accounts.job_post.title, rating);
+        Ok(())
+    }
+
+    // After payout, client or freelancer records a private 0–10 satisfaction
+    // score as sha256(score || salt || party). Only the hash is stored; the
+    // score stays off-chain until `reveal_satisfaction`.
+    pub fn record_satisfaction(ctx: Context<RecordSatisfaction>, commitment: [u8; 32]) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+        let party = ctx.accounts.party.key();
+        require!(application.completed, ErrorCode::JobNotSettled);
+
+        let slot = if party == ctx.accounts.job_post.client {
+            &mut application.client_satisfaction
+        } else if party == application.applicant {
+            &mut application.freelancer_satisfaction
+        } else {
+            return err!(ErrorCode::Unauthorized);
+        };
+        require!(slot.is_none(), ErrorCode::SatisfactionAlreadyRecorded);
+        *slot = Some(commitment);
+
+        msg!("🗳️ Satisfaction recorded for job '{}'", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // The party opens their recorded score to add it to the operator's
+    // aggregate. It's checked against the hash and counted, never stored or
+    // emitted.
+    pub fn reveal_satisfaction(
+        ctx: Context<RecordSatisfaction>,
+        score: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        require_ctx!(
+            score <= MAX_SATISFACTION_SCORE,
+            ErrorCode::InvalidInput,
+            "score",
+            MAX_SATISFACTION_SCORE,
+            score
+        );
+
+        let application = &mut ctx.accounts.application;
+        let party = ctx.accounts.party.key();
+        let (recorded, side) = if party == ctx.accounts.job_post.client {
+            (application.client_satisfaction, REVIEW_CLIENT)
+        } else if party == application.applicant {
+            (application.freelancer_satisfaction, REVIEW_FREELANCER)
+        } else {
+            return err!(ErrorCode::Unauthorized);
+        };
+        let recorded = recorded.ok_or(ErrorCode::SatisfactionNotRecorded)?;
+        require!(
+            application.satisfaction_revealed & side == 0,
+            ErrorCode::SatisfactionAlreadyRevealed
+        );
+        let expected = hashv(&[&[score], &salt, party.as_ref()]);
+        require!(expected.to_bytes() == recorded, ErrorCode::SatisfactionCommitmentMismatch);
+        application.satisfaction_revealed |= side;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.satisfaction_responses =
+            checked_add(operator_stats.satisfaction_responses, 1)?;
+        operator_stats.satisfaction_total =
+            checked_add(operator_stats.satisfaction_total, u64::from(score))?;
+        if score >= 9 {
+            operator_stats.promoters = checked_add(operator_stats.promoters, 1)?;
+        } else if score <= 6 {
+            operator_stats.detractors = checked_add(operator_stats.detractors, 1)?;
+        }
+
+        msg!("🗳️ Satisfaction counted for job '{}'", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // After completion, client or freelancer seals their review of the other
+    // side. Neither can be read until both are sealed, or the namespace's
+    // sealed review days passed since the first, so neither side writes theirs
+    // after seeing the other's.
+    pub fn seal_review(ctx: Context<SealedReview>, commitment: [u8; 32]) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+        require!(application.completed, ErrorCode::JobNotSettled);
+
+        let slot = if ctx.accounts.author.key() == ctx.accounts.job_post.client {
+            &mut application.client_sealed_review
+        } else if ctx.accounts.author.key() == application.applicant {
+            &mut application.freelancer_sealed_review
+        } else {
+            return err!(ErrorCode::Unauthorized);
+        };
+        require!(slot.is_none(), ErrorCode::ReviewAlreadySealed);
+        *slot = Some(commitment);
+
+        if application.sealed_reviews_open_at == 0 {
+            let days = ctx.accounts.platform_config.sealed_review_days;
+            application.sealed_reviews_open_at = if days == 0 {
+                i64::MAX
+            } else {
+                Clock::get()?.unix_timestamp + i64::from(days) * SECONDS_PER_DAY
+            };
+        }
+
+        msg!("🔏 Review sealed for job '{}'", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // Author publishes their sealed review once reviews are open
+    pub fn reveal_review(
+        ctx: Context<SealedReview>,
+        rating: u8,
+        text: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        require_ctx!((1..=MAX_RATING).contains(&rating), ErrorCode::InvalidRating, "rating", MAX_RATING, rating);
+        require_ctx!(text.len() <= MAX_REVIEW_LEN, ErrorCode::InvalidInput, "text", MAX_REVIEW_LEN, text.len());
+
+        let application = &mut ctx.accounts.application;
+        let author = ctx.accounts.author.key();
+        let (sealed, side) = if author == ctx.accounts.job_post.client {
+            (application.client_sealed_review, REVIEW_CLIENT)
+        } else if author == application.applicant {
+            (application.freelancer_sealed_review, REVIEW_FREELANCER)
+        } else {
+            return err!(ErrorCode::Unauthorized);
+        };
+        let sealed = sealed.ok_or(ErrorCode::ReviewNotSealed)?;
+        require!(application.reviews_revealed & side == 0, ErrorCode::ReviewAlreadyRevealed);
+
+        let both_sealed = application.client_sealed_review.is_some()
+            && application.freelancer_sealed_review.is_some();
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            both_sealed || now >= application.sealed_reviews_open_at,
+            ErrorCode::ReviewsStillSealed
+        );
+        let expected = hashv(&[&[rating], text.as_bytes(), &salt, author.as_ref()]);
+        require!(expected.to_bytes() == sealed, ErrorCode::ReviewCommitmentMismatch);
+
+        application.reviews_revealed |= side;
+
+        emit!(ReviewRevealed {
+            job_post: ctx.accounts.job_post.key(),
+            application: application.key(),
+            author,
+            rating,
+            text,
+            timestamp: now,
+        });
+
+        msg!("📣 Review of job '{}' published", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // Client rewrites their review of approved work while the feedback window
+    // opened at approval is still running
+    pub fn edit_client_review(ctx: Context<EditFeedback>, client_review: String) -> Result<()> {
+        require_ctx!(
+            client_review.len() <= MAX_REVIEW_LEN,
+            ErrorCode::InvalidInput,
+            "client_review",
+            MAX_REVIEW_LEN,
+            client_review.len()
+        );
+        require_keys_eq!(
+            ctx.accounts.author.key(),
+            ctx.accounts.job_post.client,
+            ErrorCode::Unauthorized
+        );
+        let application = &mut ctx.accounts.application;
+        let now = require_feedback_open(application)?;
+
+        application.client_review = client_review.clone();
+
+        emit!(FeedbackUpdated {
+            job_post: ctx.accounts.job_post.key(),
+            application: application.key(),
+            author: ctx.accounts.author.key(),
+            text: client_review,
+            timestamp: now,
+        });
+
+        msg!("✏️ Review of job '{}' edited", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // Freelancer attaches a public reply to the client's review, changeable
+    // until the feedback window closes
+    pub fn respond_to_review(ctx: Context<EditFeedback>, response: String) -> Result<()> {
+        require_ctx!(
+            response.len() <= MAX_REVIEW_LEN,
+            ErrorCode::InvalidInput,
+            "response",
+            MAX_REVIEW_LEN,
+            response.len()
+        );
+        let application = &mut ctx.accounts.application;
+        require_keys_eq!(
+            ctx.accounts.author.key(),
+            application.applicant,
+            ErrorCode::Unauthorized
+        );
+        let now = require_feedback_open(application)?;
+
+        application.freelancer_response = response.clone();
+
+        emit!(FeedbackUpdated {
+            job_post: ctx.accounts.job_post.key(),
+            application: application.key(),
+            author: ctx.accounts.author.key(),
+            text: response,
+            timestamp: now,
+        });
+
+        msg!("💬 Freelancer replied to the review of '{}'", ctx.accounts.job_post.title);
+        Ok(())
+    }
+
+    // Read-only: the settlement `approve_submission` would perform right now,
+    // or an auto-release with its holdback, returned without moving funds so
+    // frontends can show it before signing
+    pub fn preview_settlement(
+        ctx: Context<PreviewSettlement>,
+        auto_released: bool,
+    ) -> Result<SettlementBreakdown> {
+        let job_post = &ctx.accounts.job_post;
+        require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+
+        Ok(settlement_breakdown(
+            job_post,
+            &ctx.accounts.application,
+            &ctx.accounts.platform_config,
+            DonationPreference::load(&ctx.accounts.donation_preference)?.as_ref(),
+            auto_released,
+        ))
+    }
+
+    // Freelancer names a guardian who must acknowledge payouts of at least
+    // `threshold` (in the job's units) before they leave escrow. A default
+    // guardian key removes the requirement. Changing an existing guardian needs
+    // that guardian's signature.
+    pub fn set_payout_guardian(
+        ctx: Context<SetPayoutGuardian>,
+        guardian: Pubkey,
+        threshold: u64,
+    ) -> Result<()> {
+        let payout_guardian = &mut ctx.accounts.payout_guardian;
+
+        if payout_guardian.guardian != Pubkey::default() {
+            require!(
+                ctx.accounts
+                    .current_guardian
+                    .as_ref()
+                    .is_some_and(|signer| signer.key() == payout_guardian.guardian),
+                ErrorCode::Unauthorized
+            );
+        }
+        require_keys_neq!(guardian, ctx.accounts.freelancer.key(), ErrorCode::InvalidInput);
+
+        payout_guardian.freelancer = ctx.accounts.freelancer.key();
+        payout_guardian.guardian = guardian;
+        payout_guardian.threshold = threshold;
+        payout_guardian.bump = ctx.bumps.payout_guardian;
+
+        msg!("🛡️ Payout guardian set to {} above {}", guardian, threshold);
+        Ok(())
+    }
+
+    // User confirms the wallet their payouts go to. Team shares owed to users
+    // without one are parked in a claimable vault instead.
+    pub fn register_payout_account(
+        ctx: Context<RegisterPayoutAccount>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        let payout_account = &mut ctx.accounts.payout_account;
+        payout_account.owner = ctx.accounts.owner.key();
+        payout_account.destination = destination;
+        payout_account.bump = ctx.bumps.payout_account;
+
+        msg!("🏦 Payouts of {} go to {}", payout_account.owner, destination);
+        Ok(())
+    }
+
+    // Hired or hopeful freelancer splits their net payout with teammates, each
+    // taking `bps` of it. Only for SOL jobs, until the work is approved.
+    pub fn set_payout_team(ctx: Context<SetPayoutTeam>, team: Vec<TeamShare>) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+        require!(ctx.accounts.job_post.is_native(), ErrorCode::NativeOnly);
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require_ctx!(
+            team.len() <= MAX_TEAM_MEMBERS,
+            ErrorCode::InvalidInput,
+            "team",
+            MAX_TEAM_MEMBERS,
+            team.len()
+        );
+        let total_bps = team.iter().map(|share| u32::from(share.bps)).sum::<u32>();
+        require_ctx!(total_bps <= u32::from(MAX_BPS), ErrorCode::InvalidInput, "bps", MAX_BPS, total_bps);
+        for (i, share) in team.iter().enumerate() {
+            require!(
+                share.bps > 0
+                    && share.recipient != application.applicant
+                    && share.recipient != ctx.accounts.job_post.client
+                    && team[..i].iter().all(|other| other.recipient != share.recipient),
+                ErrorCode::InvalidInput
+            );
+        }
+
+        application.team = team;
+
+        msg!(
+            "👥 {} shares payouts with {} teammates",
+            application.applicant,
+            application.team.len()
+        );
+        Ok(())
+    }
+
+    // Applicant attaches a portfolio piece, case study or certification to
+    // their application while it is still under consideration
+    pub fn add_attachment(
+        ctx: Context<SetPayoutTeam>,
+        hash: [u8; 32],
+        uri: String,
+        label: String,
+    ) -> Result<()> {
+        let application = &mut ctx.accounts.application;
+        require!(!application.approved, ErrorCode::ApplicationAlreadyApproved);
+        require_ctx!(
+            application.attachments.len() < MAX_ATTACHMENTS,
+            ErrorCode::InvalidInput,
+            "attachments",
+            MAX_ATTACHMENTS,
+            application.attachments.len() + 1
+        );
+        require_ctx!(
+            !uri.is_empty() && uri.len() <= MAX_ATTACHMENT_URI_LEN,
+            ErrorCode::InvalidInput,
+            "uri",
+            MAX_ATTACHMENT_URI_LEN,
+            uri.len()
+        );
+        require_ctx!(
+            label.len() <= MAX_ATTACHMENT_LABEL_LEN,
+            ErrorCode::InvalidInput,
+            "label",
+            MAX_ATTACHMENT_LABEL_LEN,
+            label.len()
+        );
+
+        application.attachments.push(Attachment { hash, uri, label });
+
+        msg!(
+            "📎 Attachment {} added to the application of {}",
+            application.attachments.len(),
+            application.applicant
+        );
+        Ok(())
+    }
+
+    // Recipient takes what's owed to them from their claimable vault, which
+    // closes and returns its rent to whoever opened it
+    pub fn claim_funds(ctx: Context<ClaimFunds>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let vault = &mut ctx.accounts.claimable_vault;
+        let amount = vault.lamports_owed;
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        vault.sub_lamports(amount)?;
+        ctx.accounts.recipient.add_lamports(amount)?;
+        vault.lamports_owed = 0;
+
+        emit!(FundsClaimed {
+            recipient: vault.recipient,
+            amount: Lamports(amount),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("📬 {} claimed {} lamports", vault.recipient, amount);
+        Ok(())
+    }
+
+    // Guardian acknowledges a held payout, releasing it from escrow to the freelancer
+    pub fn acknowledge_payout(ctx: Context<AcknowledgePayout>) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        let amount = job_post.held_payout.0;
+        require!(amount > 0, ErrorCode::NoHeldPayout);
+
+        let freelancer = job_post
+            .held_for
+            .or(job_post.freelancer)
+            .ok_or(ErrorCode::InvalidAccount)?;
+        require_keys_eq!(
+            ctx.accounts.payout_guardian.freelancer,
+            freelancer,
+            ErrorCode::InvalidAccount
+        );
+
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let hooks = SettlementHooks::load(&ctx.accounts.settlement_hooks)?;
+        let mut hook_payload = SettlementHookPayload {
+            job_post: job_post.key(),
+            application: Pubkey::default(),
+            client: job_post.client,
+            freelancer,
+            payment_mint: job_post.payment_mint,
+            gross: amount,
+            paid: 0,
+        };
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+            ctx.accounts.pre_hook_program.as_ref(),
+            "pre_settlement",
+            &hook_payload,
+            &[job_post.to_account_info()],
+        )?;
+
+        escrow.pay(&ctx.accounts.freelancer, freelancer, amount)?;
+        job_post.held_payout = BaseUnits::ZERO;
+        job_post.held_for = None;
+
+        hook_payload.paid = amount;
+        job_post.exit(&crate::ID)?;
+        call_settlement_hook(
+            hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+            ctx.accounts.post_hook_program.as_ref(),
+            "post_settlement",
+            &hook_payload,
+            &[job_post.to_account_info()],
+        )?;
+
+        msg!("🛡️ Held payout of {} {} released", amount, job_post.symbol);
+        Ok(())
+    }
+
+    // Client cancels job and gets refund (only if no freelancer approved)
+    pub fn cancel_job(ctx: Context<CancelJob>) -> Result<()> {
+        cancel_job_post(ctx, false)
+    }
+
+    // Like `cancel_job`, but the refund goes to the client's credits, plus
+    // the namespace's credit refund bonus as far as the operator's SOL fee
+    // balance covers it. SOL jobs only.
+    pub fn cancel_job_for_credits(ctx: Context<CancelJob>) -> Result<()> {
+        cancel_job_post(ctx, true)
+    }
+
+    // Create a marketplace config namespaced under the signing operator, who
+    // becomes its admin. Jobs and stats are scoped to the namespace they use.
+    pub fn initialize_platform(ctx: Context<InitializePlatform>) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.namespace = ctx.accounts.admin.key();
+        config.admin = ctx.accounts.admin.key();
+        config.onramps = Vec::new();
+        config.retention_policy = RetentionPolicy::KeepFull;
+        config.retention_days = 0;
+        config.withholding_bps = 0;
+        config.tax_vault = Pubkey::default();
+        config.charities = Vec::new();
+        config.allow_self_dealing = false;
+        config.high_value_threshold = 0;
+        config.min_account_age_days = 0;
+        config.trial_amount = 0;
+        config.sol_usd_feed = [0; 32];
+        config.features = 0;
+        config.attestation_issuers = Vec::new();
+        config.default_arbiter = Pubkey::default();
+        config.fee_bps = 0;
+        config.fee_treasury = config.admin;
+        config.review_window_days = 0;
+        config.dispute_window_days = 0;
+        config.sealed_review_days = 0;
+        config.expert_reviewers = Vec::new();
+        config.second_opinion_fee = 0;
+        config.large_payout_threshold = 0;
+        config.co_sign_threshold = 0;
+        config.co_signer = Pubkey::default();
+        config.credit_refund_bonus_bps = 0;
+        config.min_job_amount = 0;
+        config.max_job_amount = 0;
+        config.min_job_duration = 0;
+        config.paused = false;
+        config.application_stake = 0;
+        config.application_stake_threshold = 0;
+        config.strict_language_match = false;
+        config.strict_timezone_match = false;
+        config.insurance_enabled = false;
+        config.bump = ctx.bumps.platform_config;
+
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        operator_stats.namespace = config.namespace;
+        operator_stats.bump = ctx.bumps.operator_stats;
+        operator_stats.last_heartbeat_at = 0;
+
+        msg!(
+            "🛠️ Platform initialized. Namespace: {} Admin: {}",
+            config.namespace,
+            config.admin
+        );
+        Ok(())
+    }
+
+    // Admin adds or removes a wallet allowed to fund escrows for clients
+    pub fn set_onramp(ctx: Context<UpdatePlatformConfig>, onramp: Pubkey, allowed: bool) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+
+        if allowed {
+            if !config.onramps.contains(&onramp) {
+                require!(
+                    config.onramps.len() < MAX_ONRAMPS,
+                    ErrorCode::OnrampAllowlistFull
+                );
+                config.onramps.push(onramp);
+            }
+        } else {
+            config.onramps.retain(|key| key != &onramp);
+        }
+
+        msg!("🛠️ On-ramp {} allowed: {}", onramp, allowed);
+        Ok(())
+    }
+
+    // Admin allows or disallows a program whose reputation attestations freelancers
+    // may import
+    pub fn set_attestation_issuer(
+        ctx: Context<UpdatePlatformConfig>,
+        issuer_program: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+
+        if allowed {
+            if !config.attestation_issuers.contains(&issuer_program) {
+                require!(
+                    config.attestation_issuers.len() < MAX_ATTESTATION_ISSUERS,
+                    ErrorCode::IssuerAllowlistFull
+                );
+                config.attestation_issuers.push(issuer_program);
+            }
+        } else {
+            config.attestation_issuers.retain(|key| key != &issuer_program);
+        }
+
+        msg!("🛠️ Attestation issuer {} allowed: {}", issuer_program, allowed);
+        Ok(())
+    }
+
+    // Admin adds or removes an expert who can be paid for second opinions, and
+    // sets the fee every opinion costs
+    pub fn set_expert_reviewer(
+        ctx: Context<UpdatePlatformConfig>,
+        expert: Pubkey,
+        allowed: bool,
+        fee: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+
+        if allowed {
+            if !config.expert_reviewers.contains(&expert) {
+                require!(
+                    config.expert_reviewers.len() < MAX_EXPERT_REVIEWERS,
+                    ErrorCode::ExpertAllowlistFull
+                );
+                config.expert_reviewers.push(expert);
+            }
+        } else {
+            config.expert_reviewers.retain(|key| key != &expert);
+        }
+        config.second_opinion_fee = fee;
+
+        msg!("🛠️ Expert reviewer {} allowed: {}, fee {}", expert, allowed, fee);
+        Ok(())
+    }
+
+    // Admin chooses what happens to settled jobs and their applications
+    pub fn set_retention_policy(
+        ctx: Context<UpdatePlatformConfig>,
+        policy: RetentionPolicy,
+        retention_days: u16,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.retention_policy = policy;
+        config.retention_days = retention_days;
+
+        msg!(
+            "🛠️ Retention policy set to {:?} after {} days",
+            policy,
+            retention_days
+        );
+        Ok(())
+    }
+
+    // Admin sets the share of every payout withheld for tax and the vault it goes to
+    pub fn set_withholding(
+        ctx: Context<UpdatePlatformConfig>,
+        withholding_bps: u16,
+        tax_vault: Pubkey,
+    ) -> Result<()> {
+        require!(withholding_bps <= MAX_BPS, ErrorCode::InvalidBps);
+        require!(
+            withholding_bps == 0 || tax_vault != Pubkey::default(),
+            ErrorCode::InvalidAccount
+        );
+
+        let config = &mut ctx.accounts.platform_config;
+        config.withholding_bps = withholding_bps;
+        config.tax_vault = tax_vault;
+
+        msg!("🛠️ Withholding set to {} bps into {}", withholding_bps, tax_vault);
+        Ok(())
+    }
+
+    // Admin lets clients apply to and approve their own jobs in this namespace
+    // Admin sets the protocol fee taken from every payout and the wallet whose
+    // token accounts receive it for token jobs. SOL fees accrue in the
+    // operator stats vault for `withdraw_operator_fees`.
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        fee_bps: u16,
+        fee_treasury: Pubkey,
+    ) -> Result<()> {
+        require_ctx!(fee_bps <= MAX_FEE_BPS, ErrorCode::InvalidInput, "fee_bps", MAX_FEE_BPS, fee_bps);
+
+        let config = &mut ctx.accounts.platform_config;
+        config.fee_bps = fee_bps;
+        config.fee_treasury = fee_treasury;
+
+        msg!("🛠️ Protocol fee set to {} bps, treasury {}", fee_bps, fee_treasury);
+        Ok(())
+    }
+
+    // Client opens their credits account in a namespace, so cancellation
+    // refunds can go to credits before any were granted
+    pub fn open_credits(ctx: Context<OpenCredits>) -> Result<()> {
+        let credits = &mut ctx.accounts.credits;
+        credits.namespace = ctx.accounts.platform_config.namespace;
+        credits.client = ctx.accounts.client.key();
+        credits.balance = 0;
+        credits.bump = ctx.bumps.credits;
+
+        msg!("🎁 Credits opened for {}", credits.client);
+        Ok(())
+    }
+
+    // Admin grants a client promotional credits, which the fee treasury backs
+    // by depositing that many lamports on the client's credits account. The
+    // client spends them by passing the account when funding a SOL job.
+    pub fn grant_credits(ctx: Context<GrantCredits>, amount: u64) -> Result<()> {
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.credits.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let credits = &mut ctx.accounts.credits;
+        credits.namespace = ctx.accounts.platform_config.namespace;
+        credits.client = ctx.accounts.client.key();
+        credits.balance = checked_add(credits.balance, amount)?;
+        credits.bump = ctx.bumps.credits;
+
+        emit!(CreditsGranted {
+            namespace: credits.namespace,
+            client: credits.client,
+            amount: Lamports(amount),
+            balance: Lamports(credits.balance),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🎁 {} lamports of credits granted to {}", amount, credits.client);
+        Ok(())
+    }
+
+    // Admin sets how long clients have to review a submission before the
+    // freelancer may claim it, unless the job sets its own window. Zero turns
+    // claims off for jobs without one.
+    pub fn set_review_window(ctx: Context<UpdatePlatformConfig>, days: u16) -> Result<()> {
+        ctx.accounts.platform_config.review_window_days = days;
+
+        msg!("🛠️ Review window set to {} days", days);
+        Ok(())
+    }
+
+    // Admin sets how long freelancers have to dispute a rejection before the
+    // client may take the escrow back. Zero leaves rejections open.
+    pub fn set_dispute_window(ctx: Context<UpdatePlatformConfig>, days: u16) -> Result<()> {
+        ctx.accounts.platform_config.dispute_window_days = days;
+
+        msg!("🛠️ Dispute window set to {} days", days);
+        Ok(())
+    }
+
+    // Admin sets how long a sealed review waits for the other side's before
+    // it may be revealed anyway. Zero waits for both.
+    pub fn set_sealed_review_days(ctx: Context<UpdatePlatformConfig>, days: u16) -> Result<()> {
+        ctx.accounts.platform_config.sealed_review_days = days;
+
+        msg!("🛠️ Sealed reviews open alone after {} days", days);
+        Ok(())
+    }
+
+    // Admin sets the share of the platform fee paid to referrers. The cut comes
+    // out of the fee, never the freelancer's pay.
+    pub fn set_referral_rate(ctx: Context<UpdatePlatformConfig>, bps: u16) -> Result<()> {
+        require_ctx!(bps <= MAX_BPS, ErrorCode::InvalidInput, "bps", MAX_BPS, bps);
+        ctx.accounts.platform_config.referral_bps = bps;
+
+        msg!("🛠️ Referral rate set to {} bps of the platform fee", bps);
+        Ok(())
+    }
+
+    // Admin sets how much of a payout released without the client's review
+    // is held back, and for how many days the client may dispute it
+    pub fn set_release_holdback(
+        ctx: Context<UpdatePlatformConfig>,
+        bps: u16,
+        days: u16,
+    ) -> Result<()> {
+        require_ctx!(bps <= MAX_BPS, ErrorCode::InvalidInput, "bps", MAX_BPS, bps);
+        require!((bps == 0) == (days == 0), ErrorCode::InvalidInput);
+        let config = &mut ctx.accounts.platform_config;
+        config.release_holdback_bps = bps;
+        config.release_dispute_days = days;
+
+        msg!("🛠️ Auto-release holdback set to {} bps for {} days", bps, days);
+        Ok(())
+    }
+
+    // Anyone opens a referrer's stats in a namespace, needed before work by
+    // someone they referred can be approved
+    pub fn open_referral_stats(ctx: Context<OpenReferralStats>, namespace: Pubkey) -> Result<()> {
+        let referral_stats = &mut ctx.accounts.referral_stats;
+        referral_stats.namespace = namespace;
+        referral_stats.referrer = ctx.accounts.referrer.key();
+        referral_stats.bump = ctx.bumps.referral_stats;
+
+        msg!("🤝 Referral stats opened for {}", referral_stats.referrer);
+        Ok(())
+    }
+
+    // Admin sets the arbiter for disputes on jobs that don't name their own
+    pub fn set_default_arbiter(ctx: Context<UpdatePlatformConfig>, arbiter: Pubkey) -> Result<()> {
+        ctx.accounts.platform_config.default_arbiter = arbiter;
+
+        msg!("🛠️ Default arbiter set to {}", arbiter);
+        Ok(())
+    }
+
+    pub fn set_self_dealing(ctx: Context<UpdatePlatformConfig>, allowed: bool) -> Result<()> {
+        ctx.accounts.platform_config.allow_self_dealing = allowed;
+
+        msg!("🛠️ Self-dealing allowed: {}", allowed);
+        Ok(())
+    }
+
+    // Admin pauses or resumes the namespace. While paused, nothing is funded
+    // or paid out, but clients can still take refunds so no funds are trapped.
+    pub fn set_pause(ctx: Context<UpdatePlatformConfig>, paused: bool) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.paused = paused;
+
+        emit!(PauseChanged {
+            namespace: config.namespace,
+            admin: ctx.accounts.admin.key(),
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🚨 Namespace {}: {}", config.namespace, if paused { "paused" } else { "resumed" });
+        Ok(())
+    }
+
+    // Admin halts all fund movement on one job while it is investigated,
+    // recording an operator-defined reason code
+    pub fn freeze_job(ctx: Context<FreezeJob>, reason: u16) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+
+        job_post.frozen = true;
+        job_post.freeze_reason = reason;
+
+        emit!(JobFreezeChanged {
+            job_post: job_post.key(),
+            admin: ctx.accounts.admin.key(),
+            frozen: true,
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🧊 Job '{}' frozen, reason {}", job_post.title, reason);
+        Ok(())
+    }
+
+    // Admin lifts a freeze once the investigation is over
+    pub fn unfreeze_job(ctx: Context<FreezeJob>, reason: u16) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(job_post.frozen, ErrorCode::JobNotFrozen);
+
+        job_post.frozen = false;
+        job_post.freeze_reason = reason;
+
+        emit!(JobFreezeChanged {
+            job_post: job_post.key(),
+            admin: ctx.accounts.admin.key(),
+            frozen: false,
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🔥 Job '{}' unfrozen, reason {}", job_post.title, reason);
+        Ok(())
+    }
+
+    // Admin moderates a listing that breaks policy. Hidden jobs take no
+    // applications or hires; a takedown also replaces the title and
+    // description with their hash, freeing that rent to the client. Neither
+    // touches the escrow, so the client can still cancel for a refund.
+    pub fn moderate_job(
+        ctx: Context<ModerateJob>,
+        action: ModerationAction,
+        reason: u16,
+    ) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        match action {
+            ModerationAction::Hide => {
+                require!(!job_post.hidden, ErrorCode::JobHidden);
+                job_post.hidden = true;
+            }
+            ModerationAction::Unhide => {
+                require!(job_post.hidden, ErrorCode::JobNotHidden);
+                require!(!job_post.title.is_empty(), ErrorCode::JobTakenDown);
+                job_post.hidden = false;
+            }
+            ModerationAction::Takedown => {
+                require!(!job_post.title.is_empty(), ErrorCode::JobTakenDown);
+                if job_post.content_hash.is_none() {
+                    job_post.content_hash = Some(
+                        hashv(&[job_post.title.as_bytes(), job_post.description.as_bytes()])
+                            .to_bytes(),
+                    );
+                }
+                let freed = job_post.title.len() + job_post.description.len();
+                job_post.title = String::new();
+                job_post.description = String::new();
+                job_post.hidden = true;
+
+                shrink_account(
+                    &job_post.to_account_info(),
+                    &ctx.accounts.client.to_account_info(),
+                    freed,
+                )?;
+            }
+        }
+        job_post.moderation_reason = reason;
+
+        emit!(JobModerated {
+            job_post: job_post.key(),
+            admin: ctx.accounts.admin.key(),
+            action,
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("🚩 Job {} moderated: {:?}, reason {}", job_post.key(), action, reason);
+        Ok(())
+    }
+
+    // Admin sets the lamport release that raises a LargePayout event for
+    // monitoring. Zero turns flagging off.
+    pub fn set_large_payout_threshold(
+        ctx: Context<UpdatePlatformConfig>,
+        threshold: u64,
+    ) -> Result<()> {
+        ctx.accounts.platform_config.large_payout_threshold = threshold;
+
+        msg!("🛠️ Large payout threshold set to {} lamports", threshold);
+        Ok(())
+    }
+
+    // Admin requires a second approval from `co_signer`, or the job's own
+    // co-signer, before releasing jobs of at least `threshold` lamports (and
+    // all token jobs). A zero threshold turns it off.
+    pub fn set_co_sign_policy(
+        ctx: Context<UpdatePlatformConfig>,
+        threshold: u64,
+        co_signer: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.co_sign_threshold = threshold;
+        config.co_signer = co_signer;
+
+        msg!("🛠️ Releases of {} lamports or more co-signed by {}", threshold, co_signer);
+        Ok(())
+    }
+
+    // Admin bounds the lamports new SOL jobs may escrow and how short any new
+    // job may run. Zero leaves a bound off.
+    pub fn set_job_bounds(
+        ctx: Context<UpdatePlatformConfig>,
+        min_amount: u64,
+        max_amount: u64,
+        min_duration: i64,
+    ) -> Result<()> {
+        require!(
+            max_amount == 0 || min_amount <= max_amount,
+            ErrorCode::InvalidAmount
+        );
+        require_ctx!(min_duration >= 0, ErrorCode::InvalidInput, "min_duration", 0, min_duration);
+        let config = &mut ctx.accounts.platform_config;
+        config.min_job_amount = min_amount;
+        config.max_job_amount = max_amount;
+        config.min_job_duration = min_duration;
+
+        msg!(
+            "🛠️ Job amounts bounded to {}..={} lamports, durations to at least {}s",
+            min_amount,
+            max_amount,
+            min_duration
+        );
+        Ok(())
+    }
+
+    // Admin sets the bonus on cancellation refunds taken as credits
+    pub fn set_credit_refund_bonus(ctx: Context<UpdatePlatformConfig>, bps: u16) -> Result<()> {
+        require_ctx!(
+            bps <= MAX_CREDIT_BONUS_BPS,
+            ErrorCode::InvalidInput,
+            "bps",
+            MAX_CREDIT_BONUS_BPS,
+            bps
+        );
+        ctx.accounts.platform_config.credit_refund_bonus_bps = bps;
+
+        msg!("🛠️ Credit refund bonus set to {} bps", bps);
+        Ok(())
+    }
+
+    // Admin makes applying to jobs of at least `threshold` lamports cost a
+    // refundable `stake`, deterring mass applications. Zero stake turns it off.
+    pub fn set_application_stake(
+        ctx: Context<UpdatePlatformConfig>,
+        threshold: u64,
+        stake: u64,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.application_stake_threshold = threshold;
+        config.application_stake = stake;
+
+        msg!(
+            "🛠️ Application stake: {} lamports on jobs from {} lamports",
+            stake,
+            threshold
+        );
+        Ok(())
+    }
+
+    // Admin decides whether applicants must speak every language a job requires,
+    // or are only flagged with a LanguageMismatch event
+    pub fn set_language_matching(ctx: Context<UpdatePlatformConfig>, strict: bool) -> Result<()> {
+        ctx.accounts.platform_config.strict_language_match = strict;
+
+        msg!("🛠️ Strict language matching: {}", strict);
+        Ok(())
+    }
+
+    // Admin decides whether applicants must work from a job's UTC offset
+    // range, or are only flagged with a TimezoneMismatch event
+    pub fn set_timezone_matching(ctx: Context<UpdatePlatformConfig>, strict: bool) -> Result<()> {
+        ctx.accounts.platform_config.strict_timezone_match = strict;
+
+        msg!("🛠️ Strict time zone matching: {}", strict);
+        Ok(())
+    }
+
+    // Admin requires freelancers approved for jobs of at least `threshold` lamports
+    // to have a prior completion or be active for `min_account_age_days`.
+    // A zero threshold turns the gate off.
+    pub fn set_high_value_gate(
+        ctx: Context<UpdatePlatformConfig>,
+        threshold: u64,
+        min_account_age_days: u16,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.high_value_threshold = threshold;
+        config.min_account_age_days = min_account_age_days;
+
+        msg!(
+            "🛠️ High-value gate: {} lamports, {} days minimum age",
+            threshold,
+            min_account_age_days
+        );
+        Ok(())
+    }
+
+    // Admin sets the Pyth SOL/USD feed whose price is recorded at funding and
+    // payout of SOL jobs; all zero turns price recording off
+    pub fn set_price_feed(ctx: Context<UpdatePlatformConfig>, sol_usd_feed: [u8; 32]) -> Result<()> {
+        ctx.accounts.platform_config.sol_usd_feed = sol_usd_feed;
+
+        msg!("🛠️ SOL/USD price feed updated");
+        Ok(())
+    }
+
+    // Admin enables or disables optional subsystems, staging their rollout
+    pub fn set_features(ctx: Context<UpdatePlatformConfig>, features: u64) -> Result<()> {
+        require!(features & !FEATURE_ALL == 0, ErrorCode::InvalidInput);
+        ctx.accounts.platform_config.features = features;
+
+        msg!("🛠️ Features set to {:#b}", features);
+        Ok(())
+    }
+
+    // Admin sets the fixed amount of paid trials; zero disables trials
+    pub fn set_trial_amount(ctx: Context<UpdatePlatformConfig>, trial_amount: u64) -> Result<()> {
+        ctx.accounts.platform_config.trial_amount = trial_amount;
+
+        msg!("🛠️ Trial amount set to {} lamports", trial_amount);
+        Ok(())
+    }
+
+    // Admin adds or removes a charity freelancers may donate part of their payouts to
+    pub fn set_charity(ctx: Context<SetCharity>, allowed: bool) -> Result<()> {
+        let charity = ctx.accounts.charity.key();
+        let config = &mut ctx.accounts.platform_config;
+
+        if allowed {
+            if !config.charities.contains(&charity) {
+                require!(
+                    config.charities.len() < MAX_CHARITIES,
+                    ErrorCode::CharityAllowlistFull
+                );
+                config.charities.push(charity);
+            }
+        } else {
+            config.charities.retain(|key| key != &charity);
+        }
+
+        let charity_totals = &mut ctx.accounts.charity_totals;
+        charity_totals.namespace = config.namespace;
+        charity_totals.charity = charity;
+
+        msg!("🛠️ Charity {} allowed: {}", charity, allowed);
+        Ok(())
+    }
+
+    // Freelancer opts in to donating `bps` of every payout to an allowlisted charity
+    pub fn set_donation_preference(
+        ctx: Context<SetDonationPreference>,
+        charity: Pubkey,
+        bps: u16,
+    ) -> Result<()> {
+        require!(bps <= MAX_BPS, ErrorCode::InvalidBps);
+        require!(
+            ctx.accounts.platform_config.charities.contains(&charity),
+            ErrorCode::CharityNotAllowed
+        );
+
+        let preference = &mut ctx.accounts.donation_preference;
+        preference.freelancer = ctx.accounts.freelancer.key();
+        preference.charity = charity;
+        preference.bps = bps;
+
+        msg!(
+            "💝 {} donates {} bps of payouts to {}",
+            preference.freelancer,
+            bps,
+            charity
+        );
+        Ok(())
+    }
+
+    // Permissionless crank enforcing the retention policy on a settled job post
+    pub fn apply_job_retention(ctx: Context<ApplyJobRetention>) -> Result<()> {
+        let config = &ctx.accounts.platform_config;
+        let job_post = &mut ctx.accounts.job_post;
+
+        require!(job_post.settled_at != 0, ErrorCode::JobNotSettled);
+        require!(
+            job_post.held_payout == BaseUnits::ZERO && job_post.holdbacks == BaseUnits::ZERO,
+            ErrorCode::PayoutHeld
+        );
+        require_retention_due(config, job_post.settled_at)?;
+
+        match config.retention_policy {
+            RetentionPolicy::KeepFull => return err!(ErrorCode::RetentionNotApplicable),
+            RetentionPolicy::HashOnly => {
+                require!(job_post.content_hash.is_none(), ErrorCode::RetentionNotApplicable);
+
+                // Title stays so the closed-out job can still be recognized
+                job_post.content_hash = Some(
+                    hashv(&[job_post.title.as_bytes(), job_post.description.as_bytes()])
+                        .to_bytes(),
+                );
+                let freed = job_post.description.len();
+                job_post.description = String::new();
+
+                shrink_account(
+                    &job_post.to_account_info(),
+                    &ctx.accounts.client.to_account_info(),
+                    freed,
+                )?;
+                msg!("🧹 Job post {} reduced to its content hash", job_post.key());
+            }
+            RetentionPolicy::AutoClose => {
+                require_auto_closable(job_post, ctx.accounts.escrow.lamports())?;
+                unindex_for_user(ctx.accounts.client_index.as_mut(), job_post.key())?;
+                job_post.close(ctx.accounts.client.to_account_info())?;
+                msg!("🧹 Job post {} closed by retention policy", job_post.key());
+            }
+        }
+        Ok(())
+    }
+
+    // Permissionless crank enforcing the retention policy on an application of a settled job
+    pub fn apply_application_retention(ctx: Context<ApplyApplicationRetention>) -> Result<()> {
+        let config = &ctx.accounts.platform_config;
+        let job_post = &ctx.accounts.job_post;
+        let application = &mut ctx.accounts.application;
+
+        require!(job_post.settled_at != 0, ErrorCode::JobNotSettled);
+        require!(
+            job_post.held_payout == BaseUnits::ZERO && job_post.holdbacks == BaseUnits::ZERO,
+            ErrorCode::PayoutHeld
+        );
+        require_retention_due(config, job_post.settled_at)?;
+        return_application_stake(application, &ctx.accounts.applicant)?;
+
+        match config.retention_policy {
+            RetentionPolicy::KeepFull => return err!(ErrorCode::RetentionNotApplicable),
+            RetentionPolicy::HashOnly => {
+                require!(
+                    application.content_hash.is_none(),
+                    ErrorCode::RetentionNotApplicable
+                );
+
+                application.content_hash = Some(
+                    hashv(&[
+                        application.resume_link.as_bytes(),
+                        application.client_review.as_bytes(),
+                    ])
+                    .to_bytes(),
+                );
+                let freed = application.resume_link.len() + application.client_review.len();
+                application.resume_link = String::new();
+                application.client_review = String::new();
+
+                shrink_account(
+                    &application.to_account_info(),
+                    &ctx.accounts.rent_receiver.to_account_info(),
+                    freed,
+                )?;
+                msg!("🧹 Application {} reduced to its content hash", application.key());
+            }
+            RetentionPolicy::AutoClose => {
+                unindex_for_user(ctx.accounts.freelancer_index.as_mut(), application.key())?;
+                ctx.accounts.job_post.applications =
+                    ctx.accounts.job_post.applications.saturating_sub(1);
+                application.close(ctx.accounts.rent_receiver.to_account_info())?;
+                msg!("🧹 Application {} closed by retention policy", application.key());
+            }
+        }
+        Ok(())
+    }
+
+    // Rent payer of an application closes it once it can no longer change: its
+    // job settled, or another freelancer was hired
+    pub fn close_application(ctx: Context<CloseApplication>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let applicant = ctx.accounts.application.applicant;
+
+        require!(
+            job_post.settled_at != 0
+                || ctx.accounts.application.declined
+                || (job_post.is_filled && !job_post.is_hired(&ctx.accounts.application)),
+            ErrorCode::ApplicationNotTerminal
+        );
+        require!(
+            job_post.held_payout == BaseUnits::ZERO && job_post.holdbacks == BaseUnits::ZERO,
+            ErrorCode::PayoutHeld
+        );
+
+        job_post.applications = job_post.applications.saturating_sub(1);
+        return_application_stake(&mut ctx.accounts.application, &ctx.accounts.applicant)?;
+        unindex_for_user(ctx.accounts.freelancer_index.as_mut(), ctx.accounts.application.key())?;
+
+        msg!("🧹 Application of {} to '{}' closed", applicant, job_post.title);
+        Ok(())
+    }
+
+    // Freelancer closes one of their submission revisions once its job
+    // settled, returning its rent
+    pub fn close_submission(ctx: Context<CloseSubmission>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        require!(job_post.settled_at != 0, ErrorCode::ApplicationNotTerminal);
+        require!(
+            job_post.held_payout == BaseUnits::ZERO && job_post.holdbacks == BaseUnits::ZERO,
+            ErrorCode::PayoutHeld
+        );
+
+        msg!(
+            "🧹 Revision {} of a submission to '{}' closed",
+            ctx.accounts.submission.revision,
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Freelancer pulls an application they no longer want, returning its rent.
+    // A hired freelancer may only walk away after a rejected submission, which
+    // reopens the job, and the best bid of an open auction must stand until it
+    // closes; the closed account can no longer be approved.
+    pub fn withdraw_application(ctx: Context<WithdrawApplication>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let application = &ctx.accounts.application;
+
+        require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+        require!(!application.submitted, ErrorCode::WorkSubmitted);
+        require!(!application.disputed, ErrorCode::ApplicationDisputed);
+        require!(
+            !application.approved || application.rejected,
+            ErrorCode::ApplicationAlreadyApproved
+        );
+        // The auction would otherwise close on a bid nobody stands behind
+        require!(
+            job_post.auction.is_none_or(|auction| {
+                auction.closed
+                    || auction
+                        .best_bid
+                        .is_none_or(|bid| bid.application != application.key())
+            }),
+            ErrorCode::BestBidStanding
+        );
+
+        if job_post.is_hired(application) {
+            job_post.hires_count = job_post
+                .hires_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            job_post.is_filled = false;
+            job_post.freelancer = None;
+            track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+        }
+        job_post.applications = job_post.applications.saturating_sub(1);
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+        return_application_stake(&mut ctx.accounts.application, &ctx.accounts.applicant)?;
+        unindex_for_user(ctx.accounts.freelancer_index.as_mut(), ctx.accounts.application.key())?;
+        let application = &ctx.accounts.application;
+
+        emit!(ApplicationWithdrawn {
+            job_post: job_post.key(),
+            application: application.key(),
+            applicant: application.applicant,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("↩️ {} withdrew from '{}'", application.applicant, job_post.title);
+        Ok(())
+    }
+
+    // Client closes a settled job once all its applications are closed,
+    // sweeping whatever is left in the escrow back to themselves
+    pub fn close_job_post(ctx: Context<CloseJobPost>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+
+        require!(job_post.settled_at != 0, ErrorCode::JobNotSettled);
+        require!(
+            job_post.held_payout == BaseUnits::ZERO && job_post.holdbacks == BaseUnits::ZERO,
+            ErrorCode::PayoutHeld
+        );
+        require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+        unindex_for_user(ctx.accounts.client_index.as_mut(), job_post.key())?;
+
+        let job_post_key = job_post.key();
+        let seeds = &[b"escrow", job_post_key.as_ref(), &[job_post.escrow_bump]];
+        let signer_seeds = &[&seeds[..]];
+        let escrow = ctx.accounts.escrow.to_account_info();
+        let client = ctx.accounts.client.to_account_info();
+
+        if let Some(payment_mint) = job_post.payment_mint {
+            let (Some(vault), Some(mint), Some(token_program), Some(client_token_account)) = (
+                &ctx.accounts.escrow_vault,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                &ctx.accounts.client_token_account,
+            ) else {
+                return err!(ErrorCode::InvalidAccount);
+            };
+            require_keys_eq!(mint.key(), payment_mint, ErrorCode::InvalidAccount);
+            require_keys_eq!(
+                client_token_account.owner,
+                job_post.client,
+                ErrorCode::InvalidAccount
+            );
+            require_keys_eq!(
+                vault.key(),
+                get_associated_token_address_with_program_id(
+                    &escrow.key(),
+                    &payment_mint,
+                    &token_program.key(),
+                ),
+                ErrorCode::InvalidAccount
+            );
+
+            if vault.amount > 0 {
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: vault.to_account_info(),
+                            mint: mint.to_account_info(),
+                            to: client_token_account.to_account_info(),
+                            authority: escrow.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    vault.amount,
+                    mint.decimals,
+                )?;
+            }
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: client.clone(),
+                    authority: escrow.clone(),
+                },
+                signer_seeds,
+            ))?;
+        }
+
+        return_unspent_credits(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.credits.as_mut(),
+        )?;
+        let swept = Lamports(escrow.lamports());
+        match escrow_shape(&escrow)? {
+            EscrowShape::Legacy if swept > Lamports::ZERO => move_escrow_lamports(
+                &ctx.accounts.system_program.to_account_info(),
+                &escrow,
+                &client,
+                EscrowShape::Legacy,
+                signer_seeds,
+                swept,
+                EscrowLeg::Refund,
+            )?,
+            EscrowShape::Legacy => {}
+            EscrowShape::Typed => close_raw_account(&escrow, &client)?,
+        }
+
+        msg!(
+            "🧹 Job post '{}' closed; {} lamports swept from escrow",
+            job_post.title,
+            swept
+        );
+        Ok(())
+    }
+
+    // Permissionless: once a job is completed or cancelled, empty its escrow
+    // back to the client, returning the rent reserve and anything funded
+    // beyond what was paid out. The job post itself stays open.
+    pub fn sweep_escrow(ctx: Context<SweepEscrow>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        require!(!job_post.frozen, ErrorCode::JobFrozen);
+        require!(job_post.status().is_terminal(), ErrorCode::JobNotSettled);
+        require!(
+            job_post.held_payout == BaseUnits::ZERO && job_post.holdbacks == BaseUnits::ZERO,
+            ErrorCode::PayoutHeld
+        );
+
+        let escrow = ctx.accounts.escrow.to_account_info();
+        let client = ctx.accounts.client.to_account_info();
+        require!(escrow.lamports() > 0, ErrorCode::NothingToSweep);
+        return_unspent_credits(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.credits.as_mut(),
+        )?;
+        let swept = Lamports(escrow.lamports());
+
+        let job_post_key = job_post.key();
+        let seeds = &[b"escrow", job_post_key.as_ref(), &[job_post.escrow_bump]];
+        match escrow_shape(&escrow)? {
+            EscrowShape::Legacy => move_escrow_lamports(
+                &ctx.accounts.system_program.to_account_info(),
+                &escrow,
+                &client,
+                EscrowShape::Legacy,
+                &[&seeds[..]],
+                swept,
+                EscrowLeg::Refund,
+            )?,
+            EscrowShape::Typed => close_raw_account(&escrow, &client)?,
+        }
+        require!(escrow.lamports() == 0, ErrorCode::InvalidEscrowAccount);
+        job_post.escrow_rent = Lamports::ZERO;
+
+        emit!(EscrowSwept {
+            job_post: job_post_key,
+            client: job_post.client,
+            amount: swept,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "🧹 {} lamports swept from the escrow of '{}'",
+            swept,
+            job_post.title
+        );
+        Ok(())
+    }
+
+    // Permissionless: upgrade a job's legacy 0-byte escrow PDA in place into a
+    // typed `Escrow` account. Escrowed lamports stay on the same address; the
+    // payer covers the rent of the new data so the job's funds are untouched.
+    pub fn migrate_escrow_v1_to_v2(ctx: Context<MigrateEscrow>) -> Result<()> {
+        let escrow = ctx.accounts.escrow.to_account_info();
+        require!(
+            escrow_shape(&escrow)? == EscrowShape::Legacy,
+            ErrorCode::EscrowAlreadyMigrated
+        );
+
+        let job_post_key = ctx.accounts.job_post.key();
+        let escrow_bump = ctx.accounts.job_post.escrow_bump;
+        let space = 8 + Escrow::INIT_SPACE;
+        // Whatever the legacy escrow holds counts as deposited
+        let deposited = escrow.lamports();
+
+        let rent = Rent::get()?.minimum_balance(space);
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: escrow.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, rent)?;
+        ctx.accounts.job_post.escrow_rent = Lamports(rent);
+
+        init_typed_escrow(
+            &escrow,
+            &ctx.accounts.system_program.to_account_info(),
+            job_post_key,
+            escrow_bump,
+            deposited,
+        )?;
+
+        msg!("🔁 Escrow of job {} migrated to v2", job_post_key);
+        Ok(())
+    }
+
+    // Permissionless: upgrade a job post, application or user stats account
+    // written under an older layout to the current one. The account grows in
+    // place to the current size, the payer covering any extra rent; fields
+    // added since read as zero, then the current version is stamped. Posts
+    // and applications still in the first release's layout are decoded as
+    // such and rewritten field by field: a post joins the namespace of the
+    // passed config, its client paying, and an application's submitted work
+    // moves into its revision 0 submission.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        let account = ctx.accounts.account.to_account_info();
+        let payer = ctx.accounts.payer.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let discriminator: [u8; 8] = account
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ErrorCode::InvalidAccount)?;
+
+        let (from_version, to_version) = if discriminator == *JobPost::DISCRIMINATOR
+            && account.data_len() == 8 + LegacyJobPost::INIT_SPACE
+        {
+            let legacy = LegacyJobPost::deserialize(&mut &account.try_borrow_data()?[8..])?;
+            require_keys_eq!(payer.key(), legacy.client, ErrorCode::Unauthorized);
+            let config = ctx
+                .accounts
+                .platform_config
+                .as_ref()
+                .ok_or(ErrorCode::InvalidAccount)?;
+            rewrite_layout(&account, &payer, &system_program, &legacy.upgrade(config.namespace))?;
+            (0, JOB_POST_VERSION)
+        } else if discriminator == *JobPost::DISCRIMINATOR {
+            let from = migrate_layout::<JobPost>(&account, &payer, &system_program, |job_post| {
+                std::mem::replace(&mut job_post.version, JOB_POST_VERSION)
+            })?;
+            (from, JOB_POST_VERSION)
+        } else if discriminator == *Application::DISCRIMINATOR
+            && account.data_len() == 8 + LegacyApplication::INIT_SPACE
+        {
+            let legacy = LegacyApplication::deserialize(&mut &account.try_borrow_data()?[8..])?;
+            let (application, submission) = legacy.upgrade(account.key());
+            if let Some(submission) = submission {
+                let submission_info = ctx
+                    .accounts
+                    .submission
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidAccount)?;
+                create_migrated_submission(submission_info, &payer, &system_program, &submission)?;
+            }
+            rewrite_layout(&account, &payer, &system_program, &application)?;
+            (0, APPLICATION_VERSION)
+        } else if discriminator == *Application::DISCRIMINATOR {
+            let from = migrate_layout::<Application>(&account, &payer, &system_program, |application| {
+                std::mem::replace(&mut application.version, APPLICATION_VERSION)
+            })?;
+            (from, APPLICATION_VERSION)
+        } else if discriminator == *UserStats::DISCRIMINATOR {
+            let from = migrate_layout::<UserStats>(&account, &payer, &system_program, |stats| {
+                std::mem::replace(&mut stats.version, USER_STATS_VERSION)
+            })?;
+            (from, USER_STATS_VERSION)
+        } else {
+            return err!(ErrorCode::InvalidAccount);
+        };
+        require!(from_version < to_version, ErrorCode::AccountAlreadyMigrated);
+
+        emit!(AccountMigrated {
+            account: account.key(),
+            from_version,
+            to_version,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "🔁 Account {} migrated from v{} to v{}",
+            account.key(),
+            from_version,
+            to_version
+        );
+        Ok(())
+    }
+
+    // Anyone records the job's escrow balance, its ledger (typed escrows
+    // only) and a hash of the job post as of this slot, giving auditors
+    // attestations to sample without replaying history
+    pub fn emit_escrow_snapshot(ctx: Context<EmitEscrowSnapshot>) -> Result<()> {
+        let job_post = &ctx.accounts.job_post;
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let balance = escrow.balance()?;
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let ledger = match escrow_shape(&escrow_info)? {
+            EscrowShape::Legacy => None,
+            EscrowShape::Typed => {
+                Some(Escrow::try_deserialize(&mut &escrow_info.try_borrow_data()?[..])?.ledger)
+            }
+        };
+        let state_hash = hashv(&[&job_post.try_to_vec()?]).to_bytes();
+        let clock = Clock::get()?;
+
+        emit!(EscrowSnapshot {
+            job_post: job_post.key(),
+            balance: BaseUnits(balance),
+            ledger,
+            state_hash,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "🧾 Escrow of job '{}' holds {} {} at slot {}",
+            job_post.title,
+            balance,
+            job_post.symbol,
+            clock.slot
+        );
+        Ok(())
+    }
+
+    // Anyone checks a batch of escrows, passed as remaining accounts in pairs
+    // of job post and escrow PDA, against what they should hold: a typed
+    // escrow its rent plus the ledger's outstanding lamports, a legacy one at
+    // least its rent. Each mismatch is emitted for off-chain watchdogs.
+    pub fn check_escrows<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CheckEscrows>,
+    ) -> Result<()> {
+        let accounts = ctx.remaining_accounts;
+        require!(
+            !accounts.is_empty()
+                && accounts.len().is_multiple_of(2)
+                && accounts.len() / 2 <= MAX_ESCROW_CHECKS,
+            ErrorCode::InvalidBatchSize
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let mut discrepancies = 0;
+        for accounts in accounts.chunks(2) {
+            let job_post: Account<JobPost> = Account::try_from(&accounts[0])?;
+            let escrow = &accounts[1];
+            let escrow_key = Pubkey::create_program_address(
+                &[b"escrow", job_post.key().as_ref(), &[job_post.escrow_bump]],
+                &crate::ID,
+            )
+            .map_err(|_| ErrorCode::InvalidEscrowAccount)?;
+            require_keys_eq!(escrow.key(), escrow_key, ErrorCode::InvalidEscrowAccount);
+
+            let actual = Lamports(escrow.lamports());
+            let (expected, balanced) = match escrow_shape(escrow)? {
+                EscrowShape::Legacy => (job_post.escrow_rent, actual >= job_post.escrow_rent),
+                EscrowShape::Typed => {
+                    let typed = Escrow::try_deserialize(&mut &escrow.try_borrow_data()?[..])?;
+                    let expected = job_post.escrow_rent.checked_add(typed.ledger.in_escrow()?)?;
+                    (expected, actual == expected)
+                }
+            };
+            if !balanced {
+                discrepancies += 1;
+                emit!(EscrowDiscrepancy {
+                    job_post: job_post.key(),
+                    escrow: escrow_key,
+                    expected,
+                    actual,
+                    timestamp,
+                });
+            }
+        }
+
+        msg!(
+            "🩺 {} escrows checked, {} out of balance",
+            accounts.len() / 2,
+            discrepancies
+        );
+        Ok(())
+    }
+
+    // Client brings the escrow's rent reserve in line with the current
+    // rent-exempt minimum: topping it up if rent went up, or taking back the
+    // excess if it went down. Job funds are left untouched either way.
+    pub fn reconcile_escrow_rent(ctx: Context<ReconcileEscrowRent>) -> Result<()> {
+        let job_post = &mut ctx.accounts.job_post;
+        let escrow = ctx.accounts.escrow.to_account_info();
+        let client = ctx.accounts.client.to_account_info();
+        let previous = job_post.escrow_rent;
+        let required = Lamports(Rent::get()?.minimum_balance(escrow.data_len()));
+        require!(required != previous, ErrorCode::EscrowRentUnchanged);
+
+        if required > previous {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: client.clone(),
+                    to: escrow.clone(),
+                },
+            );
+            system_program::transfer(cpi_ctx, required.checked_sub(previous)?.0)?;
+        } else {
+            let excess = previous.checked_sub(required)?;
+            let job_post_key = job_post.key();
+            let seeds = &[b"escrow", job_post_key.as_ref(), &[job_post.escrow_bump]];
+            match escrow_shape(&escrow)? {
+                EscrowShape::Legacy => move_escrow_lamports(
+                    &ctx.accounts.system_program.to_account_info(),
+                    &escrow,
+                    &client,
+                    EscrowShape::Legacy,
+                    &[&seeds[..]],
+                    excess,
+                    EscrowLeg::Refund,
+                )?,
+                // Rent isn't on the escrow's deposit/release ledger
+                EscrowShape::Typed => {
+                    escrow.sub_lamports(excess.0)?;
+                    client.add_lamports(excess.0)?;
+                }
+            }
+        }
+        job_post.escrow_rent = required;
+
+        emit!(EscrowRentReconciled {
+            job_post: job_post.key(),
+            previous,
+            current: required,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "🏦 Escrow rent of '{}' reconciled: {} -> {} lamports",
+            job_post.title,
+            previous,
+            required
+        );
+        Ok(())
+    }
+
+    // Freelancer publishes a snapshot of their reputation in this namespace,
+    // owned by this program, which other programs can read or verify via CPI
+    pub fn export_reputation_attestation(ctx: Context<ExportAttestation>) -> Result<[u8; 32]> {
+        let stats = &ctx.accounts.freelancer_stats;
+        let summary = AttestedReputation {
+            namespace: ctx.accounts.platform_config.namespace,
+            subject: ctx.accounts.freelancer.key(),
+            jobs_completed: stats.jobs_completed,
+            reputation_score: stats.reputation_score,
+            total_revenue_earned: stats.total_revenue_earned,
+            issued_at: Clock::get()?.unix_timestamp,
+        };
+
+        let attestation = &mut ctx.accounts.attestation;
+        attestation.digest = summary.digest()?;
+        attestation.summary = summary;
+        attestation.bump = ctx.bumps.attestation;
+
+        msg!(
+            "📜 Reputation attestation exported for {}",
+            ctx.accounts.freelancer.key()
+        );
+        Ok(attestation.digest)
+    }
+
+    // Read-only, CPI-friendly: check an attestation this program owns against
+    // its digest and return the attested reputation
+    pub fn verify_attestation(ctx: Context<VerifyAttestation>) -> Result<AttestedReputation> {
+        let attestation = &ctx.accounts.attestation;
+        require!(
+            attestation.summary.digest()? == attestation.digest,
+            ErrorCode::InvalidAttestation
+        );
+        Ok(attestation.summary.clone())
+    }
+
+    // Freelancer imports an attestation issued by an allowlisted program into
+    // their imported reputation. Each attestation account can be imported once.
+    pub fn import_attestation(ctx: Context<ImportAttestation>) -> Result<()> {
+        let source = &ctx.accounts.source_attestation;
+        require!(
+            ctx.accounts
+                .platform_config
+                .attestation_issuers
+                .contains(source.owner),
+            ErrorCode::IssuerNotAllowed
+        );
+
+        let attestation = {
+            let data = source.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == *ReputationAttestation::DISCRIMINATOR,
+                ErrorCode::InvalidAttestation
+            );
+            ReputationAttestation::deserialize(&mut &data[8..])?
+        };
+        require!(
+            attestation.summary.digest()? == attestation.digest,
+            ErrorCode::InvalidAttestation
+        );
+        require_keys_eq!(
+            attestation.summary.subject,
+            ctx.accounts.freelancer.key(),
+            ErrorCode::Unauthorized
+        );
+
+        let import_record = &mut ctx.accounts.import_record;
+        import_record.source = source.key();
+        import_record.issuer_program = *source.owner;
+        import_record.reputation_score = attestation.summary.reputation_score;
+        import_record.imported_at = Clock::get()?.unix_timestamp;
+
+        ctx.accounts.freelancer_stats.imported_reputation = checked_add(
+            ctx.accounts.freelancer_stats.imported_reputation,
+            attestation.summary.reputation_score,
+        )?;
+
+        msg!(
+            "📥 Imported {} reputation from {}",
+            attestation.summary.reputation_score,
+            source.owner
+        );
+        Ok(())
+    }
+
+    // Permissionless: emit the namespace's running totals as one Metrics
+    // event, at most once per HEARTBEAT_INTERVAL, so dashboards get a cheap
+    // periodic datapoint without indexing every transaction
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        let operator_stats = &mut ctx.accounts.operator_stats;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= operator_stats.last_heartbeat_at + HEARTBEAT_INTERVAL,
+            ErrorCode::HeartbeatTooSoon
+        );
+        operator_stats.last_heartbeat_at = now;
+
+        emit!(Metrics {
+            namespace: operator_stats.namespace,
+            jobs_posted: operator_stats.jobs_posted,
+            jobs_completed: operator_stats.jobs_completed,
+            jobs_cancelled: operator_stats.jobs_cancelled,
+            volume_escrowed: operator_stats.volume_escrowed,
+            volume_released: operator_stats.volume_released,
+            fee_revenue: operator_stats.fee_revenue,
+            fees_withdrawn: operator_stats.fees_withdrawn,
+            disputes_opened: operator_stats.disputes_opened,
+            disputes_resolved: operator_stats.disputes_resolved,
+            registered_jobs: operator_stats.registered_jobs,
+            promoters: operator_stats.promoters,
+            detractors: operator_stats.detractors,
+            satisfaction_responses: operator_stats.satisfaction_responses,
+            timestamp: now,
+        });
+
+        msg!("💓 Metrics for namespace {} emitted", operator_stats.namespace);
+        Ok(())
+    }
+
+    // Operator withdraws accrued platform fees from the namespace's stats vault
+    pub fn withdraw_operator_fees(ctx: Context<WithdrawOperatorFees>, amount: u64) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        let operator_stats = &mut ctx.accounts.operator_stats;
+
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+        require!(
+            operator_stats.fee_balance()? >= Lamports(amount),
+            ErrorCode::InsufficientFeeBalance
+        );
+
+        operator_stats.fees_withdrawn = operator_stats.fees_withdrawn.checked_add(Lamports(amount))?;
+        operator_stats.to_account_info().sub_lamports(amount)?;
+        ctx.accounts.destination.add_lamports(amount)?;
+
+        msg!(
+            "🏦 Operator withdrew {} lamports of fees to {}",
+            amount,
+            ctx.accounts.destination.key()
+        );
+        Ok(())
+    }
+
+    // Admin opens the namespace's insurance pool, or changes the share of SOL
+    // fees paid into it
+    pub fn configure_insurance_pool(
+        ctx: Context<ConfigureInsurancePool>,
+        contribution_bps: u16,
+    ) -> Result<()> {
+        require_ctx!(
+            contribution_bps <= MAX_BPS,
+            ErrorCode::InvalidInput,
+            "contribution_bps",
+            MAX_BPS,
+            contribution_bps
+        );
+        let pool = &mut ctx.accounts.insurance_pool;
+        pool.namespace = ctx.accounts.platform_config.namespace;
+        pool.contribution_bps = contribution_bps;
+        pool.bump = ctx.bumps.insurance_pool;
+        ctx.accounts.platform_config.insurance_enabled = true;
+
+        msg!("🛡️ Insurance pool takes {} bps of SOL fees", contribution_bps);
+        Ok(())
+    }
+
+    // Admin registers the namespace's settlement hook programs, or clears one
+    // with None. See `SettlementHooks` for how they are called.
+    pub fn set_settlement_hooks(
+        ctx: Context<SetSettlementHooks>,
+        pre_settlement: Option<Pubkey>,
+        post_settlement: Option<Pubkey>,
+    ) -> Result<()> {
+        for hook in [pre_settlement, post_settlement].into_iter().flatten() {
+            require_keys_neq!(hook, crate::ID, ErrorCode::InvalidInput);
+        }
+        let hooks = &mut ctx.accounts.settlement_hooks;
+        hooks.namespace = ctx.accounts.platform_config.namespace;
+        hooks.pre_settlement = pre_settlement;
+        hooks.post_settlement = post_settlement;
+        hooks.bump = ctx.bumps.settlement_hooks;
+
+        msg!(
+            "🪝 Settlement hooks set: pre {:?}, post {:?}",
+            pre_settlement,
+            post_settlement
+        );
+        Ok(())
+    }
+
+    // Admin pays a freelancer who won a dispute the part of their award the
+    // escrow could not cover, once per dispute and at most that shortfall
+    pub fn pay_insurance_claim(ctx: Context<PayInsuranceClaim>, amount: u64) -> Result<()> {
+        require_not_paused(&ctx.accounts.platform_config)?;
+        require_ctx!(amount > 0, ErrorCode::InvalidAmount, "amount", 1, amount);
+        let dispute = &mut ctx.accounts.dispute;
+        require!(dispute.resolved_at > 0, ErrorCode::DisputeNotResolved);
+        require!(dispute.freelancer_bps > MAX_BPS / 2, ErrorCode::Unauthorized);
+        require!(dispute.insurance_paid == Lamports::ZERO, ErrorCode::InsuranceAlreadyPaid);
+        // The pool holds lamports, so only SOL awards are covered
+        require!(ctx.accounts.job_post.is_native(), ErrorCode::NativeJobOnly);
+        require_ctx!(
+            amount <= dispute.shortfall,
+            ErrorCode::InvalidAmount,
+            "amount",
+            dispute.shortfall,
+            amount
+        );
+
+        let pool = &mut ctx.accounts.insurance_pool;
+        let amount = Lamports(amount);
+        require!(pool.balance()? >= amount, ErrorCode::InsufficientInsurancePool);
+        pool.paid_out = pool.paid_out.checked_add(amount)?;
+        pool.claims_paid = checked_add(pool.claims_paid, 1)?;
+        pool.sub_lamports(amount.0)?;
+        ctx.accounts.freelancer.add_lamports(amount.0)?;
+        dispute.insurance_paid = amount;
+
+        emit!(InsuranceClaimPaid {
+            job_post: ctx.accounts.job_post.key(),
+            application: ctx.accounts.application.key(),
+            freelancer: ctx.accounts.application.applicant,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "🛡️ Insurance paid {} lamports to {} for job '{}'",
+            amount,
+            ctx.accounts.application.applicant,
+            ctx.accounts.job_post.title
+        );
+        Ok(())
+    }
+
+    // Move a legacy role-less `user_stats` PDA into the role-scoped stats PDAs.
+    // Client activity goes to the client stats and earnings to the freelancer
+    // stats; the legacy account is closed back to the user.
+    pub fn migrate_user_stats(ctx: Context<MigrateUserStats>) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_stats.to_account_info();
+        require_keys_eq!(*legacy_info.owner, crate::ID, ErrorCode::InvalidAccount);
+        let user = ctx.accounts.user.key();
+        let legacy = {
+            let data = legacy_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == *UserStats::DISCRIMINATOR,
+                ErrorCode::InvalidAccount
+            );
+            let legacy = LegacyUserStats::read(&data[8..])?;
+            // Each layout only ever lived at its own address
+            let (expected, _) = if legacy.namespaced {
+                pda::legacy_user_stats(&ctx.accounts.platform_config.namespace, &user)
+            } else {
+                pda::first_release_user_stats(&user)
+            };
+            require_keys_eq!(legacy_info.key(), expected, ErrorCode::InvalidAccount);
+            legacy
+        };
+
+        let client_stats = &mut ctx.accounts.client_stats;
+        client_stats.total_gigs_posted =
+            checked_add(client_stats.total_gigs_posted, legacy.total_gigs_posted)?;
+
+        let freelancer_stats = &mut ctx.accounts.freelancer_stats;
+        freelancer_stats.total_revenue_earned =
+            checked_add(freelancer_stats.total_revenue_earned, legacy.total_revenue_earned)?;
+        freelancer_stats.jobs_completed =
+            checked_add(freelancer_stats.jobs_completed, legacy.jobs_completed)?;
+        // Legacy jobs have no pair history, so each counts at full weight
+        let reputation = legacy
+            .jobs_completed
+            .checked_mul(REPUTATION_UNIT)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        freelancer_stats.reputation_score =
+            checked_add(freelancer_stats.reputation_score, reputation)?;
+        // Legacy monthly gigs mixed both roles and can't be split; only the
+        // monthly revenue carries over, and only into the same month window
+        if freelancer_stats.last_updated_month == 0
+            || freelancer_stats.last_updated_month == legacy.last_updated_month
+        {
+            freelancer_stats.monthly_revenue =
+                checked_add(freelancer_stats.monthly_revenue, legacy.monthly_revenue)?;
+            freelancer_stats.last_updated_month = legacy.last_updated_month;
+        }
+
+        close_raw_account(&legacy_info, &ctx.accounts.user.to_account_info())?;
+
+        msg!(
+            "🔀 Stats for {} migrated to role-scoped accounts",
+            ctx.accounts.user.key()
+        );
+        Ok(())
+    }
+
+    // Anyone opens a user's MonthlyStats for a role and calendar month, so
+    // activity in that month is kept once the UserStats bucket rolls over
+    pub fn open_monthly_stats(
+        ctx: Context<OpenMonthlyStats>,
+        namespace: Pubkey,
+        role: u8,
+        year: u16,
+        month: u8,
+    ) -> Result<()> {
+        require_ctx!(role <= ROLE_FREELANCER, ErrorCode::InvalidInput, "role", ROLE_FREELANCER, role);
+        require_ctx!((1..=12).contains(&month), ErrorCode::InvalidInput, "month", 12, month);
+
+        let monthly_stats = &mut ctx.accounts.monthly_stats;
+        monthly_stats.namespace = namespace;
+        monthly_stats.user = ctx.accounts.user.key();
+        monthly_stats.role = role;
+        monthly_stats.year = year;
+        monthly_stats.month = month;
+        monthly_stats.bump = ctx.bumps.monthly_stats;
+
+        msg!("📅 Monthly stats {}-{:02} opened for {}", year, month, monthly_stats.user);
+        Ok(())
+    }
+
+    // Fetch user statistics for one role
+    pub fn get_user_stats(ctx: Context<GetUserStats>, _namespace: Pubkey, role: u8) -> Result<()> {
+        require_ctx!(role <= ROLE_FREELANCER, ErrorCode::InvalidInput, "role", ROLE_FREELANCER, role);
+        let stats = &ctx.accounts.user_stats;
+        msg!("📊 User Stats (role {}):", role);
+        msg!("Total Gigs Posted: {}", stats.total_gigs_posted);
+        msg!("Total Revenue Earned: {}", stats.total_revenue_earned);
+        msg!("Monthly Gigs: {}", stats.monthly_gigs);
+        msg!("Monthly Revenue: {}", stats.monthly_revenue);
+        msg!("Last Updated Month: {}", stats.last_updated_month);
+        msg!("Reputation Score: {}", stats.reputation_score);
+        Ok(())
+    }
+}
 
 // ----------------- ACCOUNTS -----------------
 
-#[account]
-#[derive(InitSpace)]
-pub struct JobPost {
-    pub client: Pubkey,
-    #[max_len(100)]
-    pub title: String,
-    #[max_len(500)]
-    pub description: String,
-    pub amount: u64,
-    pub is_filled: bool,
-    pub cancelled: bool,
-    pub start_date: i64,
-    pub end_date: i64,
-    pub escrow_bump: u8,
-    pub freelancer: Option<Pubkey>,
+#[account]
+#[derive(InitSpace)]
+pub struct JobPost {
+    pub namespace: Pubkey,
+    pub client: Pubkey,
+    #[max_len(100)]
+    pub title: String,
+    #[max_len(MAX_JOB_DESCRIPTION_LEN)]
+    pub description: String,
+    pub amount: BaseUnits,
+    pub is_filled: bool,
+    pub cancelled: bool,
+    pub start_date: i64,
+    pub end_date: i64,
+    pub escrow_bump: u8,
+    pub freelancer: Option<Pubkey>,
+    pub funded_by: Option<Pubkey>,
+    pub settled_at: i64,
+    pub content_hash: Option<[u8; 32]>,
+    // Display metadata for the escrowed asset, e.g. 9 / "SOL"
+    pub decimals: u8,
+    #[max_len(MAX_SYMBOL_LEN)]
+    pub symbol: String,
+    pub auction: Option<Auction>,
+    pub direct_offer: Option<DirectOffer>,
+    #[max_len(MAX_SCOPE_HISTORY)]
+    pub scope_history: Vec<ScopeEntry>,
+    pub pending_scope: Option<ScopeProposal>,
+    // SPL mint the job is paid in; None for native SOL
+    pub payment_mint: Option<Pubkey>,
+    // Token drafts stay unpublished until their escrow is funded
+    pub published: bool,
+    // Paid trial hire, and the full job it was converted into
+    pub trial: bool,
+    pub converted_to: Option<Pubkey>,
+    pub category: u8,
+    // Short free-form labels for search, e.g. "rust" or "logo"
+    #[max_len(MAX_JOB_TAGS, MAX_TAG_LEN)]
+    pub tags: Vec<String>,
+    // Highlighted by the operator in list views
+    pub featured: bool,
+    // Position in the namespace's job registry, once registered
+    pub registry_index: Option<u64>,
+    // Oracle price observed when the escrow was funded and when it paid out
+    pub funding_price: Option<PriceSnapshot>,
+    pub payout_price: Option<PriceSnapshot>,
+    pub repricing: Option<RepricingClause>,
+    // Net payout waiting in escrow for the freelancer's guardian
+    pub held_payout: BaseUnits,
+    // DAO governance decision that authorized the escrow, for DAO clients
+    pub governance: Option<GovernanceReceipt>,
+    // Job whose completion gates approving and submitting on this one
+    pub depends_on: Option<Pubkey>,
+    // Escrow already paid out through approved milestones, and the tax withheld from it
+    pub milestones_released: BaseUnits,
+    pub milestones_withheld: BaseUnits,
+    pub milestones_fees: BaseUnits,
+    pub project: Option<Pubkey>,
+    // Rules on disputes for this job instead of the namespace default
+    pub arbiter: Option<Pubkey>,
+    // Client's second key co-signing high-value releases instead of the
+    // namespace's platform signer
+    pub co_signer: Option<Pubkey>,
+    // May approve or reject submissions alongside the client
+    pub reviewer: Option<Pubkey>,
+    // Open application accounts; the post can only be closed once this is zero
+    pub applications: u32,
+    // NDA applicants must acknowledge before applying
+    pub nda_hash: Option<[u8; 32]>,
+    // Set by the admin to block fund movement during an investigation
+    pub frozen: bool,
+    pub freeze_reason: u16,
+    // Seats to fill, each paid amount / max_hires, and how many are hired
+    // and paid out so far
+    pub max_hires: u8,
+    pub hires_count: u8,
+    pub seats_settled: u8,
+    // Freelancer whose payout is in `held_payout`
+    pub held_for: Option<Pubkey>,
+    // Hashes of the client's screening questions; applicants answer each
+    #[max_len(MAX_SCREENING_QUESTIONS)]
+    pub screening_questions: Vec<[u8; 32]>,
+    // ISO 639-1 codes of the languages the work needs, e.g. `*b"es"`
+    #[max_len(MAX_LANGUAGES)]
+    pub required_languages: Vec<[u8; 2]>,
+    // UTC offsets applicants must work from, for synchronous collaboration
+    pub utc_offset_range: Option<UtcOffsetRange>,
+    // Applications ever received, unlike `applications` which drops as they
+    // are closed; capped by `max_applicants` when non-zero
+    pub applications_count: u32,
+    pub max_applicants: u32,
+    // No applications after this time, when non-zero, or once the client
+    // closes applications
+    pub application_deadline: i64,
+    pub applications_closed: bool,
+    // Lamports each applicant stakes on this job instead of the namespace's
+    // application stake; zero defers to the namespace
+    pub application_stake: Lamports,
+    // Days the client takes to review a submission instead of the namespace's
+    // review window; zero defers to the namespace
+    pub review_window_days: u16,
+    // Resubmissions allowed after a rejection; zero for no limit
+    pub max_revisions: u8,
+    // Exact review period in seconds, taking precedence over the review
+    // window in days; zero when unset
+    pub review_period_seconds: i64,
+    // Auto-release holdbacks of this job's applications still in escrow
+    pub holdbacks: BaseUnits,
+    // Escrow lamports funded from the client's promotional credits, handed
+    // back to their credits before any cash whenever escrow is refunded
+    pub credits_applied: Lamports,
+    // Rent-exempt minimum reserved on the escrow at creation, never paid out
+    // as job funds; `reconcile_escrow_rent` follows rent parameter changes
+    pub escrow_rent: Lamports,
+    pub visibility: JobVisibility,
+    // Set for retainers, paid out a period at a time
+    pub retainer: Option<RetainerTerms>,
+    // Set for bounties, awarded by judges' votes instead of a hire
+    pub bounty: Option<BountyTerms>,
+    // Nonces of the client's latest top-ups, budget increases and tips
+    pub recent_nonces: NonceRing,
+    // Lamports escrowed beside a token job's amount, settled along with it
+    pub sol_leg: Lamports,
+    // Cancelled by the `expire_job` crank after going stale unfilled
+    pub expired: bool,
+    // Layout version; 0 for posts written before versioning. See migrate_account
+    pub version: u8,
+    // Hidden from applicants by moderation, and the operator's reason code
+    pub hidden: bool,
+    pub moderation_reason: u16,
+    // Set for hourly jobs, paid per approved timesheet entry
+    pub hourly: Option<HourlyTerms>,
+    // Set when pay vests linearly over the job's dates
+    pub stream: Option<PaymentStream>,
+    // Budget in USD cents for jobs priced in dollars; `amount` is what it
+    // converted to at `funding_price`
+    pub usd_cents: Option<u64>,
+    // Commitment to the real price of a job whose `amount` is only a cap,
+    // until it is revealed; see `hide_job_amount`
+    pub amount_commitment: Option<[u8; 32]>,
+}
+
+impl JobPost {
+    pub fn accepts_applications(&self, now: i64) -> bool {
+        !self.applications_closed
+            && (self.max_applicants == 0 || self.applications_count < self.max_applicants)
+            && (self.application_deadline == 0 || now <= self.application_deadline)
+    }
+
+    pub fn is_native(&self) -> bool {
+        self.payment_mint.is_none()
+    }
+
+    // Rejected with no resubmissions left, leaving the freelancer a dispute
+    pub fn revisions_exhausted(&self, application: &Application) -> bool {
+        application.rejected
+            && self.max_revisions > 0
+            && application.rejections > self.max_revisions
+    }
+
+    // When the client's review of a submission made at `submitted_at` is
+    // overdue; None when unreviewed work can't be claimed
+    pub fn review_deadline(&self, config: &PlatformConfig, submitted_at: i64) -> Option<i64> {
+        if self.review_period_seconds > 0 {
+            return Some(submitted_at + self.review_period_seconds);
+        }
+        let days = self.review_window(config);
+        (days > 0).then(|| submitted_at + i64::from(days) * SECONDS_PER_DAY)
+    }
+
+    // Review window in force for this job, in days
+    pub fn review_window(&self, config: &PlatformConfig) -> u16 {
+        if self.review_window_days > 0 {
+            self.review_window_days
+        } else {
+            config.review_window_days
+        }
+    }
+
+    // Whether an application to this job holds one of its seats
+    pub fn is_hired(&self, application: &Application) -> bool {
+        self.freelancer == Some(application.applicant)
+            || (self.max_hires > 1 && application.approved)
+    }
+
+    // What settling one seat pays out: the seat's share on multi-seat jobs,
+    // otherwise whatever milestones haven't already released
+    pub fn seat_payout(&self) -> BaseUnits {
+        if self.max_hires > 1 {
+            BaseUnits(self.amount.0 / u64::from(self.max_hires))
+        } else {
+            self.amount.saturating_sub(self.milestones_released)
+        }
+    }
+
+    // `amount` of this job as lamports, refusing it on a token job
+    pub fn lamports(&self, amount: BaseUnits) -> Result<Lamports> {
+        require!(self.is_native(), ErrorCode::NativeOnly);
+        Ok(Lamports(amount.0))
+    }
+
+    // Splits a refund of `amount` into the credit-funded share, which goes
+    // back to the client's credits first, and the cash paid to their wallet
+    pub fn take_credit_refund(&mut self, amount: u64) -> (u64, u64) {
+        let returned = self.credits_applied.0.min(amount);
+        self.credits_applied = self.credits_applied.saturating_sub(Lamports(amount));
+        (returned, amount - returned)
+    }
+
+    // Amount vested so far on a streaming job; nothing vests before the hire
+    pub fn vested_amount(&self, now: i64) -> Option<BaseUnits> {
+        let stream = self.stream?;
+        if self.hires_count == 0 {
+            return Some(BaseUnits::ZERO);
+        }
+        let end = if stream.stopped_at != 0 {
+            stream.stopped_at
+        } else {
+            self.end_date
+        };
+        Some(BaseUnits(vested_amount(self.amount.0, self.start_date, end, now)))
+    }
+
+    // Marks one seat paid out; the job settles with its last seat
+    pub fn settle_seat(&mut self, now: i64) -> Result<()> {
+        self.seats_settled = self
+            .seats_settled
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        if self.seats_settled >= self.max_hires {
+            self.settled_at = now;
+        }
+        Ok(())
+    }
+
+    pub fn kind(&self) -> JobKind {
+        if self.trial {
+            JobKind::Trial
+        } else if self.direct_offer.is_some() {
+            JobKind::DirectOffer
+        } else if self.auction.is_some() {
+            JobKind::Auction
+        } else {
+            JobKind::Standard
+        }
+    }
+
+    pub fn status(&self) -> JobStatus {
+        JobLifecycle {
+            expired: self.expired,
+            cancelled: self.cancelled,
+            settled: self.settled_at > 0,
+            published: self.published,
+            filled: self.is_filled,
+            auction_open: self.auction.is_some_and(|auction| !auction.closed),
+        }
+        .status()
+    }
+}
+
+// Oracle price as `price * 10^expo` USD
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PriceSnapshot {
+    pub price: i64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+// Keeps a SOL job's USD value stable within agreed bounds. The escrow holds
+// `amount + buffer`, where the buffer is the headroom up to the upper bound.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct RepricingClause {
+    pub base_amount: u64,
+    pub base_price: PriceSnapshot,
+    pub min_bps: u16,
+    pub max_bps: u16,
+    pub buffer: u64,
+}
+
+// How a job's escrow is split when its work is approved, in the job's units.
+// `gross` is the fee, withholding, donation, team shares, holdback and
+// `net_payout` together.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SettlementBreakdown {
+    pub gross: u64,
+    pub fee: u64,
+    // Referrer's cut, out of `fee`
+    pub referral: u64,
+    pub withheld: u64,
+    pub donated: u64,
+    // Paid to the application's teammates
+    pub team_shares: u64,
+    // Kept in escrow for the client's dispute window on an auto-release
+    pub holdback: u64,
+    // Paid to the freelancer now
+    pub net_payout: u64,
+    // Unused repricing headroom returned to the client
+    pub client_refund: u64,
+    // A token job's SOL leg, paid to the freelancer's wallet
+    pub sol_leg: Lamports,
+}
+
+// Layout of the Pyth receiver's `PriceUpdateV2` account. Decoded by hand since
+// the Pyth SDK crates pin an older solana-program.
+#[derive(AnchorDeserialize)]
+pub struct PythPriceUpdate {
+    pub write_authority: Pubkey,
+    pub verification_level: PythVerificationLevel,
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+    pub posted_slot: u64,
+}
+
+#[derive(AnchorDeserialize, PartialEq, Eq)]
+pub enum PythVerificationLevel {
+    Partial { num_signatures: u8 },
+    Full,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum JobKind {
+    Standard,
+    DirectOffer,
+    Trial,
+    Auction,
+}
+
+// Fixed-size summary of a JobPost for list views, kept in sync with the post.
+// Opened by `sync_job_card`, then required by every instruction changing a
+// field it caches, except those moving escrow (settlements, cancels, refunds
+// and expiries), which take it when it exists so a job without a card never
+// locks its funds.
+#[account]
+#[derive(InitSpace)]
+pub struct JobCard {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub status: JobStatus,
+    pub amount: u64,
+    pub category: u8,
+    pub start_date: i64,
+    pub end_date: i64,
+    pub bump: u8,
+}
+
+// Jobs filed under one category of a namespace: how many ever were, and the
+// most recent ones, newest last. `namespace`, `category` and `job_count` sit
+// at fixed offsets for memcmp filters.
+#[account]
+#[derive(InitSpace)]
+pub struct CategoryIndex {
+    pub namespace: Pubkey,
+    pub category: u8,
+    pub job_count: u64,
+    #[max_len(CATEGORY_RECENT_JOBS)]
+    pub recent_jobs: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl CategoryIndex {
+    // Files a job, once, dropping the oldest recent job when full
+    pub fn record(&mut self, job_post: Pubkey) -> Result<()> {
+        if self.recent_jobs.contains(&job_post) {
+            return Ok(());
+        }
+        if self.recent_jobs.len() == CATEGORY_RECENT_JOBS {
+            self.recent_jobs.remove(0);
+        }
+        self.recent_jobs.push(job_post);
+        self.job_count = checked_add(self.job_count, 1)?;
+        Ok(())
+    }
+}
+
+// Promotional credits an operator granted a client, in lamports. The
+// account holds the lamports backing `balance` on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct Credits {
+    pub namespace: Pubkey,
+    pub client: Pubkey,
+    pub balance: u64,
+    pub bump: u8,
+}
+
+// One page of a user's own job posts or applications, by `kind`. Accounts
+// are listed when created and dropped when closed, so a page regains room as
+// its entries close.
+#[account]
+#[derive(InitSpace)]
+pub struct UserIndexPage {
+    pub owner: Pubkey,
+    pub kind: u8,
+    pub page: u32,
+    #[max_len(USER_INDEX_PAGE_SIZE)]
+    pub entries: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl UserIndexPage {
+    pub fn append(&mut self, entry: Pubkey) -> Result<()> {
+        if self.entries.contains(&entry) {
+            return Ok(());
+        }
+        require!(self.entries.len() < USER_INDEX_PAGE_SIZE, ErrorCode::UserIndexPageFull);
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    // Drops an entry, keeping the order of the rest
+    pub fn remove(&mut self, entry: Pubkey) -> Result<()> {
+        let position = self
+            .entries
+            .iter()
+            .position(|listed| *listed == entry)
+            .ok_or(ErrorCode::InvalidAccount)?;
+        self.entries.remove(position);
+        Ok(())
+    }
+}
+
+// One page of a namespace's append-only job registry
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryPage {
+    pub namespace: Pubkey,
+    pub page: u32,
+    #[max_len(REGISTRY_PAGE_SIZE)]
+    pub entries: Vec<RegistryEntry>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct RegistryEntry {
+    pub job_post: Pubkey,
+    // Packed filter bits, see `job_flags`
+    pub flags: u32,
+    pub client: Pubkey,
+    pub amount: u64,
+    pub status: JobStatus,
+    pub end_date: i64,
+}
+
+impl RegistryEntry {
+    pub fn from_job(job_post: &Account<JobPost>) -> Self {
+        Self {
+            job_post: job_post.key(),
+            flags: job_flags(job_post),
+            client: job_post.client,
+            amount: job_post.amount.0,
+            status: job_post.status(),
+            end_date: job_post.end_date,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct JobFilter {
+    // Entries match when `flags & flags_mask == flags_value`
+    pub flags_mask: u32,
+    pub flags_value: u32,
+    pub status: Option<JobStatus>,
+    pub client: Option<Pubkey>,
+    pub min_amount: Option<u64>,
+}
+
+impl JobFilter {
+    pub fn matches(&self, entry: &RegistryEntry) -> bool {
+        entry.flags & self.flags_mask == self.flags_value
+            && self.status.is_none_or(|status| status == entry.status)
+            && self.client.is_none_or(|client| client == entry.client)
+            && self.min_amount.is_none_or(|amount| entry.amount >= amount)
+    }
+}
+
+// Return data of `list_jobs`; pass `next_cursor` back in to continue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct JobListing {
+    pub next_cursor: u64,
+    pub entries: Vec<RegistryEntry>,
+}
+
+impl JobCard {
+    pub fn refresh(&mut self, job_post: &Account<JobPost>) {
+        self.job_post = job_post.key();
+        self.client = job_post.client;
+        self.freelancer = job_post.freelancer.unwrap_or_default();
+        self.status = job_post.status();
+        self.amount = job_post.amount.0;
+        self.category = job_post.category;
+        self.start_date = job_post.start_date;
+        self.end_date = job_post.end_date;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ScopeEntry {
+    pub hash: [u8; 32],
+    pub agreed_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct ScopeProposal {
+    pub hash: [u8; 32],
+    pub proposed_by: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct GovernanceReceipt {
+    pub realm: Pubkey,
+    pub proposal: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct DirectOffer {
+    pub freelancer: Pubkey,
+    pub expires_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Auction {
+    pub closes_at: i64,
+    pub reputation_floor: u64,
+    pub best_bid: Option<Bid>,
+    pub closed: bool,
+    // Set for sealed-bid auctions
+    pub reveal_ends_at: Option<i64>,
+}
+
+impl Auction {
+    // Keep the lowest bid; ties go to whoever bid first
+    pub fn record_bid(&mut self, application: Pubkey, amount: u64) {
+        if self.best_bid.is_none_or(|best| amount < best.amount) {
+            self.best_bid = Some(Bid { application, amount });
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Bid {
+    pub application: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Application {
+    pub applicant: Pubkey,
+    pub job_post: Pubkey,
+    #[max_len(200)]
+    pub resume_link: String,
+    #[max_len(300)]
+    pub client_review: String,
+    pub approved: bool,
+    pub submitted: bool,
+    pub completed: bool,
+    pub rejected: bool,
+    // When the work was last rejected, starting the dispute window
+    pub rejected_at: i64,
+    pub expected_end_date: i64,
+    pub sponsored_by: Option<Pubkey>,
+    pub content_hash: Option<[u8; 32]>,
+    pub bid_amount: Option<u64>,
+    pub bid_commitment: Option<[u8; 32]>,
+    pub invoice_hash: Option<[u8; 32]>,
+    // Hashed satisfaction scores from each party
+    pub client_satisfaction: Option<[u8; 32]>,
+    pub freelancer_satisfaction: Option<[u8; 32]>,
+    // Escalated to the job's arbiter; see the application's Dispute account
+    pub disputed: bool,
+    // Latest submission, starting the client's review window
+    pub submitted_at: i64,
+    // Revisions of the work submitted so far, each kept in its own
+    // `Submission` account
+    pub submissions: u8,
+    // When the client last approved or rejected a submission, stopping its
+    // review clock
+    pub reviewed_at: i64,
+    // Item hashes agreed at approval, and a bit per item signed off
+    #[max_len(MAX_CHECKLIST_ITEMS)]
+    pub checklist: Vec<[u8; 32]>,
+    pub checked_items: u8,
+    // Who owns the delivered work once it is paid for
+    pub ip_terms: IpTerms,
+    // 1–5 star ratings each party gave the other
+    pub freelancer_rating: Option<u8>,
+    pub client_rating: Option<u8>,
+    // Price the freelancer asked for instead of the job amount
+    pub proposed_amount: Option<u64>,
+    // Hashes of the answers committed at apply time, one per screening question
+    #[max_len(MAX_SCREENING_QUESTIONS)]
+    pub screening_answers: Vec<[u8; 32]>,
+    // Lamports staked to apply, held on this account until it is closed
+    pub stake: u64,
+    // Teammates paid their share of the net payout; the applicant keeps the rest
+    #[max_len(MAX_TEAM_MEMBERS)]
+    pub team: Vec<TeamShare>,
+    // Client's offer to close out the work for this share of the seat's pay,
+    // in basis points, until the freelancer accepts it
+    pub settlement_offer: Option<u16>,
+    // Who referred the applicant; paid a cut of the platform fee on approval
+    pub referrer: Option<Pubkey>,
+    // Hired, then removed by the client for missing their delivery date
+    pub abandoned: bool,
+    // When the applicant was hired, timing the engagement for stats
+    pub approved_at: i64,
+    // Applicant's profile, sent instead of or alongside the resume link
+    pub profile: Option<Pubkey>,
+    // Times the client rejected the work, counted against the job's revisions
+    pub rejections: u8,
+    // Part of an auto-released payout still in escrow, and when it may be
+    // released to the freelancer unless the client disputes first
+    pub holdback: u64,
+    pub holdback_until: i64,
+    // Who gave the second approval a high-value release needs, for the
+    // current submission
+    pub co_signed_by: Option<Pubkey>,
+    // Turned down by the client before hiring; `client_review` holds the note
+    pub declined: bool,
+    // Reviews each party sealed after completion as
+    // sha256(rating || text || salt || author), when either may be revealed
+    // without the other's, and a REVIEW_* bit per side already revealed
+    pub client_sealed_review: Option<[u8; 32]>,
+    pub freelancer_sealed_review: Option<[u8; 32]>,
+    pub sealed_reviews_open_at: i64,
+    pub reviews_revealed: u8,
+    #[max_len(MAX_ATTACHMENTS)]
+    pub attachments: Vec<Attachment>,
+    // Hash of the client's private triage notes; set only by the client and
+    // never emitted
+    pub client_private_note_hash: Option<[u8; 32]>,
+    // Layout version; 0 for applications written before versioning
+    pub version: u8,
+    // End of the window, opened at approval, in which the client may edit
+    // their review and the freelancer reply to it; zero until approved
+    pub feedback_closes_at: i64,
+    #[max_len(MAX_REVIEW_LEN)]
+    pub freelancer_response: String,
+    // A REVIEW_* bit per side whose satisfaction score was counted
+    pub satisfaction_revealed: u8,
+}
+
+// Both parties' signatures on unwinding an approved engagement; kept after
+// acceptance as the record of the agreement
+#[account]
+#[derive(InitSpace)]
+pub struct CancellationRequest {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub requested_by: Pubkey,
+    pub client_signed: bool,
+    pub freelancer_signed: bool,
+    pub requested_at: i64,
+    // Zero until the other party signs
+    pub accepted_at: i64,
+    pub bump: u8,
+}
+
+// Deliverables the hired freelancer recorded against an application, each
+// accepted by the client on its own
+#[account]
+#[derive(InitSpace)]
+pub struct Deliverables {
+    pub application: Pubkey,
+    #[max_len(MAX_DELIVERABLES)]
+    pub items: Vec<Deliverable>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct Deliverable {
+    pub file: Attachment,
+    // Share of the job amount released when it is accepted, in basis points
+    pub share_bps: u16,
+    pub status: DeliverableStatus,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DeliverableStatus {
+    Pending,
+    Accepted,
+    // Sent back for rework; resubmitting makes it pending again
+    Rejected,
+}
+
+// A file supporting an application, identified by its content hash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct Attachment {
+    pub hash: [u8; 32],
+    #[max_len(MAX_ATTACHMENT_URI_LEN)]
+    pub uri: String,
+    // e.g. "Case study" or "AWS certification"
+    #[max_len(MAX_ATTACHMENT_LABEL_LEN)]
+    pub label: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct TeamShare {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+impl TeamShare {
+    // This teammate's part of a net payout
+    pub fn of(&self, net_payout: u64) -> u64 {
+        bps_of(net_payout, self.bps)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum IpTerms {
+    // Ownership transfers to the client
+    WorkForHire,
+    // Freelancer keeps ownership and licenses the work to the client
+    License,
+    // Freelancer keeps all rights
+    Retained,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserStats {
+    pub total_gigs_posted: u64,
+    pub total_revenue_earned: u64,
+    pub monthly_gigs: u64,
+    pub monthly_revenue: u64,
+    pub last_updated_month: u8,
+    pub jobs_completed: u64,
+    // Completed jobs weighted by counterparty distinctness, in REPUTATION_UNITs
+    pub reputation_score: u64,
+    // First activity in this role, used as the account age
+    pub first_seen_at: i64,
+    // Reputation imported from other platforms' attestations, kept apart from
+    // reputation earned here
+    pub imported_reputation: u64,
+    // Star ratings received from counterparties; the average is in hundredths
+    pub ratings_count: u64,
+    pub ratings_sum: u64,
+    pub rating_average: u16,
+    // Calendar year of the monthly counters, alongside `last_updated_month`
+    pub last_updated_year: u16,
+    // Jobs the client cancelled before hiring anyone
+    pub jobs_cancelled: u64,
+    // Disputes the arbiter decided for or against this user; an even split
+    // counts as neither
+    pub disputes_won: u64,
+    pub disputes_lost: u64,
+    // Lamports paid out for completed SOL jobs, in the client role
+    pub total_spent: u64,
+    // Seconds from hire to approval over the jobs timed, and their mean
+    pub completion_secs_total: u64,
+    pub timed_completions: u64,
+    pub average_completion_secs: u64,
+    // Layout version. Stats accounts are created on first use by many
+    // instructions, so they start at 0 until migrate_account stamps them;
+    // being allocated at the current size, that upgrade only sets the version.
+    pub version: u8,
+}
+
+// One calendar month of a user's activity in one role, kept as history
+#[account]
+#[derive(InitSpace)]
+pub struct MonthlyStats {
+    pub namespace: Pubkey,
+    pub user: Pubkey,
+    pub role: u8,
+    pub year: u16,
+    pub month: u8,
+    pub gigs_posted: u64,
+    pub jobs_completed: u64,
+    // Lamports earned from SOL jobs
+    pub revenue: u64,
+    pub bump: u8,
+}
+
+impl MonthlyStats {
+    pub fn require_period(&self, year: u16, month: u8) -> Result<()> {
+        require!(
+            (self.year, self.month) == (year, month),
+            ErrorCode::WrongStatsPeriod
+        );
+        Ok(())
+    }
+}
+
+impl UserStats {
+    // Monthly counters restart when the calendar month moves on; past months
+    // live on in MonthlyStats accounts
+    fn roll_month(&mut self, year: u16, month: u8) {
+        if (self.last_updated_year, self.last_updated_month) != (year, month) {
+            self.monthly_gigs = 0;
+            self.monthly_revenue = 0;
+            self.last_updated_year = year;
+            self.last_updated_month = month;
+        }
+    }
+
+    pub fn record_gig_posted(&mut self, year: u16, month: u8) -> Result<()> {
+        self.roll_month(year, month);
+        self.total_gigs_posted = checked_add(self.total_gigs_posted, 1)?;
+        self.monthly_gigs = checked_add(self.monthly_gigs, 1)?;
+        Ok(())
+    }
+
+    pub fn record_job_completed(&mut self, year: u16, month: u8, earned: u64) -> Result<()> {
+        self.roll_month(year, month);
+        self.total_revenue_earned = checked_add(self.total_revenue_earned, earned)?;
+        self.monthly_revenue = checked_add(self.monthly_revenue, earned)?;
+        self.monthly_gigs = checked_add(self.monthly_gigs, 1)?;
+        self.jobs_completed = checked_add(self.jobs_completed, 1)?;
+        Ok(())
+    }
+
+    // A tip counts as revenue but not as another completed job
+    pub fn record_tip(&mut self, year: u16, month: u8, amount: u64) -> Result<()> {
+        self.roll_month(year, month);
+        self.total_revenue_earned = checked_add(self.total_revenue_earned, amount)?;
+        self.monthly_revenue = checked_add(self.monthly_revenue, amount)?;
+        Ok(())
+    }
+
+    // Client side of a completed job: what it cost them and how long it took
+    pub fn record_job_paid(&mut self, spent: u64) -> Result<()> {
+        self.total_spent = checked_add(self.total_spent, spent)?;
+        self.jobs_completed = checked_add(self.jobs_completed, 1)?;
+        Ok(())
+    }
+
+    pub fn record_completion_time(&mut self, secs: u64) -> Result<()> {
+        self.completion_secs_total = checked_add(self.completion_secs_total, secs)?;
+        self.timed_completions = checked_add(self.timed_completions, 1)?;
+        self.average_completion_secs = self.completion_secs_total / self.timed_completions;
+        Ok(())
+    }
+
+    pub fn record_cancellation(&mut self) -> Result<()> {
+        self.jobs_cancelled = checked_add(self.jobs_cancelled, 1)?;
+        Ok(())
+    }
+
+    pub fn record_dispute(&mut self, won: bool) -> Result<()> {
+        let tally = if won { &mut self.disputes_won } else { &mut self.disputes_lost };
+        *tally = checked_add(*tally, 1)?;
+        Ok(())
+    }
+
+    pub fn record_rating(&mut self, rating: u8) -> Result<()> {
+        self.ratings_count = checked_add(self.ratings_count, 1)?;
+        self.ratings_sum = checked_add(self.ratings_sum, u64::from(rating))?;
+        self.rating_average =
+            (u128::from(self.ratings_sum) * 100 / u128::from(self.ratings_count)) as u16;
+        Ok(())
+    }
+}
+
+// Stats accounts created before `reputation_score` existed. The first
+// release wrote 33 bytes under [b"user_stats", user], ending at
+// `last_updated_month`; namespaced stats under [b"user_stats", namespace,
+// user] appended `jobs_completed`.
+pub struct LegacyUserStats {
+    pub total_gigs_posted: u64,
+    pub total_revenue_earned: u64,
+    pub monthly_gigs: u64,
+    pub monthly_revenue: u64,
+    pub last_updated_month: u8,
+    pub jobs_completed: u64,
+    pub namespaced: bool,
+}
+
+impl LegacyUserStats {
+    pub const FIRST_RELEASE_LEN: usize = 4 * 8 + 1;
+    pub const NAMESPACED_LEN: usize = Self::FIRST_RELEASE_LEN + 8;
+
+    // Decodes either layout from the data after the discriminator
+    pub fn read(data: &[u8]) -> Result<Self> {
+        let namespaced = match data.len() {
+            Self::FIRST_RELEASE_LEN => false,
+            Self::NAMESPACED_LEN => true,
+            _ => return err!(ErrorCode::InvalidAccount),
+        };
+        let mut data = data;
+        let total_gigs_posted = u64::deserialize(&mut data)?;
+        let total_revenue_earned = u64::deserialize(&mut data)?;
+        let monthly_gigs = u64::deserialize(&mut data)?;
+        let monthly_revenue = u64::deserialize(&mut data)?;
+        let last_updated_month = u8::deserialize(&mut data)?;
+        let jobs_completed = if namespaced { u64::deserialize(&mut data)? } else { 0 };
+        Ok(Self {
+            total_gigs_posted,
+            total_revenue_earned,
+            monthly_gigs,
+            monthly_revenue,
+            last_updated_month,
+            jobs_completed,
+            namespaced,
+        })
+    }
+}
+
+// Status bits returned by `get_applications_summary`
+pub const APP_APPROVED: u8 = 1 << 0;
+pub const APP_SUBMITTED: u8 = 1 << 1;
+pub const APP_COMPLETED: u8 = 1 << 2;
+pub const APP_REJECTED: u8 = 1 << 3;
+pub const APP_BID: u8 = 1 << 4;
+pub const APP_INVOICED: u8 = 1 << 5;
+pub const APP_DISPUTED: u8 = 1 << 6;
+pub const APP_DECLINED: u8 = 1 << 7;
+
+// `Application::reviews_revealed` and `satisfaction_revealed` bits
+pub const REVIEW_CLIENT: u8 = 1 << 0;
+pub const REVIEW_FREELANCER: u8 = 1 << 1;
+
+impl Application {
+    pub fn checklist_complete(&self) -> bool {
+        self.checked_items.count_ones() as usize == self.checklist.len()
+    }
+
+    pub fn status_flags(&self) -> u8 {
+        let mut flags = 0;
+        for (set, flag) in [
+            (self.approved, APP_APPROVED),
+            (self.submitted, APP_SUBMITTED),
+            (self.completed, APP_COMPLETED),
+            (self.rejected, APP_REJECTED),
+            (self.bid_amount.is_some() || self.bid_commitment.is_some(), APP_BID),
+            (self.invoice_hash.is_some(), APP_INVOICED),
+            (self.disputed, APP_DISPUTED),
+            (self.declined, APP_DECLINED),
+        ] {
+            if set {
+                flags |= flag;
+            }
+        }
+        flags
+    }
+}
+
+// Portable snapshot of a freelancer's reputation. Nothing signs it: the
+// program owning the attestation account vouches for it, and the digest only
+// shows the summary wasn't altered or misread.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct AttestedReputation {
+    pub namespace: Pubkey,
+    pub subject: Pubkey,
+    pub jobs_completed: u64,
+    pub reputation_score: u64,
+    pub total_revenue_earned: u64,
+    pub issued_at: i64,
+}
+
+impl AttestedReputation {
+    pub fn digest(&self) -> Result<[u8; 32]> {
+        Ok(hashv(&[&self.try_to_vec()?]).to_bytes())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationAttestation {
+    pub summary: AttestedReputation,
+    pub digest: [u8; 32],
+    pub bump: u8,
+}
+
+// Marks an external attestation as imported so it can't be counted twice
+#[account]
+#[derive(InitSpace)]
+pub struct AttestationImport {
+    pub source: Pubkey,
+    pub issuer_program: Pubkey,
+    pub reputation_score: u64,
+    pub imported_at: i64,
+}
+
+// One revision of an application's submitted work. Each submission opens the
+// next revision, so rejected ones stay around for the client's review to
+// refer back to.
+#[account]
+#[derive(InitSpace)]
+pub struct Submission {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    // Paid the rent, and gets it back on close
+    pub freelancer: Pubkey,
+    // Zero for the first submission
+    pub revision: u8,
+    #[max_len(200)]
+    pub link: String,
+    #[max_len(300)]
+    pub narration: String,
+    pub submitted_at: i64,
+    pub status: SubmissionStatus,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SubmissionStatus {
+    Pending,
+    Rejected,
+    Approved,
+}
+
+// A party's escalation of a filled job to its arbiter
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub arbiter: Pubkey,
+    pub opened_by: Pubkey,
+    #[max_len(MAX_DISPUTE_TEXT_LEN)]
+    pub reason: String,
+    #[max_len(MAX_DISPUTE_TEXT_LEN)]
+    pub response: String,
+    pub opened_at: i64,
+    pub responded_at: i64,
+    pub resolved_at: i64,
+    // Share of the remaining escrow awarded to the freelancer
+    pub freelancer_bps: u16,
+    // Advisory expert opinion posted on the application before the dispute
+    pub second_opinion: Option<[u8; 32]>,
+    // The work and its latest rejection as they stood when the dispute opened
+    #[max_len(200)]
+    pub submission_link: String,
+    pub content_hash: Option<[u8; 32]>,
+    pub submitted_at: i64,
+    #[max_len(300)]
+    pub client_review: String,
+    // Zero unless the work stood rejected
+    pub rejected_at: i64,
+    pub rejections: u8,
+    pub bump: u8,
+    // Paid to the freelancer from the insurance pool after resolution
+    pub insurance_paid: Lamports,
+    // Part of the freelancer's award the escrow no longer held at resolution,
+    // the most an insurance claim may pay
+    pub shortfall: u64,
+}
+
+// An expert's non-binding opinion on an application's work, paid by one party
+#[account]
+#[derive(InitSpace)]
+pub struct SecondOpinion {
+    pub application: Pubkey,
+    pub requested_by: Pubkey,
+    pub expert: Pubkey,
+    pub fee: u64,
+    pub opinion_hash: Option<[u8; 32]>,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl SecondOpinion {
+    // The request behind a second opinion PDA, if one was made
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+}
+
+// A member of a client's team allowed to act on all of the client's jobs,
+// within the permissions granted
+#[account]
+#[derive(InitSpace)]
+pub struct Delegate {
+    pub client: Pubkey,
+    pub member: Pubkey,
+    // Hire applicants and approve submitted work
+    pub can_approve: bool,
+    pub can_cancel: bool,
+    // Reject submissions and sign off checklist items
+    pub can_review: bool,
+    pub bump: u8,
+}
+
+// A reminder about a job that a permissionless crank fires once `fire_at`
+// passes. The account holds the cranker's bounty on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct Reminder {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub kind: ReminderKind,
+    pub fire_at: i64,
+    pub bounty: u64,
+    pub bump: u8,
+}
+
+// Client-funded buffer paying the freelancer a daily fee while their
+// submission sits unreviewed past the review window; the lamports above rent
+// are the buffer
+#[account]
+#[derive(InitSpace)]
+pub struct ReviewSla {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub client: Pubkey,
+    pub daily_fee: u64,
+    pub buffer: u64,
+    // Submission the paid days were counted against; a new one restarts the count
+    pub submitted_at: i64,
+    pub days_paid: u64,
+    pub total_paid: u64,
+    pub bump: u8,
+}
+
+impl ReviewSla {
+    // Days the review of `application` has run past `late_from` by `now` and
+    // the fee still owed for them. The clock stops once the submission is
+    // reviewed, and a new submission restarts the count of paid days.
+    pub fn accrue(&mut self, application: &Application, late_from: i64, now: i64) -> (u64, u64) {
+        if self.submitted_at != application.submitted_at {
+            self.submitted_at = application.submitted_at;
+            self.days_paid = 0;
+        }
+        let reviewed = application.reviewed_at >= application.submitted_at;
+        let clock_stopped_at = if reviewed { application.reviewed_at } else { now };
+        let days_late = days_late(late_from, clock_stopped_at);
+        (days_late, late_fee(days_late, self.days_paid, self.daily_fee, self.buffer))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReminderKind {
+    ReviewDeadline,
+    HoldbackUnlock,
+    RetainerDue,
+}
+
+// Self-imposed cap on the lamports a client's approvals release per UTC day
+#[account]
+#[derive(InitSpace)]
+pub struct ReleaseLimit {
+    pub client: Pubkey,
+    // Zero means uncapped
+    pub daily_cap: u64,
+    // Looser cap waiting out the timelock
+    pub pending_cap: Option<u64>,
+    pub pending_effective_at: i64,
+    pub day: i64,
+    pub released_today: u64,
+    pub bump: u8,
+}
+
+impl ReleaseLimit {
+    // The cap in force at `now`, counting a pending change whose timelock ended
+    pub fn effective_cap(&self, now: i64) -> u64 {
+        match self.pending_cap {
+            Some(pending) if now >= self.pending_effective_at => pending,
+            _ => self.daily_cap,
+        }
+    }
+}
+
+// A signer's acknowledgment of a job's NDA
+#[account]
+#[derive(InitSpace)]
+pub struct NdaAcknowledgment {
+    pub job_post: Pubkey,
+    pub signer: Pubkey,
+    pub nda_hash: [u8; 32],
+    pub acknowledged_at: i64,
+    pub bump: u8,
+}
+
+// Client's invitation for one freelancer to apply to an invite-only job
+#[account]
+#[derive(InitSpace)]
+pub struct Invitation {
+    pub job_post: Pubkey,
+    pub freelancer: Pubkey,
+    pub invited_at: i64,
+    pub bump: u8,
+}
+
+// Groups a client's related jobs for budget and completion reporting
+#[account]
+#[derive(InitSpace)]
+pub struct Project {
+    pub namespace: Pubkey,
+    pub client: Pubkey,
+    #[max_len(MAX_PROJECT_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_URI_LEN)]
+    pub metadata_uri: String,
+    pub payment_mint: Option<Pubkey>,
+    // In the base units of `payment_mint`
+    pub budget: u64,
+    pub spent: u64,
+    pub jobs: u32,
+    pub jobs_completed: u32,
+    #[max_len(MAX_BUDGET_ALERTS)]
+    pub alert_thresholds_bps: Vec<u16>,
+    pub bump: u8,
+}
+
+// A job's escrow split into separately approved payouts
+#[account]
+#[derive(InitSpace)]
+pub struct JobMilestones {
+    pub job_post: Pubkey,
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    pub bump: u8,
+}
+
+impl JobMilestones {
+    // The milestone plan behind a milestones PDA, if the job has one
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+}
+
+// Operator-defined key/value fields attached to a job without changing the
+// JobPost layout; written by the client until the job is filled
+#[account]
+#[derive(InitSpace)]
+pub struct JobExtension {
+    pub job_post: Pubkey,
+    #[max_len(MAX_METADATA_ENTRIES)]
+    pub entries: Vec<MetadataEntry>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct MetadataEntry {
+    #[max_len(MAX_METADATA_KEY_LEN)]
+    pub key: String,
+    #[max_len(MAX_METADATA_VALUE_LEN)]
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Milestone {
+    #[max_len(MAX_MILESTONE_DESCRIPTION_LEN)]
+    pub description: String,
+    pub amount: BaseUnits,
+    pub deadline: i64,
+    #[max_len(200)]
+    pub submission_link: String,
+    pub submitted: bool,
+    pub approved: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneSpec {
+    pub description: String,
+    pub amount: BaseUnits,
+    pub deadline: i64,
+}
+
+// Which new jobs a freelancer wants to be notified about
+#[account]
+#[derive(InitSpace)]
+pub struct FeedPrefs {
+    pub freelancer: Pubkey,
+    // Bit `n` set = interested in category `n`; zero means every category
+    pub categories: u64,
+    pub min_amount: u64,
+    pub token_preference: TokenPreference,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TokenPreference {
+    Any,
+    NativeOnly,
+    TokensOnly,
+    Mint(Pubkey),
+}
+
+impl FeedPrefs {
+    pub fn matches(&self, job_post: &JobPost) -> bool {
+        let category_ok =
+            self.categories == 0 || self.categories & (1 << job_post.category) != 0;
+        let token_ok = match self.token_preference {
+            TokenPreference::Any => true,
+            TokenPreference::NativeOnly => job_post.is_native(),
+            TokenPreference::TokensOnly => !job_post.is_native(),
+            TokenPreference::Mint(mint) => job_post.payment_mint == Some(mint),
+        };
+        category_ok && token_ok && job_post.amount.0 >= self.min_amount
+    }
+}
+
+// What a freelancer declares for job matching: the languages they work in,
+// as ISO 639-1 codes, and their UTC offset in minutes
+#[account]
+#[derive(InitSpace)]
+pub struct FreelancerProfile {
+    pub freelancer: Pubkey,
+    #[max_len(MAX_PROFILE_LANGUAGES)]
+    pub languages: Vec<[u8; 2]>,
+    pub utc_offset_minutes: Option<i16>,
+    // Jobs hired and not yet settled, and how many the freelancer takes on at
+    // once (zero for no limit). `available` follows from the two.
+    pub active_jobs: u16,
+    pub max_active_jobs: u16,
+    pub available: bool,
+    pub bump: u8,
+}
+
+impl FreelancerProfile {
+    pub fn is_available(&self) -> bool {
+        self.max_active_jobs == 0 || self.active_jobs < self.max_active_jobs
+    }
+}
+
+// Public profile of a wallet, shared across namespaces
+#[account]
+#[derive(InitSpace)]
+pub struct Profile {
+    pub owner: Pubkey,
+    #[max_len(MAX_DISPLAY_NAME_LEN)]
+    pub display_name: String,
+    #[max_len(MAX_SKILLS, MAX_SKILL_LEN)]
+    pub skills: Vec<String>,
+    #[max_len(MAX_ATTACHMENT_URI_LEN)]
+    pub portfolio_link: String,
+    // Asking rate in lamports per hour; zero when not quoted
+    pub hourly_rate: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+// Inclusive range of UTC offsets, in minutes, a job's collaborators work from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct UtcOffsetRange {
+    pub min_minutes: i16,
+    pub max_minutes: i16,
+}
+
+impl UtcOffsetRange {
+    pub fn contains(&self, offset_minutes: i16) -> bool {
+        (self.min_minutes..=self.max_minutes).contains(&offset_minutes)
+    }
+}
+
+// Co-signer who must acknowledge a freelancer's large payouts
+#[account]
+#[derive(InitSpace)]
+pub struct PayoutGuardian {
+    pub freelancer: Pubkey,
+    pub guardian: Pubkey,
+    pub threshold: u64,
+    pub bump: u8,
+}
+
+impl PayoutGuardian {
+    // The guardian settings behind a guardian PDA, if the freelancer set any
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+        let guardian = Self::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok((guardian.guardian != Pubkey::default()).then_some(guardian))
+    }
+
+    pub fn requires_ack(&self, amount: u64) -> bool {
+        amount > 0 && amount >= self.threshold
+    }
+}
+
+// Wallet a user has confirmed for receiving payouts
+#[account]
+#[derive(InitSpace)]
+pub struct PayoutAccount {
+    pub owner: Pubkey,
+    pub destination: Pubkey,
+    pub bump: u8,
+}
+
+impl PayoutAccount {
+    // The registration behind a payout account PDA, if the owner made one
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+}
+
+// Lamports owed to a recipient that couldn't be paid directly at release time,
+// held until they claim them. Opened by whoever triggered the release.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimableVault {
+    pub namespace: Pubkey,
+    pub recipient: Pubkey,
+    pub rent_payer: Pubkey,
+    pub lamports_owed: u64,
+    pub bump: u8,
+}
+
+// Jobs completed between one client and one freelancer, used to weight
+// reputation so repeated pairs can't inflate each other's stats
+#[account]
+#[derive(InitSpace)]
+pub struct PairHistory {
+    pub namespace: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub jobs_completed: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    pub namespace: Pubkey,
+    pub admin: Pubkey,
+    #[max_len(MAX_ONRAMPS)]
+    pub onramps: Vec<Pubkey>,
+    pub retention_policy: RetentionPolicy,
+    pub retention_days: u16,
+    pub withholding_bps: u16,
+    pub tax_vault: Pubkey,
+    #[max_len(MAX_CHARITIES)]
+    pub charities: Vec<Pubkey>,
+    pub allow_self_dealing: bool,
+    pub high_value_threshold: u64,
+    pub min_account_age_days: u16,
+    pub trial_amount: u64,
+    // Pyth feed id recorded against SOL jobs; all zero when unset
+    pub sol_usd_feed: [u8; 32],
+    // FEATURE_* bits of the subsystems enabled in this namespace
+    pub features: u64,
+    #[max_len(MAX_ATTESTATION_ISSUERS)]
+    pub attestation_issuers: Vec<Pubkey>,
+    // Rules on disputes for jobs without their own arbiter; default key when unset
+    pub default_arbiter: Pubkey,
+    // Protocol fee on payouts, and the owner of the token accounts receiving it
+    pub fee_bps: u16,
+    pub fee_treasury: Pubkey,
+    // Days a client has to review a submission before the freelancer can claim
+    // payment; zero disables claims
+    pub review_window_days: u16,
+    // Days a freelancer has to dispute a rejection before the client may
+    // reclaim the escrow; zero leaves rejections open indefinitely
+    pub dispute_window_days: u16,
+    // Days after the first sealed review until either may be revealed alone;
+    // zero waits for both sides to commit
+    pub sealed_review_days: u16,
+    // Share of the platform fee, in basis points, paid to whoever referred
+    // the freelancer whose work is approved
+    pub referral_bps: u16,
+    // Share of an auto-released payout kept in escrow, and the days the
+    // client has to dispute the release against it; zero bps keeps none
+    pub release_holdback_bps: u16,
+    pub release_dispute_days: u16,
+    // Experts who may be paid for second opinions, and their fee in lamports
+    #[max_len(MAX_EXPERT_REVIEWERS)]
+    pub expert_reviewers: Vec<Pubkey>,
+    pub second_opinion_fee: u64,
+    // SOL releases at or above this raise a LargePayout event; zero when unset
+    pub large_payout_threshold: u64,
+    // Releases of jobs of at least this many lamports (and all token jobs)
+    // also need the co-signer's approval; zero turns it off. Jobs may name
+    // their own co-signer instead.
+    pub co_sign_threshold: u64,
+    pub co_signer: Pubkey,
+    // Bonus on refunds a client takes as credits instead of cash, paid from
+    // the operator's SOL fee balance
+    pub credit_refund_bonus_bps: u16,
+    // Lamport bounds on new SOL jobs and the shortest span from start to end
+    // date of any new job, in seconds; zero leaves each unbounded
+    pub min_job_amount: u64,
+    pub max_job_amount: u64,
+    pub min_job_duration: i64,
+    // Emergency stop: no funds enter escrow or leave it except as refunds
+    pub paused: bool,
+    // Lamports staked per application to jobs of at least the threshold
+    // (and all token jobs); zero stake turns it off
+    pub application_stake: u64,
+    pub application_stake_threshold: u64,
+    // Reject applications missing a required language instead of only
+    // emitting LanguageMismatch
+    pub strict_language_match: bool,
+    // Likewise for applicants outside a job's UTC offset range
+    pub strict_timezone_match: bool,
+    // Set once the admin opens the insurance pool; SOL fees then can't be
+    // collected without it, so no payout skips its cut
+    pub insurance_enabled: bool,
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    // Whether releases from the job need a co-signature. Compared against
+    // the job's whole value, not the single release, so splitting a job into
+    // milestones, periods or timesheets doesn't slip under the threshold. The
+    // threshold is in lamports, so token jobs always do while it is set.
+    pub fn requires_co_sign(&self, job_post: &JobPost) -> bool {
+        self.co_sign_threshold > 0
+            && (!job_post.is_native() || job_post.amount.0 >= self.co_sign_threshold)
+    }
+}
+
+// Return data left by a failed validation. `expected` is the bound the field
+// had to meet (a minimum, maximum or exact value) and `actual` what it was.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ErrorContext {
+    pub code: u32,
+    pub field: String,
+    pub expected: i128,
+    pub actual: i128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct JobSpec {
+    #[max_len(100)]
+    pub title: String,
+    #[max_len(MAX_JOB_DESCRIPTION_LEN)]
+    pub description: String,
+    pub amount: u64,
+    pub start_date: i64,
+    pub end_date: i64,
+}
+
+// The last few client-chosen nonces an account has accepted. A wallet retry
+// or RPC rebroadcast carries the same nonce and is refused, so payments
+// and hour logs, which aren't idempotent, can't apply twice.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct NonceRing {
+    // 0 marks an unused slot
+    pub nonces: [u64; NONCE_RING_LEN],
+    pub next: u8,
+}
+
+impl NonceRing {
+    // Records `nonce`, refusing one still in the ring. No nonce opts out.
+    pub fn consume(&mut self, nonce: Option<u64>) -> Result<()> {
+        let Some(nonce) = nonce else {
+            return Ok(());
+        };
+        require!(nonce != 0, ErrorCode::InvalidInput);
+        require!(!self.nonces.contains(&nonce), ErrorCode::NonceReused);
+        self.nonces[usize::from(self.next)] = nonce;
+        self.next = (self.next + 1) % NONCE_RING_LEN as u8;
+        Ok(())
+    }
+}
+
+// Signer's bound on how stale a settlement may be when it lands: a slot they
+// saw while signing and how many slots past it the transaction stays valid.
+// Durable-nonce transactions signed offline never expire on their own, so
+// this keeps one from settling long after a dispute, freeze or amendment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SlotBound {
+    pub reference_slot: u64,
+    pub max_staleness: u64,
+}
+
+impl SlotBound {
+    pub fn check(&self, current_slot: u64) -> Result<()> {
+        require!(current_slot >= self.reference_slot, ErrorCode::InvalidInput);
+        require!(
+            current_slot - self.reference_slot <= self.max_staleness,
+            ErrorCode::StaleTransaction
+        );
+        Ok(())
+    }
+
+    // No bound opts out
+    pub fn require_fresh(bound: Option<Self>) -> Result<()> {
+        match bound {
+            Some(bound) => bound.check(Clock::get()?.slot),
+            None => Ok(()),
+        }
+    }
+}
+
+// Judges of a bounty, the votes needed to award it, and a bit per judge
+// (by position) who has voted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct BountyTerms {
+    #[max_len(MAX_BOUNTY_JUDGES)]
+    pub judges: Vec<Pubkey>,
+    pub threshold: u8,
+    pub voted: u8,
+}
+
+// Someone's claim on a bounty, with the votes it has gathered
+#[account]
+#[derive(InitSpace)]
+pub struct BountyClaim {
+    pub job_post: Pubkey,
+    pub claimant: Pubkey,
+    #[max_len(MAX_LINK_LEN)]
+    pub link: String,
+    pub submitted_at: i64,
+    pub votes: u8,
+    pub bump: u8,
+}
+
+// Periodic pay for a retained freelancer: `periods` installments of
+// `period_amount`, one each `period_seconds` after the hire
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct RetainerTerms {
+    pub period_amount: u64,
+    pub period_seconds: i64,
+    pub periods: u16,
+    pub periods_paid: u16,
+    // Start of the first period; zero until a freelancer is hired
+    pub started_at: i64,
+}
+
+impl RetainerTerms {
+    // Periods that have fully run by `now`
+    pub fn periods_due(&self, now: i64) -> u16 {
+        periods_elapsed(self.started_at, self.period_seconds, self.periods, now)
+    }
+}
+
+// Hourly pay: up to `max_hours` at `hourly_rate`. Hours are logged as
+// numbered timesheet entries and paid as the client approves them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct HourlyTerms {
+    pub hourly_rate: u64,
+    pub max_hours: u32,
+    // Hours in entries pending or approved; rejected entries drop out
+    pub hours_logged: u32,
+    pub hours_approved: u32,
+    // Timesheet entries logged so far, numbering the next one
+    pub entries: u16,
+}
+
+// Hours a freelancer logged on an hourly job, paid once the client approves
+#[account]
+#[derive(InitSpace)]
+pub struct TimesheetEntry {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer: Pubkey,
+    pub index: u16,
+    pub hours: u32,
+    #[max_len(MAX_TIMESHEET_NOTE_LEN)]
+    pub note: String,
+    pub logged_at: i64,
+    pub approved: bool,
+    pub bump: u8,
+}
+
+// Productized block of a freelancer's time: `hours` at `hourly_rate`, sold
+// to one client who prepays it into this account and draws it down with
+// co-signed `consume_hours` entries. Holds the price of the hours left above
+// its rent until they are consumed or refunded.
+#[account]
+#[derive(InitSpace)]
+pub struct HourBlock {
+    pub namespace: Pubkey,
+    pub freelancer: Pubkey,
+    pub block_id: u64,
+    pub hours: u32,
+    pub hourly_rate: u64,
+    // How long a purchased block stays usable, in seconds
+    pub validity: i64,
+    // The buyer, once purchased
+    pub client: Option<Pubkey>,
+    pub expires_at: i64,
+    pub hours_consumed: u32,
+    // Consumption entries drawn so far, numbering the next one
+    pub entries: u16,
+    pub bump: u8,
+}
+
+impl HourBlock {
+    pub fn price(&self) -> Result<u64> {
+        self.hourly_rate
+            .checked_mul(u64::from(self.hours))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    pub fn hours_left(&self) -> u32 {
+        self.hours - self.hours_consumed
+    }
+}
+
+// Linear vesting of the job amount from `start_date` to `end_date`, or to
+// `stopped_at` once the client cancels the unvested rest
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct PaymentStream {
+    pub released_so_far: BaseUnits,
+    pub stopped_at: i64,
+}
+
+// Who may apply: anyone, or only freelancers the client invited
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum JobVisibility {
+    Public,
+    InviteOnly,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ModerationAction {
+    Hide,
+    Unhide,
+    // Hide for good, keeping only a hash of the title and description
+    Takedown,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RetentionPolicy {
+    KeepFull,
+    HashOnly,
+    AutoClose,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorStats {
+    pub namespace: Pubkey,
+    pub jobs_posted: u64,
+    pub jobs_completed: u64,
+    pub jobs_cancelled: u64,
+    pub volume_escrowed: u64,
+    pub volume_released: u64,
+    pub fee_revenue: Lamports,
+    pub fees_withdrawn: Lamports,
+    pub disputes_opened: u64,
+    pub disputes_resolved: u64,
+    // Aggregate of private satisfaction scores; NPS = promoters - detractors
+    pub registered_jobs: u64,
+    pub satisfaction_responses: u64,
+    pub satisfaction_total: u64,
+    pub promoters: u64,
+    pub detractors: u64,
+    pub bump: u8,
+    // When `heartbeat` last emitted this namespace's metrics
+    pub last_heartbeat_at: i64,
+}
+
+impl OperatorStats {
+    // SOL fees collected and not yet withdrawn
+    pub fn fee_balance(&self) -> Result<Lamports> {
+        self.fee_revenue.checked_sub(self.fees_withdrawn)
+    }
+
+    pub fn record_job_posted(&mut self, escrowed: u64) -> Result<()> {
+        self.jobs_posted = checked_add(self.jobs_posted, 1)?;
+        self.volume_escrowed = checked_add(self.volume_escrowed, escrowed)?;
+        Ok(())
+    }
+
+    pub fn record_job_completed(&mut self, released: u64) -> Result<()> {
+        self.jobs_completed = checked_add(self.jobs_completed, 1)?;
+        self.volume_released = checked_add(self.volume_released, released)?;
+        Ok(())
+    }
+}
+
+// Namespace-wide pool topped up with a share of SOL fees, out of which the
+// admin covers what a freelancer was awarded in a dispute but the escrow
+// could no longer pay. Holds `contributed - paid_out` above its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePool {
+    pub namespace: Pubkey,
+    // Share of each SOL fee paid into the pool, in basis points
+    pub contribution_bps: u16,
+    pub contributed: Lamports,
+    pub paid_out: Lamports,
+    pub claims_paid: u64,
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    pub fn balance(&self) -> Result<Lamports> {
+        self.contributed.checked_sub(self.paid_out)
+    }
+}
+
+// Programs the operator lets run custom compliance, loyalty or analytics
+// logic around every payout to a freelancer: a submission's settlement, each
+// partial release, an agreed settlement, a dispute ruling and an hour block
+// entry. Each is invoked via CPI with `SettlementHookPayload`, the pre-hook
+// before any funds move and the post-hook once they have; a hook that fails
+// reverts the payout, which is how the pre-hook vetoes one.
+#[account]
+#[derive(InitSpace)]
+pub struct SettlementHooks {
+    pub namespace: Pubkey,
+    pub pre_settlement: Option<Pubkey>,
+    pub post_settlement: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl SettlementHooks {
+    // The hooks behind a settlement hooks PDA, if the operator registered any
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+}
+
+// Instruction data passed to a settlement hook after its 8-byte selector,
+// `global:pre_settlement` or `global:post_settlement` hashed the way Anchor
+// names instructions. `paid` is zero for the pre-hook. Hour block entries
+// name the block as `job_post` and leave `application` zeroed, as do guardian
+// acknowledgements; a bounty names the winning claim as `application`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct SettlementHookPayload {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub gross: u64,
+    pub paid: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RecurringJob {
+    pub namespace: Pubkey,
+    pub client: Pubkey,
+    pub template: JobSpec,
+    pub duration: i64,
+    pub cadence_seconds: i64,
+    pub next_run_at: i64,
+    pub occurrences: u32,
+    pub spawned: u32,
+    pub bump: u8,
+}
+
+// A freelancer's payouts in one namespace for one calendar year, so annual
+// reporting (e.g. 1099s) is a single account read
+#[account]
+#[derive(InitSpace)]
+pub struct YearlyEarnings {
+    pub namespace: Pubkey,
+    pub freelancer: Pubkey,
+    pub year: u16,
+    pub gross: u64,
+    pub fees: u64,
+    pub withheld: u64,
+    pub net: u64,
+    pub jobs: u64,
+}
+
+impl YearlyEarnings {
+    pub fn record_payout(&mut self, gross: u64, fees: u64, withheld: u64) -> Result<()> {
+        let net = checked_sub(checked_sub(gross, fees)?, withheld)?;
+        self.gross = checked_add(self.gross, gross)?;
+        self.fees = checked_add(self.fees, fees)?;
+        self.withheld = checked_add(self.withheld, withheld)?;
+        self.net = checked_add(self.net, net)?;
+        self.jobs = checked_add(self.jobs, 1)?;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DonationPreference {
+    pub freelancer: Pubkey,
+    pub charity: Pubkey,
+    pub bps: u16,
+}
+
+impl DonationPreference {
+    // The freelancer's preference, if they ever set one
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+}
+
+// Public tally of everything donated to a charity through payouts
+#[account]
+#[derive(InitSpace)]
+pub struct CharityTotals {
+    pub namespace: Pubkey,
+    pub charity: Pubkey,
+    pub total_donated: u64,
+    pub donations: u64,
+}
+
+// What a referrer has earned from the platform fee on approved work by the
+// freelancers they referred
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralStats {
+    pub namespace: Pubkey,
+    pub referrer: Pubkey,
+    pub rewards: u64,
+    // Lamports only; token rewards are counted but not summed
+    pub total_earned: u64,
+    pub bump: u8,
+}
+
+// Jobs a client has posted. The n-th job post, counting from zero, is seeded
+// with n as little-endian bytes, so frontends can enumerate a client's jobs
+// from `jobs_posted` and titles needn't be unique or fit in a seed.
+#[account]
+#[derive(InitSpace)]
+pub struct JobCounter {
+    pub client: Pubkey,
+    pub jobs_posted: u64,
+    pub bump: u8,
+}
+
+impl JobCounter {
+    // Books the job post just created from the current count
+    pub fn record_post(&mut self, client: Pubkey, bump: u8) -> Result<()> {
+        self.client = client;
+        self.bump = bump;
+        self.jobs_posted = checked_add(self.jobs_posted, 1)?;
+        Ok(())
+    }
+}
+
+// ----------------- CONTEXTS -----------------
+
+#[derive(Accounts)]
+pub struct InitializeJobPost<'info> {
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + JobCounter::INIT_SPACE,
+        seeds = [b"job_counter", client.key().as_ref()],
+        bump
+    )]
+    pub job_counter: Account<'info, JobCounter>,
+
+    #[account(
+        init,
+        payer = client,
+        space = 8 + JobPost::INIT_SPACE,
+        seeds = [b"job_post", client.key().as_ref(), &job_counter.jobs_posted.to_le_bytes()],
+        bump
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault, no data)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_CLIENT], client.key().as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            platform_config.namespace.as_ref(),
+            &[ROLE_CLIENT],
+            client.key().as_ref(),
+            &client_monthly_stats.year.to_le_bytes(),
+            &[client_monthly_stats.month]
+        ],
+        bump = client_monthly_stats.bump
+    )]
+    pub client_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Pyth price update for the operator's SOL/USD feed, required while
+    /// one is configured; decoded and checked in `observe_price`
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", platform_config.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    /// Client's jobs index page the new job is listed on
+    #[account(
+        mut,
+        constraint = client_index.owner == client.key() && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+    /// Client's promotional credits, spent on the escrow before their own funds
+    #[account(
+        mut,
+        seeds = [b"credits", platform_config.namespace.as_ref(), client.key().as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeJobPostsBatch<'info> {
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + JobCounter::INIT_SPACE,
+        seeds = [b"job_counter", client.key().as_ref()],
+        bump
+    )]
+    pub job_counter: Account<'info, JobCounter>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_CLIENT], client.key().as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            platform_config.namespace.as_ref(),
+            &[ROLE_CLIENT],
+            client.key().as_ref(),
+            &client_monthly_stats.year.to_le_bytes(),
+            &[client_monthly_stats.month]
+        ],
+        bump = client_monthly_stats.bump
+    )]
+    pub client_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", platform_config.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    /// Client's jobs index page the new job is listed on
+    #[account(
+        mut,
+        constraint = client_index.owner == client.key() && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(template: JobSpec)]
+pub struct CreateRecurringJob<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + RecurringJob::INIT_SPACE,
+        seeds = [b"recurring_job", client.key().as_ref(), template.title.as_bytes()],
+        bump
+    )]
+    pub recurring_job: Account<'info, RecurringJob>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SpawnNextOccurrence<'info> {
+    #[account(
+        mut,
+        seeds = [b"recurring_job", recurring_job.client.as_ref(), recurring_job.template.title.as_bytes()],
+        bump = recurring_job.bump
+    )]
+    pub recurring_job: Account<'info, RecurringJob>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + JobPost::INIT_SPACE,
+        seeds = [b"job_post", recurring_job.key().as_ref(), &recurring_job.spawned.to_le_bytes()],
+        bump
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault, no data)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", recurring_job.namespace.as_ref(), &[ROLE_CLIENT], recurring_job.client.as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            recurring_job.namespace.as_ref(),
+            &[ROLE_CLIENT],
+            recurring_job.client.as_ref(),
+            &client_monthly_stats.year.to_le_bytes(),
+            &[client_monthly_stats.month]
+        ],
+        bump = client_monthly_stats.bump
+    )]
+    pub client_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    #[account(
+        seeds = [b"platform_config", recurring_job.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", recurring_job.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRecurringJob<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized,
+        close = client
+    )]
+    pub recurring_job: Account<'info, RecurringJob>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTokenJobPost<'info> {
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + JobCounter::INIT_SPACE,
+        seeds = [b"job_counter", client.key().as_ref()],
+        bump
+    )]
+    pub job_counter: Account<'info, JobCounter>,
+
+    #[account(
+        init,
+        payer = client,
+        space = 8 + JobPost::INIT_SPACE,
+        seeds = [b"job_post", client.key().as_ref(), &job_counter.jobs_posted.to_le_bytes()],
+        bump
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Escrow PDA, authority of the escrow vault and delegate for funding
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = client,
+        associated_token::mint = mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// Client's jobs index page the new job is listed on
+    #[account(
+        mut,
+        constraint = client_index.owner == client.key() && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PublishJob<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized,
+        constraint = job_post.payment_mint == Some(mint.key()) @ ErrorCode::InvalidAccount
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA, the client's approved delegate
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = client,
+        token::token_program = token_program
+    )]
+    pub client_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_CLIENT], client.key().as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            job_post.namespace.as_ref(),
+            &[ROLE_CLIENT],
+            client.key().as_ref(),
+            &client_monthly_stats.year.to_le_bytes(),
+            &[client_monthly_stats.month]
+        ],
+        bump = client_monthly_stats.bump
+    )]
+    pub client_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeJobPostViaOnramp<'info> {
+    #[account(
+        init_if_needed,
+        payer = onramp,
+        space = 8 + JobCounter::INIT_SPACE,
+        seeds = [b"job_counter", client.key().as_ref()],
+        bump
+    )]
+    pub job_counter: Account<'info, JobCounter>,
+
+    #[account(
+        init,
+        payer = onramp,
+        space = 8 + JobPost::INIT_SPACE,
+        seeds = [b"job_post", client.key().as_ref(), &job_counter.jobs_posted.to_le_bytes()],
+        bump
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault, no data)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = onramp,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_CLIENT], client.key().as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            platform_config.namespace.as_ref(),
+            &[ROLE_CLIENT],
+            client.key().as_ref(),
+            &client_monthly_stats.year.to_le_bytes(),
+            &[client_monthly_stats.month]
+        ],
+        bump = client_monthly_stats.bump
+    )]
+    pub client_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        constraint = platform_config.onramps.contains(&onramp.key()) @ ErrorCode::OnrampNotAllowed
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", platform_config.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    pub client: Signer<'info>,
+
+    #[account(mut)]
+    pub onramp: Signer<'info>,
+    /// Client's jobs index page the new job is listed on
+    #[account(
+        mut,
+        constraint = client_index.owner == client.key() && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyToJob<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Application::INIT_SPACE,
+        seeds = [b"application", job_post.key().as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+
+    /// Pays fees and rent; the freelancer themselves or a sponsoring relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], freelancer.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Required when the job has an NDA
+    #[account(
+        seeds = [b"nda", job_post.key().as_ref(), freelancer.key().as_ref()],
+        bump = nda_acknowledgment.bump
+    )]
+    pub nda_acknowledgment: Option<Account<'info, NdaAcknowledgment>>,
+
+    /// Required when the job is invite-only
+    #[account(
+        seeds = [b"invitation", job_post.key().as_ref(), freelancer.key().as_ref()],
+        bump = invitation.bump
+    )]
+    pub invitation: Option<Account<'info, Invitation>>,
+
+    /// Checked against the job's required languages and UTC offsets
+    #[account(
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), freelancer.key().as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    /// Referenced by the application in place of a resume link
+    #[account(
+        seeds = [b"profile", freelancer.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, Profile>>,
+    /// Freelancer's applications index page the application is listed on
+    #[account(
+        mut,
+        constraint = freelancer_index.owner == freelancer.key() && freelancer_index.kind == INDEX_APPLICATIONS @ ErrorCode::InvalidAccount
+    )]
+    pub freelancer_index: Option<Account<'info, UserIndexPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Application::INIT_SPACE,
+        seeds = [b"application", job_post.key().as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub application: Account<'info, Application>,
+
+    pub freelancer: Signer<'info>,
+
+    /// Pays fees and rent; the freelancer themselves or a sponsoring relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    /// Hired freelancer's active jobs go up, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), freelancer.key().as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+    /// Freelancer's applications index page the application is listed on
+    #[account(
+        mut,
+        constraint = freelancer_index.owner == freelancer.key() && freelancer_index.kind == INDEX_APPLICATIONS @ ErrorCode::InvalidAccount
+    )]
+    pub freelancer_index: Option<Account<'info, UserIndexPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireOffer<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::InvalidAccount
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    /// CHECK: Job client, receives the refund
+    pub client: UncheckedAccount<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of the refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireTrial<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::InvalidAccount
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    /// The freelancer's application, required once the trial was accepted
+    pub application: Option<Account<'info, Application>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    /// CHECK: Job client, receives the refund
+    pub client: UncheckedAccount<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of the refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConvertTrialToJob<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized,
+        constraint = trial_job.freelancer == Some(freelancer.key()) @ ErrorCode::InvalidAccount
+    )]
+    pub trial_job: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"application", trial_job.key().as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub trial_application: Account<'info, Application>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + JobCounter::INIT_SPACE,
+        seeds = [b"job_counter", client.key().as_ref()],
+        bump
+    )]
+    pub job_counter: Account<'info, JobCounter>,
+
+    #[account(
+        init,
+        payer = client,
+        space = 8 + JobPost::INIT_SPACE,
+        seeds = [b"job_post", client.key().as_ref(), &job_counter.jobs_posted.to_le_bytes()],
+        bump
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault, no data)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Application::INIT_SPACE,
+        seeds = [b"application", job_post.key().as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub application: Account<'info, Application>,
+
+    /// CHECK: Freelancer who completed the trial
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_CLIENT], client.key().as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            platform_config.namespace.as_ref(),
+            &[ROLE_CLIENT],
+            client.key().as_ref(),
+            &client_monthly_stats.year.to_le_bytes(),
+            &[client_monthly_stats.month]
+        ],
+        bump = client_monthly_stats.bump
+    )]
+    pub client_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    #[account(
+        seeds = [b"platform_config", trial_job.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Pyth price update for the operator's SOL/USD feed, required while
+    /// one is configured; decoded and checked in `observe_price`
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", platform_config.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    /// Client's jobs index page the new job is listed on
+    #[account(
+        mut,
+        constraint = client_index.owner == client.key() && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+    /// Freelancer's applications index page the application is listed on
+    #[account(
+        mut,
+        constraint = freelancer_index.owner == freelancer.key() && freelancer_index.kind == INDEX_APPLICATIONS @ ErrorCode::InvalidAccount
+    )]
+    pub freelancer_index: Option<Account<'info, UserIndexPage>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobNda<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetClientPrivateNote<'info> {
+    #[account(has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(freelancer: Pubkey)]
+pub struct InviteFreelancer<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Invitation::INIT_SPACE,
+        seeds = [b"invitation", job_post.key().as_ref(), freelancer.as_ref()],
+        bump
+    )]
+    pub invitation: Account<'info, Invitation>,
+
+    #[account(has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcknowledgeNda<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + NdaAcknowledgment::INIT_SPACE,
+        seeds = [b"nda", job_post.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub nda_acknowledgment: Account<'info, NdaAcknowledgment>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: Pubkey, category: u8)]
+pub struct OpenCategoryIndex<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CategoryIndex::INIT_SPACE,
+        seeds = [b"category_index", namespace.as_ref(), &[category]],
+        bump
+    )]
+    pub category_index: Account<'info, CategoryIndex>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: u8, page: u32)]
+pub struct OpenUserIndexPage<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + UserIndexPage::INIT_SPACE,
+        seeds = [b"user_index", owner.key().as_ref(), &[kind], &page.to_le_bytes()],
+        bump
+    )]
+    pub index_page: Account<'info, UserIndexPage>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobCategory<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    /// Index of the job's new category; checked in the handler, as the
+    /// category is an argument
+    #[account(
+        mut,
+        constraint = category_index.namespace == job_post.namespace @ ErrorCode::InvalidAccount
+    )]
+    pub category_index: Option<Account<'info, CategoryIndex>>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateJobPost<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptCounterOffer<'info> {
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow account
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Job's milestones PDA; may be uninitialized, read in the handler
+    pub job_milestones: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxHires<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Job's milestones PDA; may be uninitialized, read in the handler
+    pub job_milestones: UncheckedAccount<'info>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdjustJobBudget<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow account
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Job's milestones PDA; may be uninitialized, read in the handler
+    pub job_milestones: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitBountyClaim<'info> {
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + BountyClaim::INIT_SPACE,
+        seeds = [b"bounty_claim", job_post.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, BountyClaim>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VoteBountyClaim<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(mut, has_one = job_post @ ErrorCode::InvalidAccount)]
+    pub claim: Account<'info, BountyClaim>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow account
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        constraint = claimant.key() == claim.claimant @ ErrorCode::InvalidAccount
+    )]
+    /// CHECK: The claim's author, paid when this vote awards the bounty
+    pub claimant: UncheckedAccount<'info>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), claim.claimant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    pub judge: Signer<'info>,
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBountyClaim<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        has_one = claimant @ ErrorCode::Unauthorized,
+        has_one = job_post @ ErrorCode::InvalidAccount
+    )]
+    pub claim: Account<'info, BountyClaim>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealJobAmount<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow account
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Job's milestones PDA; may be uninitialized, read in the handler
+    pub job_milestones: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// Hired freelancer's application, required when they reveal
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Option<Account<'info, Application>>,
+
+    #[account(
+        mut,
+        constraint = client.key() == job_post.client @ ErrorCode::InvalidAccount
+    )]
+    /// CHECK: The job's client, receiving the escrow above the price
+    pub client: UncheckedAccount<'info>,
+
+    /// The client or the hired freelancer
+    pub revealer: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintCompletionBadge<'info> {
+    #[account(
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"badge", application.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Badge mint PDA, created as a Token-2022 mint in the handler
+    pub badge_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &applicant.key(),
+            &badge_mint.key(),
+            &token_program.key(),
+        ) @ ErrorCode::InvalidAccount
+    )]
+    /// CHECK: Freelancer's associated token account for the badge, created
+    /// in the handler
+    pub badge_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TipFreelancer<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], application.applicant.as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked in the handler
+    pub freelancer: UncheckedAccount<'info>,
+
+    /// Client's token account paying a tip on a token job
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobDependency<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncJobCard<'info> {
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + JobCard::INIT_SPACE,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobFeatured<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct RegisterJob<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RegistryPage::INIT_SPACE,
+        seeds = [b"registry", job_post.namespace.as_ref(), &page.to_le_bytes()],
+        bump
+    )]
+    pub registry_page: Account<'info, RegistryPage>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ListJobs<'info> {
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetRepricingClause<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RepriceJob<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Pyth price update for the configured feed, checked in `observe_price`
+    pub price_update: Option<UncheckedAccount<'info>>,
+}
+
+// Escrows to check come in as remaining accounts
+#[derive(Accounts)]
+pub struct CheckEscrows {}
+
+#[derive(Accounts)]
+pub struct ReconcileEscrowRent<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct EmitEscrowSnapshot<'info> {
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds, only read
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateEscrow<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Legacy escrow PDA, checked to be a bare system account
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Pays the rent of the typed escrow data
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut, owner = crate::ID @ ErrorCode::InvalidAccount)]
+    /// CHECK: Job post, application or user stats, possibly under an older
+    /// layout; told apart by its discriminator in the handler
+    pub account: UncheckedAccount<'info>,
+
+    /// Pays the rent of any space the current layout adds
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Namespace a job post from before namespaces joins; required for those
+    pub platform_config: Option<Account<'info, PlatformConfig>>,
+
+    /// CHECK: Revision 0 submission PDA, created for a first-release
+    /// application that carries submitted work; checked in the handler
+    #[account(mut)]
+    pub submission: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PreviewSettlement<'info> {
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Applicant's donation preference PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"donation", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub donation_preference: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExportAttestation<'info> {
+    #[account(
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_FREELANCER], freelancer.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        init_if_needed,
+        payer = freelancer,
+        space = 8 + ReputationAttestation::INIT_SPACE,
+        seeds = [b"attestation", platform_config.namespace.as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, ReputationAttestation>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyAttestation<'info> {
+    pub attestation: Account<'info, ReputationAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct ImportAttestation<'info> {
+    /// CHECK: Attestation owned by an allowlisted issuer program, parsed in the handler
+    pub source_attestation: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = freelancer,
+        space = 8 + AttestationImport::INIT_SPACE,
+        seeds = [
+            b"attestation_import",
+            platform_config.namespace.as_ref(),
+            source_attestation.key().as_ref()
+        ],
+        bump
+    )]
+    pub import_record: Account<'info, AttestationImport>,
+
+    #[account(
+        init_if_needed,
+        payer = freelancer,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_FREELANCER], freelancer.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterPayoutAccount<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + PayoutAccount::INIT_SPACE,
+        seeds = [b"payout_account", platform_config.namespace.as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub payout_account: Account<'info, PayoutAccount>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutTeam<'info> {
+    #[account(
+        mut,
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    pub applicant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFunds<'info> {
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = recipient @ ErrorCode::Unauthorized,
+        has_one = rent_payer @ ErrorCode::InvalidAccount,
+        seeds = [b"claimable", claimable_vault.namespace.as_ref(), recipient.key().as_ref()],
+        bump = claimable_vault.bump
+    )]
+    pub claimable_vault: Account<'info, ClaimableVault>,
+
+    #[account(
+        seeds = [b"platform_config", claimable_vault.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// CHECK: Opened the vault; gets its rent back
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutGuardian<'info> {
+    #[account(
+        init_if_needed,
+        payer = freelancer,
+        space = 8 + PayoutGuardian::INIT_SPACE,
+        seeds = [b"guardian", platform_config.namespace.as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub payout_guardian: Account<'info, PayoutGuardian>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Required to change a guardian that is already set
+    pub current_guardian: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcknowledgePayout<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), payout_guardian.freelancer.as_ref()],
+        bump = payout_guardian.bump,
+        has_one = guardian @ ErrorCode::Unauthorized
+    )]
+    pub payout_guardian: Account<'info, PayoutGuardian>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the payout
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    pub guardian: Signer<'info>,
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleHoldback<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    /// The client's dispute over the release, required to resolve one
+    #[account(
+        mut,
+        seeds = [b"dispute", application.key().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Option<Account<'info, Dispute>>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the holdback
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Client wallet, or their token account for token jobs; checked on payout
+    pub client: UncheckedAccount<'info>,
+
+    /// The arbiter when resolving; anyone when releasing
+    pub authority: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetApplicationsSummary<'info> {
+    pub job_post: Account<'info, JobPost>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeedPrefs<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + FeedPrefs::INIT_SPACE,
+        seeds = [b"feed_prefs", platform_config.namespace.as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub feed_prefs: Account<'info, FeedPrefs>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub freelancer: Signer<'info>,
+
+    /// Pays fees and rent; the freelancer themselves or a sponsoring relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetFreelancerProfile<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + FreelancerProfile::INIT_SPACE,
+        seeds = [b"freelancer_profile", platform_config.namespace.as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub freelancer_profile: Account<'info, FreelancerProfile>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub freelancer: Signer<'info>,
+
+    /// Pays fees and rent; the freelancer themselves or a sponsoring relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreelancerAvailability<'info> {
+    pub freelancer_profile: Account<'info, FreelancerProfile>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProfile<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Profile::INIT_SPACE,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, Profile>,
+
+    pub owner: Signer<'info>,
+
+    /// Pays fees and rent; the owner themselves or a sponsoring relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump,
+        has_one = owner @ ErrorCode::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FeedMatches<'info> {
+    pub feed_prefs: Account<'info, FeedPrefs>,
+    pub job_post: Account<'info, JobPost>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveApplication<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Required when the high-value gate applies to the job
+    #[account(
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], application.applicant.as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Option<Account<'info, UserStats>>,
+
+    /// Hired freelancer's active jobs go up, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    /// Required when the job depends on another job
+    pub dependency: Option<Account<'info, JobPost>>,
+
+    /// Required when the approver is a delegate of the client
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), approver.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// The client or a delegate of theirs allowed to approve
+    #[account(mut)]
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewApplicationsBatch<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Required to approve when the job depends on another job
+    pub dependency: Option<Account<'info, JobPost>>,
+
+    /// Required when the approver is a delegate of the client
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), approver.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// The client or a delegate of theirs allowed to approve
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StartAuction<'info> {
+    #[account(
+        mut,
+        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitBid<'info> {
+    #[account(
+        mut,
+        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], freelancer.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Option<Account<'info, UserStats>>,
+
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAuction<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::InvalidAccount
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Option<Account<'info, Application>>,
+
+    /// Winning bidder's profile; active jobs go up, when they keep one
+    #[account(mut)]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Job client, receives the unspent budget
+    pub client: UncheckedAccount<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AmendScope<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    /// Client or approved freelancer of the job
+    pub party: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RecordSatisfaction<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// Client or freelancer of the job
+    pub party: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SealedReview<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Client or freelancer of the job
+    pub author: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EditFeedback<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    /// The client editing their review, or the freelancer replying to it
+    pub author: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitWork<'info> {
+    #[account(
+        mut,
+        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        init,
+        payer = freelancer,
+        space = 8 + Submission::INIT_SPACE,
+        seeds = [b"submission", application.key().as_ref(), &[application.submissions]],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    /// Required when the job depends on another job
+    pub dependency: Option<Account<'info, JobPost>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddDeliverable<'info> {
+    #[account(
+        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        init_if_needed,
+        payer = freelancer,
+        space = 8 + Deliverables::INIT_SPACE,
+        seeds = [b"deliverables", application.key().as_ref()],
+        bump
+    )]
+    pub deliverables: Account<'info, Deliverables>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptDeliverable<'info> {
+    #[account(
+        mut,
+        seeds = [b"deliverables", application.key().as_ref()],
+        bump = deliverables.bump
+    )]
+    pub deliverables: Account<'info, Deliverables>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"release_limit", job_post.client.as_ref()],
+        bump
+    )]
+    /// CHECK: Client's release cap PDA; may be uninitialized, read in the handler
+    pub release_limit: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    /// CHECK: Freelancer's guardian PDA; may be uninitialized, read in the handler
+    pub payout_guardian: UncheckedAccount<'info>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Fee treasury's token account, required for token jobs while a fee
+    /// is set; checked on payout
+    pub fee_treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault (its token account for token jobs), required while
+    /// withholding is enabled; checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    /// CHECK: Freelancer's claimable vault PDA, may be uninitialized; takes a
+    /// SOL payout their wallet can't receive
+    #[account(
+        mut,
+        seeds = [b"claimable", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub claimable_vault: Option<UncheckedAccount<'info>>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Pays for a claimable vault opened for the freelancer
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReviewDeliverable<'info> {
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(constraint = job_post.is_hired(&application) @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"deliverables", application.key().as_ref()],
+        bump = deliverables.bump
+    )]
+    pub deliverables: Account<'info, Deliverables>,
+
+    /// Required when the approver is a delegate of the client
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), approver.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// The client, the job's reviewer or a delegate of the client allowed to
+    /// review
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+// Shared by approve_submission, claim_unreviewed_work and auto_release_payment,
+// which all take `tax_year` first
+#[instruction(tax_year: u16)]
+pub struct ApproveSubmission<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA (pure lamport vault)
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"release_limit", job_post.client.as_ref()],
+        bump
+    )]
+    /// CHECK: Client's release cap PDA; may be uninitialized, read in the handler
+    pub release_limit: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    /// CHECK: Freelancer's guardian PDA; may be uninitialized, read in the handler
+    pub payout_guardian: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: The job's client, receiving any unused repricing buffer
+    pub client: UncheckedAccount<'info>,
+
+    /// Required when the approver is a delegate of the client
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), approver.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// The client, the job's reviewer or a delegate of the client allowed to
+    /// approve; pays for any accounts created
+    #[account(mut)]
+    pub approver: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer's wallet on a token job with a SOL leg; checked on payout
+    pub freelancer_wallet: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    /// CHECK: Freelancer's claimable vault PDA, may be uninitialized; takes a
+    /// SOL payout their wallet can't receive
+    #[account(
+        mut,
+        seeds = [b"claimable", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub claimable_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], application.applicant.as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = 8 + PairHistory::INIT_SPACE,
+        seeds = [
+            b"pair_history",
+            job_post.namespace.as_ref(),
+            job_post.client.as_ref(),
+            application.applicant.as_ref()
+        ],
+        bump
+    )]
+    pub pair_history: Account<'info, PairHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Pyth price update for the operator's SOL/USD feed, required while
+    /// one is configured; decoded and checked in `observe_price`
+    pub price_update: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Fee treasury's token account, required for token jobs while a fee
+    /// is set; checked on payout
+    pub fee_treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault (its token account for token jobs), required while
+    /// withholding is enabled; checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = 8 + YearlyEarnings::INIT_SPACE,
+        seeds = [
+            b"yearly_earnings",
+            job_post.namespace.as_ref(),
+            application.applicant.as_ref(),
+            &tax_year.to_le_bytes()
+        ],
+        bump
+    )]
+    pub yearly_earnings: Account<'info, YearlyEarnings>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_stats",
+            job_post.namespace.as_ref(),
+            &[ROLE_FREELANCER],
+            application.applicant.as_ref(),
+            &freelancer_monthly_stats.year.to_le_bytes(),
+            &[freelancer_monthly_stats.month]
+        ],
+        bump = freelancer_monthly_stats.bump
+    )]
+    pub freelancer_monthly_stats: Option<Account<'info, MonthlyStats>>,
+
+    /// Client's stats in the client role, tracking spend and completion time
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_CLIENT], job_post.client.as_ref()],
+        bump
+    )]
+    pub client_stats: Option<Account<'info, UserStats>>,
+
+    /// Freelancer's active jobs go down, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    /// The revision being approved, marked so when passed
+    #[account(
+        mut,
+        seeds = [
+            b"submission",
+            application.key().as_ref(),
+            &[application.submissions.saturating_sub(1)]
+        ],
+        bump = submission.bump
+    )]
+    pub submission: Option<Account<'info, Submission>>,
+
+    /// CHECK: Applicant's donation preference PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"donation", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub donation_preference: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Charity wallet (its token account for token jobs) from the donation
+    /// preference; checked on payout
+    pub charity: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub charity_totals: Option<Account<'info, CharityTotals>>,
+
+    #[account(mut)]
+    /// CHECK: Referrer's wallet (their token account for token jobs), required
+    /// when the applicant was referred and referrals are paid; checked on payout
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"referral_stats", job_post.namespace.as_ref(), referral_stats.referrer.as_ref()],
+        bump = referral_stats.bump
+    )]
+    pub referral_stats: Option<Account<'info, ReferralStats>>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateProject<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Project::INIT_SPACE,
+        seeds = [
+            b"project",
+            platform_config.namespace.as_ref(),
+            client.key().as_ref(),
+            name.as_bytes()
+        ],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProject<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub project: Account<'info, Project>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttachJobToProject<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub project: Account<'info, Project>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMilestones<'info> {
+    #[account(has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + JobMilestones::INIT_SPACE,
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump
+    )]
+    pub job_milestones: Account<'info, JobMilestones>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobMetadata<'info> {
+    #[account(has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + JobExtension::INIT_SPACE,
+        seeds = [b"job_extension", job_post.key().as_ref()],
+        bump
+    )]
+    pub job_extension: Account<'info, JobExtension>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump = job_milestones.bump
+    )]
+    pub job_milestones: Account<'info, JobMilestones>,
+
+    #[account(
+        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    /// Required when the job depends on another job
+    pub dependency: Option<Account<'info, JobPost>>,
+
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimPeriodPayment<'info> {
+    #[account(
+        mut,
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), applicant.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Freelancer's guardian PDA; may be uninitialized, read in the handler
+    pub payout_guardian: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], applicant.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), applicant.key().as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault, required while withholding is enabled;
+    /// checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(
+        mut,
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), applicant.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Freelancer's guardian PDA; may be uninitialized, read in the handler
+    pub payout_guardian: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], applicant.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), applicant.key().as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault, required while withholding is enabled;
+    /// checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRetainer<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of the refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LogHours<'info> {
+    #[account(
+        init,
+        payer = applicant,
+        space = 8 + TimesheetEntry::INIT_SPACE,
+        seeds = [
+            b"timesheet",
+            application.key().as_ref(),
+            &job_post.hourly.map_or(0, |hourly| hourly.entries).to_le_bytes()
+        ],
+        bump
+    )]
+    pub timesheet_entry: Account<'info, TimesheetEntry>,
+
+    #[account(
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveTimesheet<'info> {
+    #[account(
+        mut,
+        has_one = application @ ErrorCode::InvalidAccount
+    )]
+    pub timesheet_entry: Account<'info, TimesheetEntry>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"release_limit", job_post.client.as_ref()],
+        bump
+    )]
+    /// CHECK: Client's release cap PDA; may be uninitialized, read in the handler
+    pub release_limit: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    /// CHECK: Freelancer's guardian PDA; may be uninitialized, read in the handler
+    pub payout_guardian: UncheckedAccount<'info>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault, required while withholding is enabled;
+    /// checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        constraint = applicant.key() == application.applicant @ ErrorCode::InvalidAccount
+    )]
+    /// CHECK: The hired freelancer, receiving the payout
+    pub applicant: UncheckedAccount<'info>,
+
+    pub client: Signer<'info>,
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectTimesheet<'info> {
+    #[account(
+        mut,
+        close = freelancer,
+        constraint = timesheet_entry.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub timesheet_entry: Account<'info, TimesheetEntry>,
+
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        constraint = freelancer.key() == timesheet_entry.freelancer @ ErrorCode::InvalidAccount
+    )]
+    /// CHECK: The entry's author, refunded its rent
+    pub freelancer: UncheckedAccount<'info>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseHourlyJob<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], application.applicant.as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of the refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTimesheetEntry<'info> {
+    #[account(
+        mut,
+        close = freelancer,
+        has_one = freelancer @ ErrorCode::Unauthorized,
+        has_one = job_post @ ErrorCode::InvalidAccount
+    )]
+    pub timesheet_entry: Account<'info, TimesheetEntry>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(block_id: u64)]
+pub struct ListHourBlock<'info> {
+    #[account(
+        init,
+        payer = freelancer,
+        space = 8 + HourBlock::INIT_SPACE,
+        seeds = [
+            b"hour_block",
+            platform_config.namespace.as_ref(),
+            freelancer.key().as_ref(),
+            &block_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub hour_block: Account<'info, HourBlock>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DelistHourBlock<'info> {
+    #[account(
+        mut,
+        close = freelancer,
+        has_one = freelancer @ ErrorCode::Unauthorized
+    )]
+    pub hour_block: Account<'info, HourBlock>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseHourBlock<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"hour_block",
+            hour_block.namespace.as_ref(),
+            hour_block.freelancer.as_ref(),
+            &hour_block.block_id.to_le_bytes()
+        ],
+        bump = hour_block.bump
+    )]
+    pub hour_block: Account<'info, HourBlock>,
+
+    #[account(
+        seeds = [b"platform_config", hour_block.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeHours<'info> {
+    #[account(
+        mut,
+        has_one = freelancer @ ErrorCode::Unauthorized,
+        constraint = hour_block.client == Some(client.key()) @ ErrorCode::Unauthorized
+    )]
+    pub hour_block: Account<'info, HourBlock>,
+
+    #[account(
+        seeds = [b"platform_config", hour_block.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", hour_block.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", hour_block.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault, required while withholding is enabled;
+    /// checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", hour_block.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", client.key().as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    pub client: Signer<'info>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefundHourBlock<'info> {
+    #[account(
+        mut,
+        close = freelancer,
+        has_one = freelancer @ ErrorCode::InvalidAccount,
+        constraint = hour_block.client == Some(client.key()) @ ErrorCode::Unauthorized
+    )]
+    pub hour_block: Account<'info, HourBlock>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: The block's freelancer, receiving its rent
+    pub freelancer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump = job_milestones.bump
+    )]
+    pub job_milestones: Account<'info, JobMilestones>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"release_limit", job_post.client.as_ref()],
+        bump
+    )]
+    /// CHECK: Client's release cap PDA; may be uninitialized, read in the handler
+    pub release_limit: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"guardian", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    /// CHECK: Freelancer's guardian PDA; may be uninitialized, read in the handler
+    pub payout_guardian: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Fee treasury's token account, required for token jobs while a fee
+    /// is set; checked on payout
+    pub fee_treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault (its token account for token jobs), required while
+    /// withholding is enabled; checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    /// CHECK: Freelancer's claimable vault PDA, may be uninitialized; takes a
+    /// SOL payout their wallet can't receive
+    #[account(
+        mut,
+        seeds = [b"claimable", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub claimable_vault: Option<UncheckedAccount<'info>>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Pays for a claimable vault opened for the freelancer
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelJob<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow account
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Client's token account receiving the refund of a token job
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// Client's stats in the client role, counting the cancellation
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_CLIENT], job_post.client.as_ref()],
+        bump
+    )]
+    pub client_stats: Option<Account<'info, UserStats>>,
+
+    #[account(mut)]
+    /// CHECK: The job's client, receiving the refund of a SOL job
+    pub client: UncheckedAccount<'info>,
+
+    /// Client's promotional credits, required when the job spent some or the
+    /// refund goes to credits
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    /// Required when the refund goes to credits, for the bonus
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Option<Account<'info, PlatformConfig>>,
+
+    /// Required when the authority is a delegate of the client
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), authority.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// The client or a delegate of theirs allowed to cancel
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimExpiredEscrow<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    /// The hired freelancer's application, required once the job is filled;
+    /// its stake is forfeited to the client
+    #[account(mut)]
+    pub application: Option<Account<'info, Application>>,
+
+    #[account(
+        seeds = [b"milestones", job_post.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Job's milestones PDA; may be uninitialized, read in the handler
+    pub job_milestones: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Client's token account receiving the refund of a token job
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireJob<'info> {
+    #[account(mut, has_one = client @ ErrorCode::InvalidAccount)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds, emptied by the crank
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Client's token account receiving the refund of a token job
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    /// CHECK: The job's client, receiving the refund
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRejectedEscrow<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Client's token account receiving the refund of a token job
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Freelancer's active jobs go down, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFreelancer<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Account<'info, JobCard>,
+
+    /// Freelancer's active jobs go down, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestMutualCancel<'info> {
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + CancellationRequest::INIT_SPACE,
+        seeds = [b"cancellation", application.key().as_ref()],
+        bump
+    )]
+    pub cancellation_request: Account<'info, CancellationRequest>,
+
+    /// The job's client or the hired freelancer
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptMutualCancel<'info> {
+    #[account(mut, has_one = client @ ErrorCode::InvalidAccount)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        seeds = [b"cancellation", application.key().as_ref()],
+        bump = cancellation_request.bump
+    )]
+    pub cancellation_request: Account<'info, CancellationRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Client's token account receiving the refund of a token job
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Freelancer's active jobs go down, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// CHECK: The job's client, receiving the refund
+    #[account(mut)]
+    pub client: UncheckedAccount<'info>,
+
+    /// The party that didn't request the cancellation
+    pub signer: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectSubmission<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        mut,
+        constraint = job_post.is_hired(&application) @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    /// The revision under review
+    #[account(
+        mut,
+        seeds = [
+            b"submission",
+            application.key().as_ref(),
+            &[application.submissions.saturating_sub(1)]
+        ],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    /// Required when the approver is a delegate of the client
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), approver.key().as_ref()],
+        bump = delegate.bump
+    )]
+    pub delegate: Option<Account<'info, Delegate>>,
+
+    /// The client, the job's reviewer or a delegate of the client allowed to
+    /// review
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptSettlement<'info> {
+    #[account(
+        mut,
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Fee treasury's token account, required for token jobs while a fee
+    /// is set; checked on payout
+    pub fee_treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault (its token account for token jobs), required while
+    /// withholding is enabled; checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Client wallet, or their token account for token jobs; checked on payout
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer's wallet on a token job with a SOL leg; checked on payout
+    pub freelancer_wallet: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Client's wallet on a token job with a SOL leg; checked on payout
+    pub client_wallet: Option<UncheckedAccount<'info>>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    /// Freelancer's active jobs go down, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), applicant.key().as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    pub applicant: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddDelegate<'info> {
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + Delegate::INIT_SPACE,
+        seeds = [b"delegate", client.key().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub delegate: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveDelegate<'info> {
+    #[account(
+        mut,
+        close = client,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub delegate: Account<'info, Delegate>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: ReminderKind)]
+pub struct RegisterReminder<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Reminder::INIT_SPACE,
+        seeds = [b"reminder", job_post.key().as_ref(), &[kind as u8]],
+        bump
+    )]
+    pub reminder: Account<'info, Reminder>,
+
+    #[account(has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FireReminder<'info> {
+    #[account(
+        mut,
+        close = client,
+        has_one = client @ ErrorCode::InvalidAccount,
+        has_one = job_post @ ErrorCode::InvalidAccount
+    )]
+    pub reminder: Account<'info, Reminder>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: the client who registered the reminder, receiving its rent back
+    #[account(mut)]
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelReminder<'info> {
+    #[account(
+        mut,
+        close = client,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub reminder: Account<'info, Reminder>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FundReviewSla<'info> {
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + ReviewSla::INIT_SPACE,
+        seeds = [b"review_sla", application.key().as_ref()],
+        bump
+    )]
+    pub review_sla: Account<'info, ReviewSla>,
+
+    #[account(has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReviewLateFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"review_sla", application.key().as_ref()],
+        bump = review_sla.bump,
+        has_one = application @ ErrorCode::InvalidAccount
+    )]
+    pub review_sla: Account<'info, ReviewSla>,
+
+    #[account(
+        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(address = review_sla.job_post @ ErrorCode::InvalidAccount)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseReviewSla<'info> {
+    #[account(
+        mut,
+        close = client,
+        has_one = client @ ErrorCode::Unauthorized,
+        has_one = job_post @ ErrorCode::InvalidAccount,
+        has_one = application @ ErrorCode::InvalidAccount
+    )]
+    pub review_sla: Account<'info, ReviewSla>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    pub application: Account<'info, Application>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut, address = application.applicant @ ErrorCode::InvalidAccount)]
+    /// CHECK: The hired freelancer, paid any late fee not yet claimed
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetReleaseCap<'info> {
+    #[account(
+        init_if_needed,
+        payer = client,
+        space = 8 + ReleaseLimit::INIT_SPACE,
+        seeds = [b"release_limit", client.key().as_ref()],
+        bump
+    )]
+    pub release_limit: Account<'info, ReleaseLimit>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RateClient<'info> {
+    #[account(
+        mut,
+        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_CLIENT], job_post.client.as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobReviewer<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetJobArbiter<'info> {
+    #[account(mut, has_one = client @ ErrorCode::Unauthorized)]
+    pub job_post: Account<'info, JobPost>,
+
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CoSignRelease<'info> {
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub co_signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        init,
+        payer = party,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", application.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(
+        seeds = [b"second_opinion", application.key().as_ref()],
+        bump
+    )]
+    /// CHECK: Application's second opinion PDA; may be uninitialized, read in the handler
+    pub second_opinion: UncheckedAccount<'info>,
+
+    /// Latest revision of the work, required once any was submitted
+    #[account(
+        seeds = [
+            b"submission",
+            application.key().as_ref(),
+            &[application.submissions.saturating_sub(1)]
+        ],
+        bump = submission.bump
+    )]
+    pub submission: Option<Account<'info, Submission>>,
+
+    /// Client or freelancer of the job
+    #[account(mut)]
+    pub party: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestSecondOpinion<'info> {
+    #[account(
+        init,
+        payer = party,
+        space = 8 + SecondOpinion::INIT_SPACE,
+        seeds = [b"second_opinion", application.key().as_ref()],
+        bump
+    )]
+    pub second_opinion: Account<'info, SecondOpinion>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Client or freelancer of the job
+    #[account(mut)]
+    pub party: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostSecondOpinion<'info> {
+    #[account(
+        mut,
+        seeds = [b"second_opinion", second_opinion.application.as_ref()],
+        bump = second_opinion.bump,
+        has_one = expert @ ErrorCode::Unauthorized
+    )]
+    pub second_opinion: Account<'info, SecondOpinion>,
+
+    #[account(mut)]
+    pub expert: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSecondOpinion<'info> {
+    #[account(
+        mut,
+        seeds = [b"second_opinion", second_opinion.application.as_ref()],
+        bump = second_opinion.bump,
+        has_one = requested_by @ ErrorCode::Unauthorized,
+        close = requested_by
+    )]
+    pub second_opinion: Account<'info, SecondOpinion>,
+
+    #[account(mut)]
+    pub requested_by: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RespondToDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute", application.key().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    /// Client or freelancer of the job
+    pub party: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute", application.key().as_ref()],
+        bump = dispute.bump,
+        has_one = arbiter @ ErrorCode::Unauthorized
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA holding the job's funds
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: The client's delegate PDA for the payee, which must not exist
+    #[account(
+        seeds = [b"delegate", job_post.client.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub payee_delegate: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_stats", job_post.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    /// The namespace's insurance pool, taking its cut of SOL fees; required once opened
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Option<Account<'info, InsurancePool>>,
+
+    /// CHECK: Namespace's settlement hooks PDA, may be uninitialized; read in the handler
+    #[account(
+        seeds = [b"settlement_hooks", job_post.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: UncheckedAccount<'info>,
+
+    /// CHECK: The registered pre-settlement hook program, required while one is set
+    pub pre_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The registered post-settlement hook program, required while one is set
+    pub post_hook_program: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Fee treasury's token account, required for token jobs while a fee
+    /// is set; checked on payout
+    pub fee_treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Operator's tax vault (its token account for token jobs), required while
+    /// withholding is enabled; checked on payout
+    pub tax_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer wallet, or their token account for token jobs; checked on payout
+    pub freelancer: UncheckedAccount<'info>,
+
+    /// CHECK: Freelancer's claimable vault PDA, may be uninitialized; takes a
+    /// SOL payout their wallet can't receive
+    #[account(
+        mut,
+        seeds = [b"claimable", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump
+    )]
+    pub claimable_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Client wallet, or their token account for token jobs; checked on payout
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Freelancer's wallet on a token job with a SOL leg; checked on payout
+    pub freelancer_wallet: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    /// CHECK: Client's wallet on a token job with a SOL leg; checked on payout
+    pub client_wallet: Option<UncheckedAccount<'info>>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Required when the job is attached to a project
+    #[account(mut)]
+    pub project: Option<Account<'info, Project>>,
+
+    /// Freelancer's active jobs go down, when they keep a profile
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), application.applicant.as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    /// Each party's stats in their role, tallying who the ruling favoured
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_FREELANCER], application.applicant.as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Option<Account<'info, UserStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"stats", job_post.namespace.as_ref(), &[ROLE_CLIENT], job_post.client.as_ref()],
+        bump
+    )]
+    pub client_stats: Option<Account<'info, UserStats>>,
+
+    /// Pays for a claimable vault opened for the freelancer
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatform<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [b"platform_config", admin.key().as_ref()],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OperatorStats::INIT_SPACE,
+        seeds = [b"operator_stats", admin.key().as_ref()],
+        bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeJob<'info> {
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ModerateJob<'info> {
+    #[account(mut, has_one = client @ ErrorCode::InvalidAccount)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    /// CHECK: Job client, receives the rent a takedown frees
+    pub client: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenCredits<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Credits::INIT_SPACE,
+        seeds = [b"credits", platform_config.namespace.as_ref(), client.key().as_ref()],
+        bump
+    )]
+    pub credits: Account<'info, Credits>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GrantCredits<'info> {
+    #[account(
+        init_if_needed,
+        payer = treasury,
+        space = 8 + Credits::INIT_SPACE,
+        seeds = [b"credits", platform_config.namespace.as_ref(), client.key().as_ref()],
+        bump
+    )]
+    pub credits: Account<'info, Credits>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Client receiving the credits
+    pub client: UncheckedAccount<'info>,
+
+    /// Fee treasury paying for the credits
+    #[account(mut, address = platform_config.fee_treasury @ ErrorCode::InvalidAccount)]
+    pub treasury: Signer<'info>,
+
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyJobRetention<'info> {
+    #[account(
+        mut,
+        has_one = client @ ErrorCode::InvalidAccount
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA, which must already be swept before the post is closed
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Job client, receives the freed rent
+    pub client: UncheckedAccount<'info>,
+
+    /// Client's jobs index page the job is dropped from when closed
+    #[account(
+        mut,
+        constraint = client_index.owner == job_post.client && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyApplicationRetention<'info> {
+    #[account(
+        mut,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount,
+        constraint = rent_receiver.key() == application.sponsored_by.unwrap_or(application.applicant) @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    /// CHECK: Whoever paid the application rent (sponsor or applicant)
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// CHECK: Applicant, receives back their application stake
+    #[account(
+        mut,
+        constraint = applicant.key() == application.applicant @ ErrorCode::InvalidAccount
+    )]
+    pub applicant: UncheckedAccount<'info>,
+
+    /// Freelancer's applications index page the application is dropped from when closed
+    #[account(
+        mut,
+        constraint = freelancer_index.owner == application.applicant && freelancer_index.kind == INDEX_APPLICATIONS @ ErrorCode::InvalidAccount
+    )]
+    pub freelancer_index: Option<Account<'info, UserIndexPage>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseApplication<'info> {
+    #[account(
+        mut,
+        close = rent_receiver,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount,
+        constraint = rent_receiver.key() == application.sponsored_by.unwrap_or(application.applicant) @ ErrorCode::Unauthorized
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    /// Whoever paid the application rent (sponsor or applicant)
+    #[account(mut)]
+    pub rent_receiver: Signer<'info>,
+
+    /// CHECK: Applicant, receives back their application stake
+    #[account(
+        mut,
+        constraint = applicant.key() == application.applicant @ ErrorCode::InvalidAccount
+    )]
+    pub applicant: UncheckedAccount<'info>,
+
+    /// Freelancer's applications index page the application is dropped from
+    #[account(
+        mut,
+        constraint = freelancer_index.owner == application.applicant && freelancer_index.kind == INDEX_APPLICATIONS @ ErrorCode::InvalidAccount
+    )]
+    pub freelancer_index: Option<Account<'info, UserIndexPage>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSubmission<'info> {
+    #[account(
+        mut,
+        close = freelancer,
+        has_one = freelancer @ ErrorCode::Unauthorized,
+        has_one = job_post @ ErrorCode::InvalidAccount
+    )]
+    pub submission: Account<'info, Submission>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawApplication<'info> {
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = applicant @ ErrorCode::Unauthorized,
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"job_card", job_post.key().as_ref()],
+        bump = job_card.bump
+    )]
+    pub job_card: Option<Account<'info, JobCard>>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+
+    /// CHECK: Whoever paid the application rent (sponsor or applicant)
+    #[account(
+        mut,
+        constraint = rent_receiver.key() == application.sponsored_by.unwrap_or(application.applicant) @ ErrorCode::InvalidAccount
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Active jobs go down when a hired freelancer withdraws
+    #[account(
+        mut,
+        seeds = [b"freelancer_profile", job_post.namespace.as_ref(), applicant.key().as_ref()],
+        bump = freelancer_profile.bump
+    )]
+    pub freelancer_profile: Option<Account<'info, FreelancerProfile>>,
+
+    /// Freelancer's applications index page the application is dropped from
+    #[account(
+        mut,
+        constraint = freelancer_index.owner == application.applicant && freelancer_index.kind == INDEX_APPLICATIONS @ ErrorCode::InvalidAccount
+    )]
+    pub freelancer_index: Option<Account<'info, UserIndexPage>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseJobPost<'info> {
+    #[account(
+        mut,
+        close = client,
+        has_one = client @ ErrorCode::Unauthorized
+    )]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA, swept and closed
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Escrow PDA's token account, for jobs paid in an SPL token
+    #[account(mut)]
+    pub escrow_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Client's token account receiving any tokens left in the vault
+    #[account(mut)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+    /// Client's jobs index page the job is dropped from
+    #[account(
+        mut,
+        constraint = client_index.owner == job_post.client && client_index.kind == INDEX_JOBS @ ErrorCode::InvalidAccount
+    )]
+    pub client_index: Option<Account<'info, UserIndexPage>>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepEscrow<'info> {
+    #[account(mut, has_one = client @ ErrorCode::InvalidAccount)]
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", job_post.key().as_ref()],
+        bump = job_post.escrow_bump
+    )]
+    /// CHECK: Escrow PDA, emptied to the client
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: The job's client, receiving the sweep
+    pub client: UncheckedAccount<'info>,
+
+    /// Client's promotional credits, getting back the credit-funded share
+    /// of any refund
+    #[account(
+        mut,
+        seeds = [b"credits", job_post.namespace.as_ref(), job_post.client.as_ref()],
+        bump = credits.bump
+    )]
+    pub credits: Option<Account<'info, Credits>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [b"operator_stats", operator_stats.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureInsurancePool<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + InsurancePool::INIT_SPACE,
+        seeds = [b"insurance_pool", platform_config.namespace.as_ref()],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(
+        mut,
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSettlementHooks<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + SettlementHooks::INIT_SPACE,
+        seeds = [b"settlement_hooks", platform_config.namespace.as_ref()],
+        bump
+    )]
+    pub settlement_hooks: Account<'info, SettlementHooks>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayInsuranceClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", job_post.namespace.as_ref()],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", application.key().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
+    )]
+    pub application: Account<'info, Application>,
+
+    pub job_post: Account<'info, JobPost>,
+
+    #[account(
+        seeds = [b"platform_config", job_post.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        address = application.applicant @ ErrorCode::InvalidAccount
+    )]
+    /// CHECK: The freelancer's wallet
+    pub freelancer: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawOperatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"operator_stats", platform_config.namespace.as_ref()],
+        bump = operator_stats.bump
+    )]
+    pub operator_stats: Account<'info, OperatorStats>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Any wallet chosen by the operator
+    pub destination: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCharity<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CharityTotals::INIT_SPACE,
+        seeds = [b"charity_totals", platform_config.namespace.as_ref(), charity.key().as_ref()],
+        bump
+    )]
+    pub charity_totals: Account<'info, CharityTotals>,
+
+    /// CHECK: Charity wallet being allowlisted
+    pub charity: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetDonationPreference<'info> {
+    #[account(
+        init_if_needed,
+        payer = freelancer,
+        space = 8 + DonationPreference::INIT_SPACE,
+        seeds = [b"donation", platform_config.namespace.as_ref(), freelancer.key().as_ref()],
+        bump
+    )]
+    pub donation_preference: Account<'info, DonationPreference>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateUserStats<'info> {
+    #[account(mut)]
+    /// CHECK: Legacy stats PDA in a pre-reputation layout, at the first
+    /// release's address or the namespaced one; both checked in the handler
+    pub legacy_stats: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_CLIENT], user.key().as_ref()],
+        bump
+    )]
+    pub client_stats: Account<'info, UserStats>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserStats::INIT_SPACE,
+        seeds = [b"stats", platform_config.namespace.as_ref(), &[ROLE_FREELANCER], user.key().as_ref()],
+        bump
+    )]
+    pub freelancer_stats: Account<'info, UserStats>,
+
+    #[account(
+        seeds = [b"platform_config", platform_config.namespace.as_ref()],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: Pubkey, role: u8, year: u16, month: u8)]
+pub struct OpenMonthlyStats<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MonthlyStats::INIT_SPACE,
+        seeds = [
+            b"monthly_stats",
+            namespace.as_ref(),
+            &[role],
+            user.key().as_ref(),
+            &year.to_le_bytes(),
+            &[month]
+        ],
+        bump
+    )]
+    pub monthly_stats: Account<'info, MonthlyStats>,
+
+    /// CHECK: User whose activity the account records
+    pub user: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: Pubkey)]
+pub struct OpenReferralStats<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ReferralStats::INIT_SPACE,
+        seeds = [b"referral_stats", namespace.as_ref(), referrer.key().as_ref()],
+        bump
+    )]
+    pub referral_stats: Account<'info, ReferralStats>,
+
+    /// CHECK: Referrer whose rewards the account records
+    pub referrer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(namespace: Pubkey, role: u8)]
+pub struct GetUserStats<'info> {
+    #[account(
+        seeds = [b"stats", namespace.as_ref(), &[role], user.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// CHECK: The user whose stats we're querying
+    pub user: UncheckedAccount<'info>,
+}
+
+// ----------------- EVENTS -----------------
+
+#[event]
+pub struct JobPosted {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub escrow: Pubkey,
+    pub amount: BaseUnits,
+    pub decimals: u8,
+    pub symbol: String,
+    // Days the client has to review a submission; zero when claims are off
+    pub review_window_days: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewWindowSet {
+    pub job_post: Pubkey,
+    // The job's own window; zero defers to the namespace's
+    pub review_window_days: u16,
+    // Exact period overriding the window in days; zero when unset
+    pub review_period_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApplicationSubmitted {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub applicant: Pubkey,
+    pub expected_end_date: i64,
+    // Freelancer's counter-offer to the job amount, if any
+    pub proposed_amount: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoursLogged {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub entry: Pubkey,
+    pub hours: u32,
+    // Hours logged on the job so far, this entry included
+    pub hours_logged: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TimesheetApproved {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub entry: Pubkey,
+    pub hours: u32,
+    pub amount: BaseUnits,
+    pub fee: BaseUnits,
+    pub withheld: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HourlyJobClosed {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub hours_approved: u32,
+    pub released: BaseUnits,
+    pub refund: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HourBlockListed {
+    pub hour_block: Pubkey,
+    pub freelancer: Pubkey,
+    pub hours: u32,
+    pub hourly_rate: Lamports,
+    pub validity: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HourBlockPurchased {
+    pub hour_block: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: Lamports,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoursConsumed {
+    pub hour_block: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    // 0-based number of the entry
+    pub entry: u16,
+    pub hours: u32,
+    // Gross price of the hours, before the fee and withholding
+    pub amount: Lamports,
+    pub fee: Lamports,
+    pub withheld: Lamports,
+    pub note_hash: [u8; 32],
+    pub hours_left: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HourBlockRefunded {
+    pub hour_block: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub hours_unused: u32,
+    pub refund: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PeriodPaid {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    // 1-based number of the period paid
+    pub period: u16,
+    pub amount: BaseUnits,
+    pub fee: BaseUnits,
+    pub withheld: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RetainerCancelled {
+    pub job_post: Pubkey,
+    pub periods_kept: u16,
+    pub refund: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestedWithdrawn {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub amount: BaseUnits,
+    pub released_so_far: BaseUnits,
+    pub fee: BaseUnits,
+    pub withheld: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreamCancelled {
+    pub job_post: Pubkey,
+    pub vested: BaseUnits,
+    pub refund: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TeamSharePaid {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: BaseUnits,
+    // Left in the recipient's claimable vault rather than paid out
+    pub parked: bool,
+    pub timestamp: i64,
+}
+
+// A SOL payout its destination couldn't receive, left in the claimant's
+// claimable vault
+#[event]
+pub struct PayoutParked {
+    pub claimant: Pubkey,
+    pub destination: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsClaimed {
+    pub recipient: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FreelancerInvited {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub timestamp: i64,
+}
+
+// A token transfer of `gross` for the job of which the mint's transfer fee
+// withheld `fee` at the destination
+#[event]
+pub struct TransferFeeWithheld {
+    pub job_post: Pubkey,
+    pub recipient: Pubkey,
+    pub gross: BaseUnits,
+    pub fee: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowRentReconciled {
+    pub job_post: Pubkey,
+    pub previous: Lamports,
+    pub current: Lamports,
+    pub timestamp: i64,
+}
+
+// An escrow holding other than `expected` lamports: less than its rent for
+// a legacy escrow, or other than rent plus outstanding funds for a typed one
+#[event]
+pub struct EscrowDiscrepancy {
+    pub job_post: Pubkey,
+    pub escrow: Pubkey,
+    pub expected: Lamports,
+    pub actual: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JobExpired {
+    pub job_post: Pubkey,
+    pub cranker: Pubkey,
+    // Paid to the cranker from the escrow's rent reserve
+    pub reward: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowSwept {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+// Escrow balance (lamports, or token units for token jobs) and ledger as of
+// `slot`, with the sha256 of the job post's serialized state at that slot
+#[event]
+pub struct EscrowSnapshot {
+    pub job_post: Pubkey,
+    pub balance: BaseUnits,
+    pub ledger: Option<EscrowLedger>,
+    pub state_hash: [u8; 32],
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApplicationStakeForfeited {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub applicant: Pubkey,
+    pub client: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AvailabilityChanged {
+    pub freelancer: Pubkey,
+    pub active_jobs: u16,
+    pub max_active_jobs: u16,
+    pub available: bool,
+    pub timestamp: i64,
+}
+
+// Applicant's declared UTC offset, if any, is outside the job's range
+#[event]
+pub struct TimezoneMismatch {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub applicant: Pubkey,
+    pub required_offsets: UtcOffsetRange,
+    pub applicant_offset: Option<i16>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApplicationsClosed {
+    pub job_post: Pubkey,
+    pub applications_count: u32,
+    pub timestamp: i64,
+}
+
+// Applicant doesn't list every language the job requires
+#[event]
+pub struct LanguageMismatch {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub applicant: Pubkey,
+    pub missing_languages: Vec<[u8; 2]>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsGranted {
+    pub namespace: Pubkey,
+    pub client: Pubkey,
+    pub amount: Lamports,
+    pub balance: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsApplied {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsReturned {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyClaimSubmitted {
+    pub job_post: Pubkey,
+    pub claim: Pubkey,
+    pub claimant: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyVoteCast {
+    pub job_post: Pubkey,
+    pub claim: Pubkey,
+    pub judge: Pubkey,
+    // Votes the claim has, this one included
+    pub votes: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyAwarded {
+    pub job_post: Pubkey,
+    pub claim: Pubkey,
+    pub winner: Pubkey,
+    pub payout: BaseUnits,
+    pub fee: BaseUnits,
+    pub timestamp: i64,
+}
+
+// A review edited by the client, or the freelancer's reply to it
+#[event]
+pub struct FeedbackUpdated {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub author: Pubkey,
+    pub text: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewRevealed {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub author: Pubkey,
+    pub rating: u8,
+    pub text: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JobAmountRevealed {
+    pub job_post: Pubkey,
+    pub amount: BaseUnits,
+    // Escrow above the price, returned to the client
+    pub refunded: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundedAsCredits {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub amount: Lamports,
+    pub bonus: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApplicationDeclined {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub applicant: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApplicationApproved {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WorkSubmitted {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer: Pubkey,
+    pub revision: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubmissionRejected {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    // The client or the job's reviewer
+    pub reviewer: Pubkey,
+    pub revision: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApplicationWithdrawn {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub applicant: Pubkey,
+    pub timestamp: i64,
+}
+
+// Cancelled, expired or reclaimed; `refund` went back to the client
+#[event]
+pub struct JobCancelled {
+    pub job_post: Pubkey,
+    pub client: Pubkey,
+    pub refund: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DeliverableAdded {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub index: u8,
+    pub hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+// `amount` is the deliverable's share of the escrow, before fee and withholding
+#[event]
+pub struct DeliverableAccepted {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub index: u8,
+    pub amount: BaseUnits,
+    pub fee: BaseUnits,
+    pub withheld: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DeliverableRejected {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub index: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReviewLateFeePaid {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer: Pubkey,
+    // Days paid by this claim
+    pub days: u64,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowToppedUp {
+    pub job_post: Pubkey,
+    pub amount: BaseUnits,
+    // Job amount after the top-up
+    pub total: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeMinted {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SolLegFunded {
+    pub job_post: Pubkey,
+    pub amount: Lamports,
+    // The job's SOL leg, this deposit included
+    pub total: Lamports,
+    pub timestamp: i64,
+}
+
+// A token job's SOL leg, or one party's share of it, paid out with its tokens:
+// released to the freelancer, or `refunded` to the client
+#[event]
+pub struct SolLegSettled {
+    pub job_post: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: Lamports,
+    pub refunded: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipPaid {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MutualCancelRequested {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub requested_by: Pubkey,
+    pub timestamp: i64,
+}
+
+// The client removed a hired freelancer who missed their delivery date
+#[event]
+pub struct FreelancerRemoved {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer: Pubkey,
+    pub timestamp: i64,
+}
+
+// A referrer was paid their cut of the platform fee on approved work
+#[event]
+pub struct ReferralRewarded {
+    pub job_post: Pubkey,
+    pub applicant: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: BaseUnits,
+    pub timestamp: i64,
+}
+
+// A registered reminder came due and was cranked; notification services pick
+// it up from here
+#[event]
+pub struct ReminderFired {
+    pub job_post: Pubkey,
+    pub kind: ReminderKind,
+    pub fire_at: i64,
+    pub cranker: Pubkey,
+    pub bounty: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub namespace: Pubkey,
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+// `reason` is an operator-defined code for the freeze or its lifting
+#[event]
+pub struct JobFreezeChanged {
+    pub job_post: Pubkey,
+    pub admin: Pubkey,
+    pub frozen: bool,
+    pub reason: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct JobModerated {
+    pub job_post: Pubkey,
+    pub admin: Pubkey,
+    pub action: ModerationAction,
+    pub reason: u16,
+    pub timestamp: i64,
+}
+
+// A release at or above the operator's threshold, for monitoring bots
+#[event]
+pub struct LargePayout {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: Lamports,
+    pub threshold: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubmissionApproved {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: BaseUnits,
+    pub invoice_hash: Option<[u8; 32]>,
+    pub ip_terms: IpTerms,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneApproved {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub index: u8,
+    pub amount: BaseUnits,
+    pub fee: BaseUnits,
+    pub withheld: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub opened_by: Pubkey,
+    pub arbiter: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReleaseCoSigned {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub co_signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementOffered {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SettlementAccepted {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer_bps: u16,
+    pub freelancer_amount: BaseUnits,
+    pub client_amount: BaseUnits,
+    pub timestamp: i64,
+}
+
+// Snapshot of a namespace's OperatorStats totals, emitted by `heartbeat`
+#[event]
+pub struct Metrics {
+    pub namespace: Pubkey,
+    pub jobs_posted: u64,
+    pub jobs_completed: u64,
+    pub jobs_cancelled: u64,
+    pub volume_escrowed: u64,
+    pub volume_released: u64,
+    pub fee_revenue: Lamports,
+    pub fees_withdrawn: Lamports,
+    pub disputes_opened: u64,
+    pub disputes_resolved: u64,
+    pub registered_jobs: u64,
+    pub promoters: u64,
+    pub detractors: u64,
+    pub satisfaction_responses: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceClaimPaid {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: Lamports,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub job_post: Pubkey,
+    pub application: Pubkey,
+    pub arbiter: Pubkey,
+    pub freelancer_bps: u16,
+    pub freelancer_amount: BaseUnits,
+    pub client_amount: BaseUnits,
+    // Award the escrow couldn't cover, claimable from the insurance pool
+    pub shortfall: BaseUnits,
+    pub timestamp: i64,
+}
+
+// A project's spending reached one of its alert thresholds
+#[event]
+pub struct BudgetThresholdCrossed {
+    pub project: Pubkey,
+    pub job_post: Pubkey,
+    pub threshold_bps: u16,
+    pub budget: BaseUnits,
+    pub spent: BaseUnits,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineKind {
+    JobEnd,
+    ExpectedDelivery,
+    OfferExpiry,
+    Milestone,
+}
+
+// A deadline was set or moved; notification services schedule reminders from it
+#[event]
+pub struct DeadlineSet {
+    pub job_post: Pubkey,
+    pub application: Option<Pubkey>,
+    pub kind: DeadlineKind,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+// ----------------- HELPERS -----------------
+
+pub const MAX_ONRAMPS: usize = 8;
+pub const MAX_BATCH_JOBS: usize = 10;
+pub const MAX_BATCH_APPLICATIONS: usize = 30;
+pub const MAX_ESCROW_CHECKS: usize = 30;
+pub const MAX_SCOPE_HISTORY: usize = 8;
+pub const MAX_CHARITIES: usize = 8;
+pub const MAX_ATTESTATION_ISSUERS: usize = 8;
+pub const MAX_EXPERT_REVIEWERS: usize = 8;
+pub const MAX_MILESTONES: usize = 10;
+pub const MAX_PROJECT_NAME_LEN: usize = 32;
+pub const MAX_URI_LEN: usize = 200;
+// Room for resume and submission links
+pub const MAX_LINK_LEN: usize = 200;
+pub const MAX_BUDGET_ALERTS: usize = 4;
+pub const MAX_DISPUTE_TEXT_LEN: usize = 300;
+pub const MAX_REVIEW_LEN: usize = 300;
+// Time after approval in which the client's review and the freelancer's reply
+// to it may still change
+pub const FEEDBACK_WINDOW: i64 = 7 * SECONDS_PER_DAY;
+// Fits the `checked_items` bitmask
+pub const MAX_CHECKLIST_ITEMS: usize = 8;
+pub const DEFAULT_BUDGET_ALERTS_BPS: [u16; 3] = [5_000, 8_000, 10_000];
+pub const MAX_MILESTONE_DESCRIPTION_LEN: usize = 100;
+// Categories fit the u64 bitmask of feed preferences
+pub const MAX_CATEGORIES: u8 = 64;
+pub const CATEGORY_RECENT_JOBS: usize = 10;
+// JobCard's category byte, after the discriminator, three keys, status and amount
+pub const JOB_CARD_CATEGORY_OFFSET: usize = 8 + 32 * 3 + 1 + 8;
+pub const MAX_JOB_TAGS: usize = 5;
+pub const MAX_TEAM_MEMBERS: usize = 4;
+pub const MAX_RETAINER_PERIODS: u16 = 120;
+pub const MAX_HOURLY_HOURS: u32 = 10_000;
+pub const MAX_TIMESHEET_NOTE_LEN: usize = 200;
+pub const BADGE_SYMBOL: &str = "GIG";
+pub const MAX_TAG_LEN: usize = 16;
+pub const MAX_METADATA_ENTRIES: usize = 8;
+pub const MAX_METADATA_KEY_LEN: usize = 32;
+pub const MAX_METADATA_VALUE_LEN: usize = 64;
+pub const MAX_ATTACHMENTS: usize = 5;
+pub const MAX_ATTACHMENT_URI_LEN: usize = 128;
+pub const MAX_ATTACHMENT_LABEL_LEN: usize = 32;
+pub const MAX_DELIVERABLES: usize = 10;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const NATIVE_DECIMALS: u8 = 9;
+// 10^decimals must fit in a u64 for whole-unit conversions
+pub const MAX_TOKEN_DECIMALS: u8 = 18;
+pub const NATIVE_SYMBOL: &str = "SOL";
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const MAX_BPS: u16 = 10_000;
+pub const MAX_FEE_BPS: u16 = 1_000;
+pub const MAX_CREDIT_BONUS_BPS: u16 = 1_000;
+// Role byte in the `stats` PDA seeds, so each role of a user gets its own account
+pub const ROLE_CLIENT: u8 = 0;
+pub const ROLE_FREELANCER: u8 = 1;
+pub const TRIAL_DURATION: i64 = 2 * SECONDS_PER_DAY;
+// Time after a job's end date before its client can reclaim an unworked escrow
+pub const RECLAIM_GRACE_PERIOD: i64 = 7 * SECONDS_PER_DAY;
+// Time after a job's end date before anyone can expire it if it never hired,
+// leaving the client a week to reclaim it themselves first
+pub const EXPIRY_GRACE_PERIOD: i64 = 14 * SECONDS_PER_DAY;
+// Furthest ahead a job may end, keeping its end date and grace periods in range
+pub const MAX_JOB_DURATION: i64 = 10 * 365 * SECONDS_PER_DAY;
+// Paid to whoever cranks `expire_job`, out of the escrow's rent reserve
+pub const EXPIRY_CRANK_REWARD: u64 = 10_000;
+// Current layout versions, bumped whenever fields are appended; older accounts
+// are brought up to date with migrate_account
+pub const JOB_POST_VERSION: u8 = 2;
+pub const APPLICATION_VERSION: u8 = 2;
+pub const USER_STATS_VERSION: u8 = 1;
+// Shortest gap between two heartbeat metrics events of a namespace
+pub const HEARTBEAT_INTERVAL: i64 = 60 * 60;
+// Time an expert has to post a paid second opinion before it can be cancelled
+pub const SECOND_OPINION_TIMEOUT: i64 = 3 * SECONDS_PER_DAY;
+// Delay before a client's release cap can be raised or removed
+pub const RELEASE_CAP_TIMELOCK: i64 = SECONDS_PER_DAY;
+pub const MAX_SATISFACTION_SCORE: u8 = 10;
+pub const MAX_RATING: u8 = 5;
+pub const MAX_JOB_DESCRIPTION_LEN: usize = 500;
+pub const MAX_HIRES: u8 = 20;
+// Fits `BountyTerms::voted`
+pub const MAX_BOUNTY_JUDGES: usize = 8;
+pub const NONCE_RING_LEN: usize = 8;
+pub const MAX_SCREENING_QUESTIONS: usize = 3;
+pub const MAX_LANGUAGES: usize = 4;
+pub const MAX_PROFILE_LANGUAGES: usize = 8;
+pub const MAX_DISPLAY_NAME_LEN: usize = 50;
+pub const MAX_SKILLS: usize = 10;
+pub const MAX_SKILL_LEN: usize = 32;
+pub const MIN_UTC_OFFSET_MINUTES: i16 = -12 * 60;
+pub const MAX_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+pub const REGISTRY_PAGE_SIZE: usize = 64;
+pub const USER_INDEX_PAGE_SIZE: usize = 64;
+// Kind byte in the `user_index` PDA seeds
+pub const INDEX_JOBS: u8 = 0;
+pub const INDEX_APPLICATIONS: u8 = 1;
+pub const PYTH_RECEIVER_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+pub const PYTH_PRICE_UPDATE_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+pub const MAX_PRICE_AGE: i64 = 60;
+// Widest confidence interval, relative to the price, a USD budget converts at
+pub const MAX_PRICE_CONF_BPS: u16 = 100;
+// Repriced jobs may grow to at most 3x their original amount
+pub const MAX_REPRICING_BPS: u16 = 30_000;
+// Optional subsystems gated by `PlatformConfig::features`
+// Tokens gate everything bringing a mint in (posting, publishing, tipping,
+// token projects); live token jobs still pay out and refund when it's off
+pub const FEATURE_TOKENS: u64 = 1 << 0;
+pub const FEATURE_DISPUTES: u64 = 1 << 1;
+pub const FEATURE_MILESTONES: u64 = 1 << 2;
+pub const FEATURE_AUCTIONS: u64 = 1 << 3;
+pub const FEATURE_REPRICING: u64 = 1 << 4;
+pub const FEATURE_ALL: u64 = (1 << 5) - 1;
+
+// Rent-exempt minimum of an account with `space` data bytes under the default
+// rent parameters: (space + 128 bytes of overhead) * 3480 lamports/byte-year * 2 years
+pub const fn rent_exempt_lamports(space: usize) -> u64 {
+    (space as u64 + 128) * 6_960
+}
+
+// Rent locked by each account at its current size, for cost estimates in clients
+pub mod rent_costs {
+    use super::*;
+
+    pub const JOB_POST: u64 = rent_exempt_lamports(8 + JobPost::INIT_SPACE);
+    pub const JOB_CARD: u64 = rent_exempt_lamports(8 + JobCard::INIT_SPACE);
+    pub const CATEGORY_INDEX: u64 = rent_exempt_lamports(8 + CategoryIndex::INIT_SPACE);
+    pub const APPLICATION: u64 = rent_exempt_lamports(8 + Application::INIT_SPACE);
+    pub const SUBMISSION: u64 = rent_exempt_lamports(8 + Submission::INIT_SPACE);
+    pub const TIMESHEET_ENTRY: u64 = rent_exempt_lamports(8 + TimesheetEntry::INIT_SPACE);
+    pub const HOUR_BLOCK: u64 = rent_exempt_lamports(8 + HourBlock::INIT_SPACE);
+    pub const BOUNTY_CLAIM: u64 = rent_exempt_lamports(8 + BountyClaim::INIT_SPACE);
+    pub const USER_STATS: u64 = rent_exempt_lamports(8 + UserStats::INIT_SPACE);
+    pub const PAIR_HISTORY: u64 = rent_exempt_lamports(8 + PairHistory::INIT_SPACE);
+    pub const PLATFORM_CONFIG: u64 = rent_exempt_lamports(8 + PlatformConfig::INIT_SPACE);
+    pub const OPERATOR_STATS: u64 = rent_exempt_lamports(8 + OperatorStats::INIT_SPACE);
+    pub const INSURANCE_POOL: u64 = rent_exempt_lamports(8 + InsurancePool::INIT_SPACE);
+    pub const SETTLEMENT_HOOKS: u64 = rent_exempt_lamports(8 + SettlementHooks::INIT_SPACE);
+    pub const RECURRING_JOB: u64 = rent_exempt_lamports(8 + RecurringJob::INIT_SPACE);
+    pub const YEARLY_EARNINGS: u64 = rent_exempt_lamports(8 + YearlyEarnings::INIT_SPACE);
+    pub const DONATION_PREFERENCE: u64 = rent_exempt_lamports(8 + DonationPreference::INIT_SPACE);
+    pub const CHARITY_TOTALS: u64 = rent_exempt_lamports(8 + CharityTotals::INIT_SPACE);
+    pub const REGISTRY_PAGE: u64 = rent_exempt_lamports(8 + RegistryPage::INIT_SPACE);
+    pub const JOB_COUNTER: u64 = rent_exempt_lamports(8 + JobCounter::INIT_SPACE);
+    pub const USER_INDEX_PAGE: u64 = rent_exempt_lamports(8 + UserIndexPage::INIT_SPACE);
+    pub const CREDITS: u64 = rent_exempt_lamports(8 + Credits::INIT_SPACE);
+    pub const REPUTATION_ATTESTATION: u64 =
+        rent_exempt_lamports(8 + ReputationAttestation::INIT_SPACE);
+    pub const ATTESTATION_IMPORT: u64 = rent_exempt_lamports(8 + AttestationImport::INIT_SPACE);
+    pub const PAYOUT_GUARDIAN: u64 = rent_exempt_lamports(8 + PayoutGuardian::INIT_SPACE);
+    pub const PAYOUT_ACCOUNT: u64 = rent_exempt_lamports(8 + PayoutAccount::INIT_SPACE);
+    pub const CLAIMABLE_VAULT: u64 = rent_exempt_lamports(8 + ClaimableVault::INIT_SPACE);
+    pub const FEED_PREFS: u64 = rent_exempt_lamports(8 + FeedPrefs::INIT_SPACE);
+    pub const FREELANCER_PROFILE: u64 = rent_exempt_lamports(8 + FreelancerProfile::INIT_SPACE);
+    pub const JOB_MILESTONES: u64 = rent_exempt_lamports(8 + JobMilestones::INIT_SPACE);
+    pub const PROJECT: u64 = rent_exempt_lamports(8 + Project::INIT_SPACE);
+    pub const DISPUTE: u64 = rent_exempt_lamports(8 + Dispute::INIT_SPACE);
+    pub const SECOND_OPINION: u64 = rent_exempt_lamports(8 + SecondOpinion::INIT_SPACE);
+    pub const NDA_ACKNOWLEDGMENT: u64 = rent_exempt_lamports(8 + NdaAcknowledgment::INIT_SPACE);
+    pub const INVITATION: u64 = rent_exempt_lamports(8 + Invitation::INIT_SPACE);
+    pub const RELEASE_LIMIT: u64 = rent_exempt_lamports(8 + ReleaseLimit::INIT_SPACE);
+    pub const MONTHLY_STATS: u64 = rent_exempt_lamports(8 + MonthlyStats::INIT_SPACE);
+    pub const LEGACY_ESCROW: u64 = rent_exempt_lamports(0);
+    pub const ESCROW: u64 = rent_exempt_lamports(8 + Escrow::INIT_SPACE);
+    // SPL token account holding a token job's escrow
+    pub const ESCROW_VAULT: u64 = rent_exempt_lamports(165);
+    pub const CANCELLATION_REQUEST: u64 = rent_exempt_lamports(8 + CancellationRequest::INIT_SPACE);
+    pub const DELIVERABLES: u64 = rent_exempt_lamports(8 + Deliverables::INIT_SPACE);
+    pub const DELEGATE: u64 = rent_exempt_lamports(8 + Delegate::INIT_SPACE);
+    pub const REMINDER: u64 = rent_exempt_lamports(8 + Reminder::INIT_SPACE);
+    pub const REVIEW_SLA: u64 = rent_exempt_lamports(8 + ReviewSla::INIT_SPACE);
+    pub const JOB_EXTENSION: u64 = rent_exempt_lamports(8 + JobExtension::INIT_SPACE);
+    pub const PROFILE: u64 = rent_exempt_lamports(8 + Profile::INIT_SPACE);
+    pub const REFERRAL_STATS: u64 = rent_exempt_lamports(8 + ReferralStats::INIT_SPACE);
+}
+
+// Compute units to request for each instruction, for fee estimates in clients.
+// These are budgets, not measurements: each instruction gets the tier of the
+// heaviest thing it does, with headroom over what that work costs.
+pub mod compute_units {
+    // Reads and writes program accounts only
+    pub const STATE: u32 = 20_000;
+    // Creates or closes an account, or moves lamports
+    pub const TRANSFER: u32 = 40_000;
+    // Token CPIs, or pays out to several parties
+    pub const PAYOUT: u32 = 100_000;
+    // A release through the shared payout path: fee, withholding, donation,
+    // guardian or parking, team shares and the settlement hooks
+    pub const SETTLEMENT: u32 = 300_000;
+
+    pub const BY_INSTRUCTION: &[(&str, u32)] = &[
+        ("initialize_job_post", TRANSFER),
+        ("initialize_usd_job_post", TRANSFER),
+        ("initialize_job_posts_batch", PAYOUT),
+        ("create_recurring_job", TRANSFER),
+        ("spawn_next_occurrence", TRANSFER),
+        ("cancel_recurring_job", TRANSFER),
+        ("create_project", TRANSFER),
+        ("update_project", STATE),
+        ("set_budget_alerts", STATE),
+        ("attach_job_to_project", STATE),
+        ("initialize_token_job_post", TRANSFER),
+        ("publish_job", PAYOUT),
+        ("initialize_bounty", TRANSFER),
+        ("submit_bounty_claim", TRANSFER),
+        ("vote_bounty_claim", PAYOUT),
+        ("close_bounty_claim", TRANSFER),
+        ("initialize_job_post_via_onramp", TRANSFER),
+        ("apply_to_job", TRANSFER),
+        ("initialize_direct_offer", TRANSFER),
+        ("accept_offer", TRANSFER),
+        ("expire_offer", PAYOUT),
+        ("initialize_retainer_job", TRANSFER),
+        ("claim_period_payment", SETTLEMENT),
+        ("cancel_retainer", PAYOUT),
+        ("initialize_hourly_job", TRANSFER),
+        ("log_hours", TRANSFER),
+        ("approve_timesheet", SETTLEMENT),
+        ("reject_timesheet", TRANSFER),
+        ("close_hourly_job", PAYOUT),
+        ("close_timesheet_entry", TRANSFER),
+        ("list_hour_block", TRANSFER),
+        ("delist_hour_block", TRANSFER),
+        ("purchase_hour_block", TRANSFER),
+        ("consume_hours", SETTLEMENT),
+        ("refund_hour_block", TRANSFER),
+        ("set_streaming", STATE),
+        ("withdraw_vested", SETTLEMENT),
+        ("cancel_stream", PAYOUT),
+        ("initialize_trial_job", TRANSFER),
+        ("expire_trial", PAYOUT),
+        ("convert_trial_to_job", TRANSFER),
+        ("set_job_nda", STATE),
+        ("set_screening_questions", STATE),
+        ("set_job_languages", STATE),
+        ("set_application_limits", STATE),
+        ("close_applications", STATE),
+        ("set_utc_offset_range", STATE),
+        ("set_job_application_stake", STATE),
+        ("set_job_revision_limit", STATE),
+        ("set_job_review_window", STATE),
+        ("set_job_review_period", STATE),
+        ("set_job_visibility", STATE),
+        ("invite_freelancer", TRANSFER),
+        ("acknowledge_nda", TRANSFER),
+        ("set_job_category", STATE),
+        ("set_job_tags", STATE),
+        ("set_job_metadata", TRANSFER),
+        ("open_category_index", TRANSFER),
+        ("open_user_index_page", TRANSFER),
+        ("set_job_dependency", STATE),
+        ("sync_job_card", TRANSFER),
+        ("set_job_featured", STATE),
+        ("register_job", TRANSFER),
+        ("list_jobs", STATE),
+        ("set_repricing_clause", TRANSFER),
+        ("reprice_job", STATE),
+        ("update_job_post", STATE),
+        ("accept_counter_offer", PAYOUT),
+        ("set_max_hires", STATE),
+        ("increase_job_budget", TRANSFER),
+        ("top_up_escrow", TRANSFER),
+        ("mint_completion_badge", PAYOUT),
+        ("add_sol_leg", TRANSFER),
+        ("tip_freelancer", PAYOUT),
+        ("decrease_job_budget", PAYOUT),
+        ("hide_job_amount", STATE),
+        ("reveal_job_amount", PAYOUT),
+        ("get_applications_summary", STATE),
+        ("set_feed_prefs", TRANSFER),
+        ("set_freelancer_profile", TRANSFER),
+        ("is_available", STATE),
+        ("create_profile", TRANSFER),
+        ("update_profile", STATE),
+        ("feed_matches", STATE),
+        ("approve_application", STATE),
+        ("approve_applications_batch", STATE),
+        ("reject_applications_batch", STATE),
+        ("set_client_private_note", STATE),
+        ("start_auction", STATE),
+        ("submit_bid", STATE),
+        ("commit_bid", STATE),
+        ("reveal_bid", STATE),
+        ("close_auction", PAYOUT),
+        ("amend_scope", STATE),
+        ("accept_scope_amendment", STATE),
+        ("submit_work", TRANSFER),
+        ("add_deliverable", TRANSFER),
+        ("set_deliverable_shares", STATE),
+        ("accept_deliverable", SETTLEMENT),
+        ("reject_deliverable", STATE),
+        ("resubmit_deliverable", TRANSFER),
+        ("request_payment", TRANSFER),
+        ("set_milestones", TRANSFER),
+        ("submit_milestone", STATE),
+        ("approve_milestone", SETTLEMENT),
+        ("approve_submission", SETTLEMENT),
+        ("co_sign_release", STATE),
+        ("claim_unreviewed_work", SETTLEMENT),
+        ("auto_release_payment", SETTLEMENT),
+        ("fund_review_sla", TRANSFER),
+        ("claim_review_late_fee", TRANSFER),
+        ("close_review_sla", TRANSFER),
+        ("reclaim_expired_escrow", PAYOUT),
+        ("expire_job", PAYOUT),
+        ("reclaim_rejected_escrow", PAYOUT),
+        ("remove_freelancer", STATE),
+        ("request_mutual_cancel", TRANSFER),
+        ("accept_mutual_cancel", PAYOUT),
+        ("check_item", STATE),
+        ("reject_submission", STATE),
+        ("settle_partial", STATE),
+        ("accept_settlement", SETTLEMENT),
+        ("add_delegate", TRANSFER),
+        ("remove_delegate", TRANSFER),
+        ("register_reminder", TRANSFER),
+        ("fire_reminder", TRANSFER),
+        ("cancel_reminder", TRANSFER),
+        ("set_release_cap", TRANSFER),
+        ("request_second_opinion", TRANSFER),
+        ("post_second_opinion", TRANSFER),
+        ("cancel_second_opinion", TRANSFER),
+        ("set_job_reviewer", STATE),
+        ("set_job_arbiter", STATE),
+        ("set_job_co_signer", STATE),
+        ("open_dispute", TRANSFER),
+        ("dispute_rejection", TRANSFER),
+        ("dispute_auto_release", TRANSFER),
+        ("resolve_holdback_dispute", PAYOUT),
+        ("release_holdback", PAYOUT),
+        ("respond_to_dispute", STATE),
+        ("resolve_dispute", SETTLEMENT),
+        ("rate_client", STATE),
+        ("record_satisfaction", STATE),
+        ("reveal_satisfaction", STATE),
+        ("seal_review", STATE),
+        ("reveal_review", STATE),
+        ("edit_client_review", STATE),
+        ("respond_to_review", STATE),
+        ("preview_settlement", STATE),
+        ("set_payout_guardian", TRANSFER),
+        ("register_payout_account", TRANSFER),
+        ("set_payout_team", STATE),
+        ("add_attachment", STATE),
+        ("claim_funds", TRANSFER),
+        ("acknowledge_payout", PAYOUT),
+        ("cancel_job", PAYOUT),
+        ("cancel_job_for_credits", PAYOUT),
+        ("initialize_platform", TRANSFER),
+        ("set_onramp", STATE),
+        ("set_attestation_issuer", STATE),
+        ("set_expert_reviewer", STATE),
+        ("set_retention_policy", STATE),
+        ("set_withholding", STATE),
+        ("update_platform_config", STATE),
+        ("open_credits", TRANSFER),
+        ("grant_credits", TRANSFER),
+        ("set_review_window", STATE),
+        ("set_dispute_window", STATE),
+        ("set_sealed_review_days", STATE),
+        ("set_referral_rate", STATE),
+        ("set_release_holdback", STATE),
+        ("open_referral_stats", TRANSFER),
+        ("set_default_arbiter", STATE),
+        ("set_self_dealing", STATE),
+        ("set_pause", STATE),
+        ("freeze_job", STATE),
+        ("unfreeze_job", STATE),
+        ("moderate_job", STATE),
+        ("set_large_payout_threshold", STATE),
+        ("set_co_sign_policy", STATE),
+        ("set_job_bounds", STATE),
+        ("set_credit_refund_bonus", STATE),
+        ("set_application_stake", STATE),
+        ("set_language_matching", STATE),
+        ("set_timezone_matching", STATE),
+        ("set_high_value_gate", STATE),
+        ("set_price_feed", STATE),
+        ("set_features", STATE),
+        ("set_trial_amount", STATE),
+        ("set_charity", TRANSFER),
+        ("set_donation_preference", TRANSFER),
+        ("apply_job_retention", TRANSFER),
+        ("apply_application_retention", TRANSFER),
+        ("close_application", TRANSFER),
+        ("close_submission", TRANSFER),
+        ("withdraw_application", TRANSFER),
+        ("close_job_post", PAYOUT),
+        ("sweep_escrow", TRANSFER),
+        ("migrate_escrow_v1_to_v2", TRANSFER),
+        ("migrate_account", STATE),
+        ("emit_escrow_snapshot", PAYOUT),
+        ("check_escrows", TRANSFER),
+        ("reconcile_escrow_rent", TRANSFER),
+        ("export_reputation_attestation", TRANSFER),
+        ("verify_attestation", STATE),
+        ("import_attestation", TRANSFER),
+        ("heartbeat", STATE),
+        ("withdraw_operator_fees", TRANSFER),
+        ("configure_insurance_pool", TRANSFER),
+        ("set_settlement_hooks", TRANSFER),
+        ("pay_insurance_claim", TRANSFER),
+        ("migrate_user_stats", TRANSFER),
+        ("open_monthly_stats", TRANSFER),
+        ("get_user_stats", STATE),
+    ];
+
+    pub fn budget(instruction: &str) -> Option<u32> {
+        BY_INSTRUCTION
+            .iter()
+            .find(|(name, _)| *name == instruction)
+            .map(|(_, units)| *units)
+    }
+}
+// Keeps a `list_jobs` result within the 1 KiB return data limit
+pub const MAX_LIST_LIMIT: usize = 10;
+// Reputation credited for a job with a new counterparty
+pub const REPUTATION_UNIT: u64 = 1_000;
+
+fn require_not_paused(config: &PlatformConfig) -> Result<()> {
+    require!(!config.paused, ErrorCode::ProgramPaused);
+    Ok(())
+}
+
+fn require_feature(config: &PlatformConfig, feature: u64) -> Result<()> {
+    require!(config.features & feature != 0, ErrorCode::FeatureDisabled);
+    Ok(())
+}
+
+fn validate_job_terms(spec: &JobSpec) -> Result<()> {
+    require_ctx!(!spec.title.is_empty(), ErrorCode::InvalidInput, "title", 1, spec.title.len());
+    require_ctx!(
+        !spec.description.is_empty(),
+        ErrorCode::InvalidInput,
+        "description",
+        1,
+        spec.description.len()
+    );
+    require_ctx!(spec.amount > 0, ErrorCode::InvalidAmount, "amount", 1, spec.amount);
+    require_ctx!(
+        spec.start_date <= spec.end_date,
+        ErrorCode::InvalidDates,
+        "start_date",
+        spec.end_date,
+        spec.start_date
+    );
+
+    let clock = Clock::get()?;
+    require_ctx!(
+        spec.start_date >= clock.unix_timestamp,
+        ErrorCode::InvalidDates,
+        "start_date",
+        clock.unix_timestamp,
+        spec.start_date
+    );
+    let latest_end = clock.unix_timestamp + MAX_JOB_DURATION;
+    require_ctx!(
+        spec.end_date <= latest_end,
+        ErrorCode::InvalidDates,
+        "end_date",
+        latest_end,
+        spec.end_date
+    );
+    Ok(())
+}
+
+// Holds a new job to its namespace's bounds; amounts only bind SOL jobs
+fn validate_job_bounds(config: &PlatformConfig, spec: &JobSpec, native: bool) -> Result<()> {
+    if native {
+        require_ctx!(
+            spec.amount >= config.min_job_amount,
+            ErrorCode::JobAmountTooLow,
+            "amount",
+            config.min_job_amount,
+            spec.amount
+        );
+        require_ctx!(
+            config.max_job_amount == 0 || spec.amount <= config.max_job_amount,
+            ErrorCode::JobAmountTooHigh,
+            "amount",
+            config.max_job_amount,
+            spec.amount
+        );
+    }
+    let duration = spec.end_date - spec.start_date;
+    require_ctx!(
+        duration >= config.min_job_duration,
+        ErrorCode::JobTooShort,
+        "end_date",
+        config.min_job_duration,
+        duration
+    );
+    Ok(())
+}
+
+// A link users hand each other: https or IPFS, printable ASCII without
+// spaces, and within the account's room for it
+fn validate_link(field: &str, link: &str) -> Result<()> {
+    require_ctx!(link.len() <= MAX_LINK_LEN, ErrorCode::LinkTooLong, field, MAX_LINK_LEN, link.len());
+    require!(
+        link.starts_with("https://") || link.starts_with("ipfs://"),
+        ErrorCode::LinkSchemeNotAllowed
+    );
+    require!(link.bytes().all(|byte| byte.is_ascii_graphic()), ErrorCode::LinkCharsetInvalid);
+    Ok(())
+}
+
+// Lamports a recurring job budgets per occurrence: the escrowed amount, the
+// escrow's rent-exempt minimum and the job post rent refunded to the cranker
+fn occurrence_cost(amount: u64) -> Result<u64> {
+    let rent = Rent::get()?;
+    let amount = checked_add(amount, rent.minimum_balance(8 + Escrow::INIT_SPACE))?;
+    checked_add(amount, rent.minimum_balance(8 + JobPost::INIT_SPACE))
+}
+
+// Fresh application; `payer` is recorded as sponsor when it isn't the applicant
+fn new_application(
+    applicant: Pubkey,
+    job_post: Pubkey,
+    resume_link: String,
+    expected_end_date: i64,
+    payer: Pubkey,
+) -> Application {
+    Application {
+        applicant,
+        job_post,
+        resume_link,
+        client_review: String::new(),
+        approved: false,
+        submitted: false,
+        completed: false,
+        rejected: false,
+        rejected_at: 0,
+        expected_end_date,
+        sponsored_by: (payer != applicant).then_some(payer),
+        content_hash: None,
+        bid_amount: None,
+        bid_commitment: None,
+        invoice_hash: None,
+        client_satisfaction: None,
+        freelancer_satisfaction: None,
+        disputed: false,
+        submitted_at: 0,
+        submissions: 0,
+        reviewed_at: 0,
+        checklist: Vec::new(),
+        checked_items: 0,
+        ip_terms: IpTerms::WorkForHire,
+        freelancer_rating: None,
+        client_rating: None,
+        proposed_amount: None,
+        stake: 0,
+        screening_answers: Vec::new(),
+        team: Vec::new(),
+        settlement_offer: None,
+        referrer: None,
+        abandoned: false,
+        attachments: Vec::new(),
+        approved_at: 0,
+        profile: None,
+        rejections: 0,
+        holdback: 0,
+        holdback_until: 0,
+        co_signed_by: None,
+        declined: false,
+        client_sealed_review: None,
+        freelancer_sealed_review: None,
+        sealed_reviews_open_at: 0,
+        reviews_revealed: 0,
+        client_private_note_hash: None,
+        version: APPLICATION_VERSION,
+        feedback_closes_at: 0,
+        freelancer_response: String::new(),
+        satisfaction_revealed: 0,
+    }
+}
+
+// Fresh, unfilled job post for a validated spec
+fn new_job_post(
+    namespace: Pubkey,
+    client: Pubkey,
+    spec: JobSpec,
+    escrow_bump: u8,
+    funded_by: Option<Pubkey>,
+) -> JobPost {
+    JobPost {
+        namespace,
+        client,
+        title: spec.title,
+        description: spec.description,
+        amount: BaseUnits(spec.amount),
+        is_filled: false,
+        cancelled: false,
+        start_date: spec.start_date,
+        end_date: spec.end_date,
+        escrow_bump,
+        freelancer: None,
+        funded_by,
+        settled_at: 0,
+        content_hash: None,
+        decimals: NATIVE_DECIMALS,
+        symbol: NATIVE_SYMBOL.to_string(),
+        auction: None,
+        direct_offer: None,
+        scope_history: Vec::new(),
+        pending_scope: None,
+        payment_mint: None,
+        published: true,
+        trial: false,
+        converted_to: None,
+        category: 0,
+        tags: Vec::new(),
+        featured: false,
+        registry_index: None,
+        funding_price: None,
+        payout_price: None,
+        repricing: None,
+        held_payout: BaseUnits::ZERO,
+        governance: None,
+        depends_on: None,
+        milestones_released: BaseUnits::ZERO,
+        milestones_withheld: BaseUnits::ZERO,
+        milestones_fees: BaseUnits::ZERO,
+        project: None,
+        arbiter: None,
+        co_signer: None,
+        reviewer: None,
+        applications: 0,
+        nda_hash: None,
+        frozen: false,
+        freeze_reason: 0,
+        max_hires: 1,
+        hires_count: 0,
+        seats_settled: 0,
+        held_for: None,
+        screening_questions: Vec::new(),
+        required_languages: Vec::new(),
+        utc_offset_range: None,
+        applications_count: 0,
+        max_applicants: 0,
+        application_deadline: 0,
+        applications_closed: false,
+        application_stake: Lamports::ZERO,
+        review_window_days: 0,
+        max_revisions: 0,
+        review_period_seconds: 0,
+        holdbacks: BaseUnits::ZERO,
+        credits_applied: Lamports::ZERO,
+        escrow_rent: Lamports::ZERO,
+        visibility: JobVisibility::Public,
+        retainer: None,
+        bounty: None,
+        recent_nonces: NonceRing::default(),
+        sol_leg: Lamports::ZERO,
+        expired: false,
+        version: JOB_POST_VERSION,
+        hidden: false,
+        moderation_reason: 0,
+        hourly: None,
+        stream: None,
+        usd_cents: None,
+        amount_commitment: None,
+    }
+}
+
+// Registry filter bitmap of a job:
+//   bits 0..8   status, one bit per JobStatus
+//   bits 8..16  category
+//   bits 16..20 kind, one bit per JobKind
+//   bit 20      featured
+//   bit 21      paid in an SPL token rather than SOL
+pub const FLAG_FEATURED: u32 = 1 << 20;
+pub const FLAG_TOKEN: u32 = 1 << 21;
+
+pub fn job_flags(job_post: &JobPost) -> u32 {
+    let mut flags = 1 << (job_post.status() as u32);
+    flags |= u32::from(job_post.category) << 8;
+    flags |= 1 << (16 + job_post.kind() as u32);
+    if job_post.featured {
+        flags |= FLAG_FEATURED;
+    }
+    if !job_post.is_native() {
+        flags |= FLAG_TOKEN;
+    }
+    flags
+}
+
+// The split `settle_submission` pays out; an auto-release keeps the holdback
+pub fn settlement_breakdown(
+    job_post: &JobPost,
+    application: &Application,
+    config: &PlatformConfig,
+    donation_preference: Option<&DonationPreference>,
+    auto_released: bool,
+) -> SettlementBreakdown {
+    let gross = job_post.seat_payout().0;
+    let donation_bps = donation_preference
+        .filter(|preference| config.charities.contains(&preference.charity))
+        .map_or(0, |preference| preference.bps);
+    let split = split_payout(gross, config.fee_bps, config.withholding_bps, donation_bps);
+    let referral = application
+        .referrer
+        .map_or(0, |_| bps_of(split.fee, config.referral_bps));
+    let team_shares: u64 = application.team.iter().map(|share| share.of(split.net)).sum();
+    let freelancer_share = split.net - team_shares;
+    let holdback = if auto_released {
+        bps_of(freelancer_share, config.release_holdback_bps)
+    } else {
+        0
+    };
+
+    SettlementBreakdown {
+        gross,
+        fee: split.fee,
+        referral,
+        withheld: split.withheld,
+        donated: split.donated,
+        team_shares,
+        holdback,
+        net_payout: freelancer_share - holdback,
+        client_refund: job_post.repricing.map_or(0, |clause| clause.buffer),
+        sol_leg: job_post.sol_leg,
+    }
+}
+
+// Lamports worth the clause's base USD value at `current`, clamped to its bounds.
+// None if the prices can't be compared.
+pub fn repriced_amount(clause: &RepricingClause, current: &PriceSnapshot) -> Option<u64> {
+    let base = &clause.base_price;
+    if base.price <= 0 || current.price <= 0 {
+        return None;
+    }
+
+    // amount * base_value / current_value, with both values brought to one exponent
+    let mut numerator = u128::from(clause.base_amount).checked_mul(base.price as u128)?;
+    let mut denominator = current.price as u128;
+    let expo_diff = base.expo.checked_sub(current.expo)?;
+    let scale = 10u128.checked_pow(expo_diff.unsigned_abs())?;
+    if expo_diff >= 0 {
+        numerator = numerator.checked_mul(scale)?;
+    } else {
+        denominator = denominator.checked_mul(scale)?;
+    }
+    let amount = u64::try_from(numerator / denominator).unwrap_or(u64::MAX);
+
+    Some(amount.clamp(
+        bps_of(clause.base_amount, clause.min_bps),
+        bps_of(clause.base_amount, clause.max_bps),
+    ))
+}
+
+// Lamports worth `usd_cents` at `price`. None if the price isn't positive or
+// the result overflows.
+pub fn usd_cents_to_lamports(usd_cents: u64, price: &PriceSnapshot) -> Option<u64> {
+    if price.price <= 0 {
+        return None;
+    }
+
+    // cents * 10^9 lamports / (100 * price * 10^expo)
+    let mut numerator = u128::from(usd_cents);
+    let mut denominator = price.price as u128;
+    let expo_diff = 7i32.checked_sub(price.expo)?;
+    let scale = 10u128.checked_pow(expo_diff.unsigned_abs())?;
+    if expo_diff >= 0 {
+        numerator = numerator.checked_mul(scale)?;
+    } else {
+        denominator = denominator.checked_mul(scale)?;
+    }
+    u64::try_from(numerator / denominator).ok()
+}
+
+// Convert a USD budget to lamports at the configured SOL/USD price, refusing
+// prices whose confidence interval is too wide to convert against
+fn usd_budget_in_lamports(
+    config: &PlatformConfig,
+    price_update: Option<&UncheckedAccount>,
+    usd_cents: u64,
+) -> Result<(u64, PriceSnapshot)> {
+    require!(config.sol_usd_feed != [0; 32], ErrorCode::PriceFeedUnset);
+    let price_update = price_update.ok_or(ErrorCode::OraclePriceRequired)?;
+    let update = read_price_update(config, price_update)?;
+    require!(
+        u128::from(update.conf) * u128::from(MAX_BPS)
+            <= update.price as u128 * u128::from(MAX_PRICE_CONF_BPS),
+        ErrorCode::OraclePriceUncertain
+    );
+
+    let price = PriceSnapshot {
+        price: update.price,
+        expo: update.exponent,
+        publish_time: update.publish_time,
+    };
+    let amount = usd_cents_to_lamports(usd_cents, &price).ok_or(ErrorCode::ArithmeticOverflow)?;
+    Ok((amount, price))
+}
+
+// Read the configured SOL/USD price for a SOL job, if price recording is on
+fn observe_price(
+    config: &PlatformConfig,
+    job_post: &JobPost,
+    price_update: Option<&UncheckedAccount>,
+) -> Result<Option<PriceSnapshot>> {
+    if config.sol_usd_feed == [0; 32] || !job_post.is_native() {
+        return Ok(None);
+    }
+
+    let price_update = price_update.ok_or(ErrorCode::OraclePriceRequired)?;
+    let update = read_price_update(config, price_update)?;
+    Ok(Some(PriceSnapshot {
+        price: update.price,
+        expo: update.exponent,
+        publish_time: update.publish_time,
+    }))
+}
+
+// Decode a Pyth price update, checking it is a fresh, fully verified update
+// for the configured feed
+fn read_price_update(
+    config: &PlatformConfig,
+    price_update: &UncheckedAccount,
+) -> Result<PythPriceUpdate> {
+    require_keys_eq!(*price_update.owner, PYTH_RECEIVER_ID, ErrorCode::InvalidOraclePrice);
+    let data = price_update.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == PYTH_PRICE_UPDATE_DISCRIMINATOR,
+        ErrorCode::InvalidOraclePrice
+    );
+    let update = PythPriceUpdate::deserialize(&mut &data[8..])?;
+
+    require!(
+        update.verification_level == PythVerificationLevel::Full
+            && update.feed_id == config.sol_usd_feed
+            && update.price > 0,
+        ErrorCode::InvalidOraclePrice
+    );
+    require!(
+        Clock::get()?.unix_timestamp - update.publish_time <= MAX_PRICE_AGE,
+        ErrorCode::StaleOraclePrice
+    );
+    Ok(update)
+}
+
+// Refresh a job's card when the caller passed it in
+fn refresh_job_card(job_card: Option<&mut Account<JobCard>>, job_post: &Account<JobPost>) {
+    if let Some(job_card) = job_card {
+        job_card.refresh(job_post);
+    }
+}
+
+// Budgets move only on open SOL jobs whose price isn't set by an offer,
+// auction or repricing clause
+fn require_budget_adjustable(job_post: &JobPost) -> Result<()> {
+    require!(!job_post.frozen, ErrorCode::JobFrozen);
+    require!(
+        !job_post.is_filled && job_post.hires_count == 0,
+        ErrorCode::JobAlreadyFilled
+    );
+    require!(!job_post.cancelled, ErrorCode::JobCancelled);
+    require!(job_post.is_native(), ErrorCode::NativeOnly);
+    require!(
+        job_post.auction.is_none()
+            && job_post.direct_offer.is_none()
+            && job_post.repricing.is_none()
+            && job_post.retainer.is_none()
+            && job_post.hourly.is_none(),
+        ErrorCode::PriceLocked
+    );
+    Ok(())
+}
+
+// Checks every release of `amount` from a job's escrow to its freelancer
+// must pass, whichever instruction pays it: the co-signature on jobs above
+// the platform threshold, and the client's daily cap on SOL releases
+fn check_release(
+    config: &PlatformConfig,
+    job_post: &JobPost,
+    application: &Application,
+    release_limit: Option<&AccountInfo>,
+    amount: u64,
+) -> Result<()> {
+    if config.requires_co_sign(job_post) {
+        require!(application.co_signed_by.is_some(), ErrorCode::CoSignatureRequired);
+    }
+    if let Some(release_limit) = release_limit.filter(|_| job_post.is_native()) {
+        track_release(release_limit, amount)?;
+    }
+    Ok(())
+}
+
+// Counts a SOL release against the client's daily cap, if they set one
+fn track_release(release_limit: &AccountInfo, amount: u64) -> Result<()> {
+    if release_limit.owner != &crate::ID {
+        return Ok(());
+    }
+    let mut limit = ReleaseLimit::try_deserialize(&mut &release_limit.try_borrow_data()?[..])?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let cap = limit.effective_cap(now);
+    if limit.pending_cap.is_some() && now >= limit.pending_effective_at {
+        limit.daily_cap = cap;
+        limit.pending_cap = None;
+    }
+
+    let day = now.div_euclid(SECONDS_PER_DAY);
+    if limit.day != day {
+        limit.day = day;
+        limit.released_today = 0;
+    }
+    limit.released_today = checked_add(limit.released_today, amount)?;
+    require!(
+        cap == 0 || limit.released_today <= cap,
+        ErrorCode::ReleaseCapExceeded
+    );
+
+    limit.try_serialize(&mut &mut release_limit.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+// Pays the freelancer `fee` for the review running `days_late` days past its
+// window, out of the SLA's buffer
+fn pay_review_late_fee(
+    sla: &mut Account<ReviewSla>,
+    application: &Account<Application>,
+    freelancer: &AccountInfo,
+    days_late: u64,
+    fee: u64,
+    now: i64,
+) -> Result<()> {
+    let days = days_late.saturating_sub(sla.days_paid);
+    sla.to_account_info().sub_lamports(fee)?;
+    freelancer.add_lamports(fee)?;
+    sla.buffer = checked_sub(sla.buffer, fee)?;
+    sla.days_paid = days_late;
+    sla.total_paid = checked_add(sla.total_paid, fee)?;
+
+    emit!(ReviewLateFeePaid {
+        job_post: sla.job_post,
+        application: application.key(),
+        freelancer: application.applicant,
+        days,
+        amount: Lamports(fee),
+        timestamp: now,
+    });
+    msg!("⏱️ Review {} day(s) late; {} lamports paid to {}", days_late, fee, application.applicant);
+    Ok(())
+}
+
+// Hands an application's stake back to its applicant before the account is
+// closed or shrunk, so the rent receiver only gets rent
+fn return_application_stake<'info>(
+    application: &mut Account<'info, Application>,
+    applicant: &AccountInfo<'info>,
+) -> Result<()> {
+    let stake = application.stake;
+    if stake > 0 {
+        application.sub_lamports(stake)?;
+        applicant.add_lamports(stake)?;
+        application.stake = 0;
+    }
+    Ok(())
+}
+
+// Pays an abandoning freelancer's application stake to the client
+fn forfeit_application_stake<'info>(
+    application: &mut Account<'info, Application>,
+    client: &AccountInfo<'info>,
+) -> Result<()> {
+    let stake = application.stake;
+    if stake == 0 {
+        return Ok(());
+    }
+    application.sub_lamports(stake)?;
+    client.add_lamports(stake)?;
+    application.stake = 0;
+
+    emit!(ApplicationStakeForfeited {
+        job_post: application.job_post,
+        application: application.key(),
+        applicant: application.applicant,
+        client: client.key(),
+        amount: Lamports(stake),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// Flags SOL releases at or above the config's large payout threshold
+fn flag_large_payout(
+    config: &PlatformConfig,
+    job_post: &Account<JobPost>,
+    application: Pubkey,
+    freelancer: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let threshold = config.large_payout_threshold;
+    if threshold == 0 || !job_post.is_native() || amount < threshold {
+        return Ok(());
+    }
+
+    emit!(LargePayout {
+        job_post: job_post.key(),
+        application,
+        client: job_post.client,
+        freelancer,
+        amount: job_post.lamports(BaseUnits(amount))?,
+        threshold: Lamports(threshold),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    msg!("🚨 Large payout of {} lamports on '{}'", amount, job_post.title);
+    Ok(())
+}
+
+// Sets an ErrorContext for `err` as return data so clients can say which field
+// failed and why, then hands back the error to return
+pub fn error_context(err: ErrorCode, field: &str, expected: i128, actual: i128) -> Error {
+    let context = ErrorContext {
+        code: ERROR_CODE_OFFSET + err as u32,
+        field: field.to_string(),
+        expected,
+        actual,
+    };
+    if let Ok(data) = context.try_to_vec() {
+        set_return_data(&data);
+    }
+    msg!("🚫 {}: expected {}, got {}", field, expected, actual);
+    err.into()
+}
+
+// Jobs with an NDA only take applications from signers of that exact NDA
+fn require_nda_acknowledged(
+    job_post: &JobPost,
+    acknowledgment: Option<&NdaAcknowledgment>,
+) -> Result<()> {
+    let Some(nda_hash) = job_post.nda_hash else {
+        return Ok(());
+    };
+    require!(
+        acknowledgment.is_some_and(|acknowledgment| acknowledgment.nda_hash == nda_hash),
+        ErrorCode::NdaNotAcknowledged
+    );
+    Ok(())
+}
+
+// A job with `depends_on` set can only move forward once that job has settled
+fn require_dependency_settled(
+    job_post: &JobPost,
+    dependency: Option<&Account<JobPost>>,
+) -> Result<()> {
+    let Some(depends_on) = job_post.depends_on else {
+        return Ok(());
+    };
+    let dependency = dependency.ok_or(ErrorCode::DependencyNotSettled)?;
+    require_keys_eq!(dependency.key(), depends_on, ErrorCode::InvalidAccount);
+    require!(
+        dependency.status() == JobStatus::Completed,
+        ErrorCode::DependencyNotSettled
+    );
+    Ok(())
+}
+
+// Cancels a job, or its open seats once some are filled, refunding the
+// client in cash or, with `to_credits`, in credits; callers are the two
+// cancel instructions
+fn cancel_job_post(ctx: Context<CancelJob>, to_credits: bool) -> Result<()> {
+    let job_post = &mut ctx.accounts.job_post;
+    require!(!job_post.frozen, ErrorCode::JobFrozen);
+    require!(!to_credits || job_post.is_native(), ErrorCode::NativeOnly);
+
+    require_client_or_delegate(
+        job_post,
+        ctx.accounts.authority.key(),
+        ctx.accounts.delegate.as_deref(),
+        |delegate| delegate.can_cancel,
+    )?;
+    require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+    require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+
+    // A multi-seat job with hires only gives up its open seats
+    if job_post.hires_count > 0 {
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let refund_to = match &ctx.accounts.client_token_account {
+            Some(token_account) if !job_post.is_native() => token_account.to_account_info(),
+            _ => ctx.accounts.client.to_account_info(),
+        };
+        let open_seats = job_post.max_hires - job_post.hires_count;
+        let refund = job_post.seat_payout().0 * u64::from(open_seats);
+        if to_credits {
+            refund_as_credits(
+                &escrow,
+                job_post,
+                ctx.accounts.credits.as_mut(),
+                &mut ctx.accounts.operator_stats,
+                ctx.accounts.platform_config.as_deref(),
+                refund,
+            )?;
+        } else {
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &refund_to, refund)?;
+        }
+
+        job_post.amount = job_post.amount.checked_sub(BaseUnits(refund))?;
+        job_post.max_hires = job_post.hires_count;
+        job_post.is_filled = true;
+        refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+        msg!("🪑 {} open seats of '{}' cancelled, {} refunded", open_seats, job_post.title, refund);
+        return Ok(());
+    }
+
+    job_post.cancelled = true;
+    job_post.settled_at = Clock::get()?.unix_timestamp;
+
+    // Refund client from escrow; unpublished token drafts hold nothing
+    let mut refund = 0;
+    if job_post.published {
+        let escrow = EscrowSource::new(
+            job_post,
+            &ctx.accounts.escrow,
+            &ctx.accounts.system_program,
+            ctx.accounts.escrow_vault.as_ref(),
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        )?;
+        let refund_to = match &ctx.accounts.client_token_account {
+            Some(token_account) if !job_post.is_native() => token_account.to_account_info(),
+            _ => ctx.accounts.client.to_account_info(),
+        };
+        let buffer = job_post.repricing.map_or(0, |clause| clause.buffer);
+        refund = job_post.amount.0 + buffer;
+        if to_credits {
+            refund_as_credits(
+                &escrow,
+                job_post,
+                ctx.accounts.credits.as_mut(),
+                &mut ctx.accounts.operator_stats,
+                ctx.accounts.platform_config.as_deref(),
+                refund,
+            )?;
+        } else {
+            escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &refund_to, refund)?;
+        }
+
+        let sol_leg = job_post.sol_leg;
+        if sol_leg > Lamports::ZERO {
+            let client = ctx.accounts.client.to_account_info();
+            escrow.pay_lamports(&client, job_post.client, sol_leg, EscrowLeg::Refund)?;
+            job_post.sol_leg = Lamports::ZERO;
+            emit!(SolLegSettled {
+                job_post: job_post.key(),
+                recipient: job_post.client,
+                amount: sol_leg,
+                refunded: true,
+                timestamp: job_post.settled_at,
+            });
+        }
+    }
+    if let Some(clause) = job_post.repricing.as_mut() {
+        clause.buffer = 0;
+    }
+
+    ctx.accounts.operator_stats.jobs_cancelled =
+        checked_add(ctx.accounts.operator_stats.jobs_cancelled, 1)?;
+    if let Some(client_stats) = ctx.accounts.client_stats.as_mut() {
+        client_stats.record_cancellation()?;
+    }
+    refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+    emit!(JobCancelled {
+        job_post: job_post.key(),
+        client: job_post.client,
+        refund: BaseUnits(refund),
+        timestamp: job_post.settled_at,
+    });
+
+    msg!("❌ Job cancelled and funds refunded to client");
+    Ok(())
+}
+
+// Refunds `amount` of a SOL job's escrow into the client's credits, adding
+// the namespace's credit refund bonus out of the operator's fee balance
+fn refund_as_credits<'info>(
+    escrow: &EscrowSource<'info>,
+    job_post: &mut Account<'info, JobPost>,
+    credits: Option<&mut Account<'info, Credits>>,
+    operator_stats: &mut Account<'info, OperatorStats>,
+    config: Option<&PlatformConfig>,
+    amount: u64,
+) -> Result<()> {
+    let credits = credits.ok_or(ErrorCode::InvalidAccount)?;
+    let config = config.ok_or(ErrorCode::InvalidAccount)?;
+
+    escrow.pay_leg(&credits.to_account_info(), credits.key(), amount, EscrowLeg::Refund)?;
+    job_post.credits_applied = job_post.credits_applied.saturating_sub(Lamports(amount));
+
+    let bonus = bps_of(amount, config.credit_refund_bonus_bps).min(operator_stats.fee_balance()?.0);
+    if bonus > 0 {
+        operator_stats.fees_withdrawn = operator_stats.fees_withdrawn.checked_add(Lamports(bonus))?;
+        operator_stats.to_account_info().sub_lamports(bonus)?;
+        credits.to_account_info().add_lamports(bonus)?;
+    }
+    credits.balance = checked_add(credits.balance, amount + bonus)?;
+
+    emit!(RefundedAsCredits {
+        job_post: job_post.key(),
+        client: job_post.client,
+        amount: Lamports(amount),
+        bonus: Lamports(bonus),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// Link of the application's latest revision, which must be passed once any
+// was submitted
+fn latest_submission_link(
+    application: &Application,
+    submission: Option<&Submission>,
+) -> Result<String> {
+    match submission {
+        Some(submission) => Ok(submission.link.clone()),
+        None => {
+            require!(application.submissions == 0, ErrorCode::InvalidAccount);
+            Ok(String::new())
+        }
+    }
+}
+
+// Pays out an approved (or unreviewed past its window) submission and
+// records the completion; callers check who may trigger it
+fn settle_submission<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApproveSubmission<'info>>,
+    client_review: String,
+    tax_year: u16,
+    auto_released: bool,
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.platform_config)?;
+    let job_post = &mut ctx.accounts.job_post;
+    require!(!job_post.frozen, ErrorCode::JobFrozen);
+    require!(job_post.amount_commitment.is_none(), ErrorCode::AmountHidden);
+    require!(job_post.hourly.is_none(), ErrorCode::HourlyJob);
+    let application = &mut ctx.accounts.application;
+
+    // --- VALIDATIONS ---
+    require!(application.submitted, ErrorCode::WorkNotCompleted);
+    require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+    require!(!application.disputed, ErrorCode::ApplicationDisputed);
+    require!(
+        application.job_post == job_post.key(),
+        ErrorCode::InvalidAccount
+    );
+    require!(job_post.is_hired(application), ErrorCode::Unauthorized);
+    require_independent_payee(job_post.client, application.applicant, &ctx.accounts.payee_delegate)?;
+    check_release(
+        &ctx.accounts.platform_config,
+        job_post,
+        application,
+        Some(&ctx.accounts.release_limit),
+        job_post.seat_payout().0,
+    )?;
+
+    let escrow = EscrowSource::new(
+        job_post,
+        &ctx.accounts.escrow,
+        &ctx.accounts.system_program,
+        ctx.accounts.escrow_vault.as_ref(),
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+
+    // Ensure escrow holds what this seat is owed
+    require!(
+        escrow.balance()? >= job_post.seat_payout().0,
+        ErrorCode::InsufficientEscrowBalance
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(civil_date(now).0 == tax_year, ErrorCode::InvalidTaxYear);
+
+    // --- UPDATE APPLICATION STATUS ---
+    application.client_review = client_review;
+    application.completed = true;
+    application.feedback_closes_at = now + FEEDBACK_WINDOW;
+    if let Some(submission) = ctx.accounts.submission.as_mut() {
+        submission.status = SubmissionStatus::Approved;
+    }
+    application.reviewed_at = now;
+    job_post.payout_price = observe_price(
+        &ctx.accounts.platform_config,
+        job_post,
+        ctx.accounts.price_update.as_ref(),
+    )?;
+
+    // Loaded whenever it exists, so a donation can't be skipped by leaving it out
+    let donation_preference = DonationPreference::load(&ctx.accounts.donation_preference)?;
+    let breakdown = settlement_breakdown(
+        job_post,
+        application,
+        &ctx.accounts.platform_config,
+        donation_preference.as_ref(),
+        auto_released,
+    );
+
+    // --- OPERATOR HOOKS, WHICH MAY VETO BY FAILING ---
+    let hooks = SettlementHooks::load(&ctx.accounts.settlement_hooks)?;
+    let mut hook_payload = SettlementHookPayload {
+        job_post: job_post.key(),
+        application: application.key(),
+        client: job_post.client,
+        freelancer: application.applicant,
+        payment_mint: job_post.payment_mint,
+        gross: breakdown.gross,
+        paid: 0,
+    };
+    call_settlement_hook(
+        hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+        ctx.accounts.pre_hook_program.as_ref(),
+        "pre_settlement",
+        &hook_payload,
+        &[job_post.to_account_info(), application.to_account_info()],
+    )?;
+
+    // --- PROTOCOL FEE, LESS THE REFERRER'S CUT ---
+    let referral = breakdown.referral;
+    collect_fee(
+        &escrow,
+        &mut ctx.accounts.operator_stats,
+        ctx.accounts.insurance_pool.as_mut(),
+        ctx.accounts.fee_treasury.as_ref(),
+        &ctx.accounts.platform_config,
+        breakdown.fee - referral,
+    )?;
+    if referral > 0 {
+        pay_referral(
+            &escrow,
+            job_post,
+            application,
+            ctx.accounts.referrer.as_ref(),
+            ctx.accounts.referral_stats.as_mut(),
+            referral,
+        )?;
+    }
+
+    // --- WITHHOLD TAX, THEN TRANSFER THE REST FROM ESCROW TO FREELANCER ---
+    let withheld = breakdown.withheld;
+    if withheld > 0 {
+        let tax_vault = ctx
+            .accounts
+            .tax_vault
+            .as_ref()
+            .ok_or(ErrorCode::InvalidAccount)?;
+        escrow.withhold(
+            tax_vault,
+            ctx.accounts.platform_config.tax_vault,
+            withheld,
+        )?;
+    }
+
+    // --- OPTIONAL DONATION TO THE FREELANCER'S CHOSEN CHARITY ---
+    let donated = breakdown.donated;
+    if let Some(preference) = &donation_preference {
+        if donated > 0 {
+            let (Some(charity), Some(charity_totals)) = (
+                &ctx.accounts.charity,
+                ctx.accounts.charity_totals.as_mut(),
+            ) else {
+                return err!(ErrorCode::InvalidAccount);
+            };
+            require_keys_eq!(
+                charity_totals.charity,
+                preference.charity,
+                ErrorCode::InvalidAccount
+            );
+            require_keys_eq!(
+                charity_totals.namespace,
+                job_post.namespace,
+                ErrorCode::InvalidAccount
+            );
+
+            escrow.pay(charity, preference.charity, donated)?;
+            // Totals are denominated in lamports
+            if job_post.is_native() {
+                charity_totals.total_donated = checked_add(charity_totals.total_donated, donated)?;
+            }
+            charity_totals.donations = checked_add(charity_totals.donations, 1)?;
+        }
+    }
+
+    // Teammates are paid their shares first, from remaining accounts, out of
+    // what the cuts leave
+    let team_paid = pay_team_shares(
+        &escrow,
+        application,
+        job_post,
+        breakdown.net_payout + breakdown.holdback + breakdown.team_shares,
+        ctx.remaining_accounts,
+        &ctx.accounts.approver.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    require_eq!(team_paid, breakdown.team_shares, ErrorCode::InvalidAmount);
+    let net_payout = breakdown.net_payout;
+
+    // Released without the client's review: part of the pay stays in escrow
+    // for the client's post-release dispute window
+    let config = &ctx.accounts.platform_config;
+    if auto_released && config.release_holdback_bps > 0 {
+        let holdback = breakdown.holdback;
+        application.holdback = holdback;
+        application.holdback_until =
+            now + i64::from(config.release_dispute_days) * SECONDS_PER_DAY;
+        job_post.holdbacks = job_post.holdbacks.checked_add(BaseUnits(holdback))?;
+        escrow.hold_back(holdback)?;
+    }
+
+    // Payouts at or above the freelancer's guardian threshold stay in escrow
+    // until the guardian acknowledges them
+    let guardian = PayoutGuardian::load(&ctx.accounts.payout_guardian)?;
+    if guardian.is_some_and(|guardian| guardian.requires_ack(net_payout)) {
+        require!(
+            job_post.held_for.is_none_or(|held_for| held_for == application.applicant),
+            ErrorCode::PayoutHeld
+        );
+        job_post.held_payout = job_post.held_payout.checked_add(BaseUnits(net_payout))?;
+        job_post.held_for = Some(application.applicant);
+        msg!("🛡️ Payout held for guardian acknowledgement");
+    } else {
+        let parking = ctx.accounts.claimable_vault.as_ref().map(|vault| Parking {
+            vault,
+            namespace: job_post.namespace,
+            claimant: application.applicant,
+            rent_payer: &ctx.accounts.approver,
+        });
+        escrow.pay_or_park(&ctx.accounts.freelancer, application.applicant, net_payout, parking)?;
+    }
+
+    // Return the unused repricing buffer to the client
+    if let Some(buffer) = job_post.repricing.map(|clause| clause.buffer) {
+        let client = ctx.accounts.client.to_account_info();
+        escrow.refund_with_credits(job_post, ctx.accounts.credits.as_mut(), &client, buffer)?;
+        if let Some(clause) = job_post.repricing.as_mut() {
+            clause.buffer = 0;
+        }
+    }
+
+    // A token job's SOL leg goes to the freelancer's wallet with the tokens
+    let sol_leg = job_post.sol_leg;
+    if sol_leg > Lamports::ZERO {
+        let wallet = ctx
+            .accounts
+            .freelancer_wallet
+            .as_ref()
+            .ok_or(ErrorCode::InvalidAccount)?;
+        escrow.pay_lamports(wallet, application.applicant, sol_leg, EscrowLeg::Release)?;
+        job_post.sol_leg = Lamports::ZERO;
+        emit!(SolLegSettled {
+            job_post: job_post.key(),
+            recipient: application.applicant,
+            amount: sol_leg,
+            refunded: false,
+            timestamp: now,
+        });
+    }
+
+    // Earnings and revenue stats are denominated in lamports, so token
+    // payouts only count towards job totals
+    let paid = checked_add(breakdown.gross, job_post.milestones_released.0)?;
+    let native_amount = if job_post.is_native() { paid } else { 0 };
+
+    let yearly_earnings = &mut ctx.accounts.yearly_earnings;
+    yearly_earnings.namespace = job_post.namespace;
+    yearly_earnings.freelancer = application.applicant;
+    yearly_earnings.year = tax_year;
+    let (fees, withheld) = if job_post.is_native() {
+        (
+            checked_add(breakdown.fee, job_post.milestones_fees.0)?,
+            checked_add(withheld, job_post.milestones_withheld.0)?,
+        )
+    } else {
+        (0, 0)
+    };
+    yearly_earnings.record_payout(native_amount, fees, withheld)?;
+
+    // --- UPDATE FREELANCER STATS ---
+    let (year, month) = current_period()?;
+    let freelancer_stats = &mut ctx.accounts.freelancer_stats;
+    freelancer_stats.record_job_completed(year, month, native_amount)?;
+    let completion_secs = (application.approved_at > 0)
+        .then(|| u64::try_from(now - application.approved_at).unwrap_or(0));
+    if let Some(secs) = completion_secs {
+        freelancer_stats.record_completion_time(secs)?;
+    }
+    if let Some(client_stats) = ctx.accounts.client_stats.as_mut() {
+        client_stats.record_job_paid(native_amount)?;
+        if let Some(secs) = completion_secs {
+            client_stats.record_completion_time(secs)?;
+        }
+    }
+    if let Some(monthly_stats) = ctx.accounts.freelancer_monthly_stats.as_mut() {
+        monthly_stats.require_period(year, month)?;
+        monthly_stats.jobs_completed = checked_add(monthly_stats.jobs_completed, 1)?;
+        monthly_stats.revenue = checked_add(monthly_stats.revenue, native_amount)?;
+    }
+    track_engagement(ctx.accounts.freelancer_profile.as_mut(), application.applicant, false)?;
+
+    // Repeat jobs between the same pair count for less: the n-th one adds 1/n
+    let pair_history = &mut ctx.accounts.pair_history;
+    if pair_history.jobs_completed == 0 {
+        pair_history.namespace = job_post.namespace;
+        pair_history.client = job_post.client;
+        pair_history.freelancer = application.applicant;
+        pair_history.bump = ctx.bumps.pair_history;
+    }
+    pair_history.jobs_completed = checked_add(pair_history.jobs_completed, 1)?;
+    freelancer_stats.reputation_score = checked_add(
+        freelancer_stats.reputation_score,
+        REPUTATION_UNIT / pair_history.jobs_completed,
+    )?;
+
+    ctx.accounts.operator_stats.record_job_completed(native_amount)?;
+
+    job_post.settle_seat(now)?;
+    // The holdback counts once it's released
+    record_project_spend(
+        ctx.accounts.project.as_mut(),
+        job_post,
+        breakdown.gross - breakdown.holdback,
+        true,
+    )?;
+    flag_large_payout(
+        &ctx.accounts.platform_config,
+        job_post,
+        application.key(),
+        application.applicant,
+        breakdown.gross,
+    )?;
+
+    hook_payload.paid = paid;
+    job_post.exit(&crate::ID)?;
+    application.exit(&crate::ID)?;
+    call_settlement_hook(
+        hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+        ctx.accounts.post_hook_program.as_ref(),
+        "post_settlement",
+        &hook_payload,
+        &[job_post.to_account_info(), application.to_account_info()],
+    )?;
+
+    emit!(SubmissionApproved {
+        job_post: job_post.key(),
+        application: application.key(),
+        client: job_post.client,
+        freelancer: application.applicant,
+        amount: BaseUnits(paid),
+        invoice_hash: application.invoice_hash,
+        ip_terms: application.ip_terms,
+        timestamp: now,
+    });
+
+    refresh_job_card(ctx.accounts.job_card.as_mut(), job_post);
+
+    msg!(
+        "💸 Funds released to freelancer: {} {}. Stats updated.",
+        paid,
+        job_post.symbol
+    );
+
+    Ok(())
+}
+
+// Invokes the registered hook program, if any, with the selector for
+// `name` and the payload. `accounts` are passed read-only; callers write
+// them back first for the post hook, so it sees the settled state rather
+// than the instruction's starting one.
+fn call_settlement_hook<'info>(
+    registered: Option<Pubkey>,
+    hook_program: Option<&UncheckedAccount<'info>>,
+    name: &str,
+    payload: &SettlementHookPayload,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let Some(registered) = registered else {
+        return Ok(());
+    };
+    let hook_program = hook_program.ok_or(ErrorCode::InvalidAccount)?;
+    require_keys_eq!(hook_program.key(), registered, ErrorCode::InvalidAccount);
+    require!(hook_program.executable, ErrorCode::InvalidAccount);
+
+    let mut data = hashv(&[b"global:", name.as_bytes()]).to_bytes()[..8].to_vec();
+    payload.serialize(&mut data)?;
+    let instruction = Instruction {
+        program_id: registered,
+        accounts: accounts
+            .iter()
+            .map(|info| AccountMeta::new_readonly(info.key(), false))
+            .collect(),
+        data,
+    };
+    let mut infos = accounts.to_vec();
+    infos.push(hook_program.to_account_info());
+    invoke(&instruction, &infos)?;
+    Ok(())
+}
+
+// Pays each teammate's share of `net_payout` to their registered payout
+// wallet, or parks it in their claimable vault if they haven't registered one,
+// so an absent teammate doesn't block the others. `team_accounts` holds
+// [wallet, payout account PDA, claimable vault PDA] per teammate, in order.
+// Shares naming the job's client, or registered to pay out to them, are
+// refused. Returns the total paid out to the team.
+fn pay_team_shares<'info>(
+    escrow: &EscrowSource<'info>,
+    application: &Account<'info, Application>,
+    job_post: &JobPost,
+    net_payout: u64,
+    team_accounts: &[AccountInfo<'info>],
+    rent_payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    require!(
+        team_accounts.len() >= application.team.len() * 3,
+        ErrorCode::InvalidAccount
+    );
+
+    let (client, namespace) = (job_post.client, job_post.namespace);
+    let mut paid = 0;
+    for (share, accounts) in application.team.iter().zip(team_accounts.chunks(3)) {
+        let amount = share.of(net_payout);
+        if amount == 0 {
+            continue;
+        }
+        require_keys_neq!(share.recipient, client, ErrorCode::SelfDealing);
+        let (wallet, payout_account, vault) = (&accounts[0], &accounts[1], &accounts[2]);
+        let (payout_key, _) = pda::payout_account(&namespace, &share.recipient);
+        require_keys_eq!(payout_account.key(), payout_key, ErrorCode::InvalidAccount);
+
+        let parked = match PayoutAccount::load(payout_account)? {
+            Some(registered) if registered.destination == client => {
+                return err!(ErrorCode::SelfDealing);
+            }
+            Some(registered) => escrow.pay_or_park(
+                wallet,
+                registered.destination,
+                amount,
+                Some(Parking {
+                    vault,
+                    namespace,
+                    claimant: share.recipient,
+                    rent_payer,
+                }),
+            )?,
+            None => {
+                park_funds(escrow, vault, namespace, share.recipient, amount, rent_payer, system_program)?;
+                true
+            }
+        };
+        paid = checked_add(paid, amount)?;
+
+        emit!(TeamSharePaid {
+            job_post: application.job_post,
+            application: application.key(),
+            recipient: share.recipient,
+            amount: BaseUnits(amount),
+            parked,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    Ok(paid)
+}
+
+// Moves `amount` from a SOL escrow into `recipient`'s claimable vault, opening
+// the vault at `rent_payer`'s expense if it doesn't exist yet
+fn park_funds<'info>(
+    escrow: &EscrowSource<'info>,
+    vault: &AccountInfo<'info>,
+    namespace: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+    rent_payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(escrow.is_native(), ErrorCode::NativeOnly);
+    let (vault_key, bump) = pda::claimable_vault(&namespace, &recipient);
+    require_keys_eq!(vault.key(), vault_key, ErrorCode::InvalidAccount);
+
+    let mut claimable = if vault.owner == &crate::ID {
+        ClaimableVault::try_deserialize(&mut &vault.try_borrow_data()?[..])?
+    } else {
+        let space = 8 + ClaimableVault::INIT_SPACE;
+        invoke_signed(
+            &system_instruction::create_account(
+                rent_payer.key,
+                &vault_key,
+                Rent::get()?.minimum_balance(space),
+                space as u64,
+                &crate::ID,
+            ),
+            &[rent_payer.clone(), vault.clone(), system_program.clone()],
+            &[&[b"claimable", namespace.as_ref(), recipient.as_ref(), &[bump]]],
+        )?;
+        ClaimableVault {
+            namespace,
+            recipient,
+            rent_payer: rent_payer.key(),
+            lamports_owed: 0,
+            bump,
+        }
+    };
+
+    escrow.pay(vault, vault_key, amount)?;
+    claimable.lamports_owed = checked_add(claimable.lamports_owed, amount)?;
+    claimable.try_serialize(&mut &mut vault.try_borrow_mut_data()?[..])
+}
+
+// Deliverable shares release from the job amount the way milestones do, so
+// only on jobs whose amount is fixed and paid out in one seat
+fn require_partial_release(job_post: &JobPost) -> Result<()> {
+    require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+    require!(job_post.repricing.is_none(), ErrorCode::RepricingJob);
+    require!(job_post.retainer.is_none(), ErrorCode::RetainerJob);
+    require!(job_post.hourly.is_none(), ErrorCode::HourlyJob);
+    require!(job_post.stream.is_none(), ErrorCode::StreamingJob);
+    Ok(())
+}
+
+// An approved engagement both parties may still unwind: a single-seat job
+// whose work hasn't been approved, disputed or paid out over time
+fn require_mutually_cancellable(job_post: &JobPost, application: &Application) -> Result<()> {
+    require!(!job_post.frozen, ErrorCode::JobFrozen);
+    require!(!job_post.cancelled, ErrorCode::JobAlreadyCancelled);
+    require!(job_post.settled_at == 0, ErrorCode::JobAlreadySettled);
+    require!(job_post.max_hires <= 1, ErrorCode::MultiSeatJob);
+    require!(job_post.retainer.is_none(), ErrorCode::RetainerJob);
+    require!(job_post.hourly.is_none(), ErrorCode::HourlyJob);
+    require!(job_post.stream.is_none(), ErrorCode::StreamingJob);
+    require!(job_post.is_hired(application), ErrorCode::ApplicationNotApproved);
+    require!(!application.completed, ErrorCode::WorkAlreadyApproved);
+    require!(!application.disputed, ErrorCode::ApplicationDisputed);
+    Ok(())
+}
+
+// Submissions are reviewed by the client or the reviewer they named; a
+// reviewer can never review their own work
+fn require_reviewer(
+    job_post: &JobPost,
+    application: &Application,
+    approver: Pubkey,
+    delegate: Option<&Delegate>,
+    allowed: fn(&Delegate) -> bool,
+) -> Result<()> {
+    let is_reviewer =
+        Some(approver) == job_post.reviewer && approver != application.applicant;
+    if is_reviewer || approver == job_post.client {
+        return Ok(());
+    }
+    // A freelancer can't review their own work as a delegate either
+    require_keys_neq!(approver, application.applicant, ErrorCode::Unauthorized);
+    require_client_or_delegate(job_post, approver, delegate, allowed)
+}
+
+// `signer` acts for the job's client: it is the client, or a delegate of
+// theirs holding the permission `allowed` checks
+fn require_client_or_delegate(
+    job_post: &JobPost,
+    signer: Pubkey,
+    delegate: Option<&Delegate>,
+    allowed: fn(&Delegate) -> bool,
+) -> Result<()> {
+    if signer == job_post.client {
+        return Ok(());
+    }
+    let delegate = delegate.ok_or(ErrorCode::Unauthorized)?;
+    require!(
+        delegate.client == job_post.client && delegate.member == signer && allowed(delegate),
+        ErrorCode::Unauthorized
+    );
+    Ok(())
+}
+
+// Lamport fees accrue in the operator stats vault; token fees go straight to
+// the fee treasury's token account
+fn collect_fee<'info>(
+    escrow: &EscrowSource<'info>,
+    operator_stats: &mut Account<'info, OperatorStats>,
+    insurance_pool: Option<&mut Account<'info, InsurancePool>>,
+    fee_treasury: Option<&UncheckedAccount<'info>>,
+    config: &PlatformConfig,
+    fee: u64,
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    if escrow.is_native() {
+        // The insurance pool's cut comes out of SOL fees only
+        require!(
+            insurance_pool.is_some() || !config.insurance_enabled,
+            ErrorCode::InvalidAccount
+        );
+        let mut fee = fee;
+        if let Some(pool) = insurance_pool {
+            let contribution = bps_of(fee, pool.contribution_bps);
+            if contribution > 0 {
+                let pool_info = pool.to_account_info();
+                escrow.pay_leg(&pool_info, pool_info.key(), contribution, EscrowLeg::Fee)?;
+                pool.contributed = pool.contributed.checked_add(Lamports(contribution))?;
+                fee = checked_sub(fee, contribution)?;
+            }
+        }
+        let vault = operator_stats.to_account_info();
+        escrow.pay_leg(&vault, vault.key(), fee, EscrowLeg::Fee)?;
+        operator_stats.fee_revenue = operator_stats.fee_revenue.checked_add(Lamports(fee))?;
+    } else {
+        let fee_treasury = fee_treasury.ok_or(ErrorCode::InvalidAccount)?;
+        escrow.pay_leg(fee_treasury, config.fee_treasury, fee, EscrowLeg::Fee)?;
+    }
+    Ok(())
+}
+
+// Settles a token job's SOL leg along with its tokens: `freelancer_bps` of it
+// to the freelancer's wallet and the rest back to the client's. A wallet is
+// only needed for a share that isn't zero.
+fn settle_sol_leg<'info>(
+    escrow: &EscrowSource<'info>,
+    job_post: &mut Account<'info, JobPost>,
+    freelancer: Pubkey,
+    freelancer_wallet: Option<&AccountInfo<'info>>,
+    client_wallet: Option<&AccountInfo<'info>>,
+    freelancer_bps: u16,
+) -> Result<()> {
+    let sol_leg = job_post.sol_leg;
+    if sol_leg == Lamports::ZERO {
+        return Ok(());
+    }
+    let released = Lamports(bps_of(sol_leg.0, freelancer_bps));
+    let legs = [
+        (freelancer_wallet, freelancer, released, false),
+        (client_wallet, job_post.client, sol_leg.checked_sub(released)?, true),
+    ];
+    let timestamp = Clock::get()?.unix_timestamp;
+    for (wallet, recipient, amount, refunded) in legs {
+        if amount == Lamports::ZERO {
+            continue;
+        }
+        let wallet = wallet.ok_or(ErrorCode::InvalidAccount)?;
+        let leg = if refunded { EscrowLeg::Refund } else { EscrowLeg::Release };
+        escrow.pay_lamports(wallet, recipient, amount, leg)?;
+        emit!(SolLegSettled {
+            job_post: job_post.key(),
+            recipient,
+            amount,
+            refunded,
+            timestamp,
+        });
+    }
+    job_post.sol_leg = Lamports::ZERO;
+    Ok(())
+}
+
+// Accounts a release to the hired freelancer pays through
+pub struct ReleaseAccounts<'a, 'info> {
+    pub config: &'a PlatformConfig,
+    pub operator_stats: &'a mut Account<'info, OperatorStats>,
+    pub insurance_pool: Option<&'a mut Account<'info, InsurancePool>>,
+    pub fee_treasury: Option<&'a UncheckedAccount<'info>>,
+    pub tax_vault: Option<&'a UncheckedAccount<'info>>,
+    // The freelancer's guardian PDA; settlements and dispute rulings pay
+    // without one
+    pub payout_guardian: Option<&'a UncheckedAccount<'info>>,
+    pub freelancer: &'a AccountInfo<'info>,
+    pub parking: Option<Parking<'a, 'info>>,
+    pub settlement_hooks: &'a UncheckedAccount<'info>,
+    pub pre_hook_program: Option<&'a UncheckedAccount<'info>>,
+    pub post_hook_program: Option<&'a UncheckedAccount<'info>>,
+    pub project: Option<&'a mut Account<'info, Project>>,
+}
+
+// Pays `amount` of the escrow to the job's hired freelancer, less the fee and
+// withholding at the final payout's rates, between the namespace's settlement
+// hooks, and counts it towards the job's project. A net the guardian must
+// acknowledge is held on the job instead; `label` names the payout in that log.
+// A `partial` release is booked against the job's milestone totals before the
+// post hook runs.
+fn release_to_freelancer<'info>(
+    escrow: &EscrowSource<'info>,
+    job_post: &mut Account<'info, JobPost>,
+    application: &Account<'info, Application>,
+    accounts: ReleaseAccounts<'_, 'info>,
+    amount: u64,
+    label: &str,
+    partial: bool,
+) -> Result<PayoutSplit> {
+    let hooks = SettlementHooks::load(accounts.settlement_hooks)?;
+    let mut hook_payload = SettlementHookPayload {
+        job_post: job_post.key(),
+        application: application.key(),
+        client: job_post.client,
+        freelancer: application.applicant,
+        payment_mint: job_post.payment_mint,
+        gross: amount,
+        paid: 0,
+    };
+    let hook_accounts = [job_post.to_account_info(), application.to_account_info()];
+    call_settlement_hook(
+        hooks.as_ref().and_then(|hooks| hooks.pre_settlement),
+        accounts.pre_hook_program,
+        "pre_settlement",
+        &hook_payload,
+        &hook_accounts,
+    )?;
+
+    let config = accounts.config;
+    let split = split_payout(amount, config.fee_bps, config.withholding_bps, 0);
+    collect_fee(
+        escrow,
+        accounts.operator_stats,
+        accounts.insurance_pool,
+        accounts.fee_treasury,
+        config,
+        split.fee,
+    )?;
+    if split.withheld > 0 {
+        let tax_vault = accounts.tax_vault.ok_or(ErrorCode::InvalidAccount)?;
+        escrow.withhold(tax_vault, config.tax_vault, split.withheld)?;
+    }
+
+    let guardian = match accounts.payout_guardian {
+        Some(info) => PayoutGuardian::load(info)?,
+        None => None,
+    };
+    if guardian.is_some_and(|guardian| guardian.requires_ack(split.net)) {
+        job_post.held_payout = job_post.held_payout.checked_add(BaseUnits(split.net))?;
+        msg!("🛡️ {} payout held for guardian acknowledgement", label);
+    } else if split.net > 0 {
+        escrow.pay_or_park(accounts.freelancer, application.applicant, split.net, accounts.parking)?;
+    }
+    record_project_spend(accounts.project, job_post, amount, false)?;
+    if partial {
+        job_post.milestones_released = job_post.milestones_released.checked_add(BaseUnits(amount))?;
+        job_post.milestones_withheld = job_post.milestones_withheld.checked_add(BaseUnits(split.withheld))?;
+        job_post.milestones_fees = job_post.milestones_fees.checked_add(BaseUnits(split.fee))?;
+    }
+
+    hook_payload.paid = amount;
+    job_post.exit(&crate::ID)?;
+    application.exit(&crate::ID)?;
+    call_settlement_hook(
+        hooks.as_ref().and_then(|hooks| hooks.post_settlement),
+        accounts.post_hook_program,
+        "post_settlement",
+        &hook_payload,
+        &hook_accounts,
+    )?;
+    Ok(split)
+}
+
+// A release ahead of the final payout: a milestone, deliverable, timesheet,
+// retainer period or vested withdrawal. It's counted in `milestones_released`
+// so the final payout only pays what's left.
+fn release_partial<'info>(
+    escrow: &EscrowSource<'info>,
+    job_post: &mut Account<'info, JobPost>,
+    application: &Account<'info, Application>,
+    accounts: ReleaseAccounts<'_, 'info>,
+    amount: u64,
+    label: &str,
+) -> Result<PayoutSplit> {
+    let config = accounts.config;
+    let split = release_to_freelancer(escrow, job_post, application, accounts, amount, label, true)?;
+    flag_large_payout(config, job_post, application.key(), application.applicant, amount)?;
+    Ok(split)
+}
+
+// `collect_fee` for lamports held on a program-owned account other than an
+// escrow, such as an hour block; the insurance pool takes its cut the same way
+fn collect_fee_from<'info>(
+    source: &AccountInfo<'info>,
+    operator_stats: &mut Account<'info, OperatorStats>,
+    insurance_pool: Option<&mut Account<'info, InsurancePool>>,
+    config: &PlatformConfig,
+    fee: u64,
+) -> Result<()> {
+    require!(
+        insurance_pool.is_some() || !config.insurance_enabled,
+        ErrorCode::InvalidAccount
+    );
+    let mut fee = fee;
+    if let Some(pool) = insurance_pool {
+        let contribution = bps_of(fee, pool.contribution_bps);
+        if contribution > 0 {
+            source.sub_lamports(contribution)?;
+            pool.add_lamports(contribution)?;
+            pool.contributed = pool.contributed.checked_add(Lamports(contribution))?;
+            fee = checked_sub(fee, contribution)?;
+        }
+    }
+    if fee > 0 {
+        source.sub_lamports(fee)?;
+        operator_stats.add_lamports(fee)?;
+        operator_stats.fee_revenue = operator_stats.fee_revenue.checked_add(Lamports(fee))?;
+    }
+    Ok(())
+}
+
+// Pays the referrer of an approved freelancer their cut of the platform fee
+// and credits it to their referral stats
+fn pay_referral<'info>(
+    escrow: &EscrowSource<'info>,
+    job_post: &Account<'info, JobPost>,
+    application: &Application,
+    referrer: Option<&UncheckedAccount<'info>>,
+    referral_stats: Option<&mut Account<'info, ReferralStats>>,
+    amount: u64,
+) -> Result<()> {
+    let (Some(referrer_key), Some(referrer), Some(referral_stats)) =
+        (application.referrer, referrer, referral_stats)
+    else {
+        return err!(ErrorCode::InvalidAccount);
+    };
+    require_keys_eq!(referral_stats.referrer, referrer_key, ErrorCode::InvalidAccount);
+
+    escrow.pay_leg(referrer, referrer_key, amount, EscrowLeg::Fee)?;
+    referral_stats.rewards = checked_add(referral_stats.rewards, 1)?;
+    if escrow.is_native() {
+        referral_stats.total_earned = checked_add(referral_stats.total_earned, amount)?;
+    }
+
+    emit!(ReferralRewarded {
+        job_post: job_post.key(),
+        applicant: application.applicant,
+        referrer: referrer_key,
+        amount: BaseUnits(amount),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// Counts a payout towards the job's project, if it has one
+fn record_project_spend(
+    project: Option<&mut Account<Project>>,
+    job_post: &Account<JobPost>,
+    amount: u64,
+    completed: bool,
+) -> Result<()> {
+    let Some(project_key) = job_post.project else {
+        return Ok(());
+    };
+    let project = project.ok_or(ErrorCode::InvalidAccount)?;
+    require_keys_eq!(project.key(), project_key, ErrorCode::InvalidAccount);
+
+    let spent_before = project.spent;
+    project.spent = checked_add(project.spent, amount)?;
+    if completed {
+        project.jobs_completed = project
+            .jobs_completed
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    // Warn once per threshold, on the payout that crosses it
+    if project.budget > 0 {
+        for &threshold_bps in &project.alert_thresholds_bps {
+            let threshold = bps_of(project.budget, threshold_bps);
+            if spent_before < threshold && project.spent >= threshold {
+                emit!(BudgetThresholdCrossed {
+                    project: project.key(),
+                    job_post: job_post.key(),
+                    threshold_bps,
+                    budget: BaseUnits(project.budget),
+                    spent: BaseUnits(project.spent),
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn emit_deadline(
+    job_post: Pubkey,
+    application: Option<Pubkey>,
+    kind: DeadlineKind,
+    deadline: i64,
+) -> Result<()> {
+    emit!(DeadlineSet {
+        job_post,
+        application,
+        kind,
+        deadline,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// Create the escrow PDA (a pure system account to hold lamports) and lock the
+// job amount in it, both paid by `funder`. Returns the rent reserved on top of
+// the amount, to be kept on the job as `escrow_rent`.
+fn fund_escrow<'info>(
+    funder: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    job_post_key: Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<Lamports> {
+    let rent = Rent::get()?.minimum_balance(8 + Escrow::INIT_SPACE);
+    let cpi_ctx = CpiContext::new(
+        system_program.clone(),
+        system_program::Transfer {
+            from: funder.clone(),
+            to: escrow.clone(),
+        },
+    );
+    system_program::transfer(cpi_ctx, rent + amount)?;
+
+    init_typed_escrow(escrow, system_program, job_post_key, bump, amount)?;
+    Ok(Lamports(rent))
+}
+
+// Turn a funded, system-owned escrow PDA into a typed Escrow whose ledger
+// starts at `deposited`
+fn init_typed_escrow<'info>(
+    escrow: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    job_post_key: Pubkey,
+    bump: u8,
+    deposited: u64,
+) -> Result<()> {
+    let seeds = &[b"escrow", job_post_key.as_ref(), &[bump]];
+    let signer_seeds = &[&seeds[..]];
+    let space = 8 + Escrow::INIT_SPACE;
+
+    invoke_signed(
+        &system_instruction::allocate(escrow.key, space as u64),
+        &[escrow.clone(), system_program.clone()],
+        signer_seeds,
+    )?;
+    invoke_signed(
+        &system_instruction::assign(escrow.key, &crate::ID),
+        &[escrow.clone(), system_program.clone()],
+        signer_seeds,
+    )?;
+
+    let typed = Escrow {
+        job_post: job_post_key,
+        ledger: EscrowLedger {
+            funded: Lamports(deposited),
+            ..Default::default()
+        },
+        bump,
+    };
+    typed.try_serialize(&mut &mut escrow.try_borrow_mut_data()?[..])
+}
+
+// Move `amount` of the client's credits into a freshly funded escrow
+fn apply_credits(
+    credits: &mut Account<Credits>,
+    escrow: &AccountInfo,
+    job_post: &mut Account<JobPost>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    credits.balance = checked_sub(credits.balance, amount)?;
+    credits.to_account_info().sub_lamports(amount)?;
+    escrow.add_lamports(amount)?;
+    update_escrow_ledger(escrow, |escrow| {
+        escrow.ledger.funded = escrow.ledger.funded.checked_add(Lamports(amount))?;
+        Ok(())
+    })?;
+    job_post.credits_applied = Lamports(amount);
+
+    emit!(CreditsApplied {
+        job_post: job_post.key(),
+        client: job_post.client,
+        amount: Lamports(amount),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// Before a settled SOL job's escrow is emptied, hand whatever it still holds
+// beyond its rent back to the client's credits, up to what they funded
+fn return_unspent_credits<'info>(
+    job_post: &mut Account<'info, JobPost>,
+    escrow: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    credits: Option<&mut Account<'info, Credits>>,
+) -> Result<()> {
+    if !job_post.is_native() || job_post.credits_applied == Lamports::ZERO {
+        return Ok(());
+    }
+    let escrow = EscrowSource::new(job_post, escrow, system_program, None, None, None)?;
+    let returned = job_post.credits_applied.0.min(escrow.balance()?);
+    if returned > 0 {
+        let credits = credits.ok_or(ErrorCode::InvalidAccount)?;
+        let to = credits.to_account_info();
+        escrow.refund_with_credits(job_post, Some(credits), &to, returned)?;
+    }
+    Ok(())
+}
+
+// Pay lamports into a job's escrow, recording them on a typed escrow's ledger
+fn deposit_to_escrow<'info>(
+    funder: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let shape = escrow_shape(escrow)?;
+    let cpi_ctx = CpiContext::new(
+        system_program.clone(),
+        system_program::Transfer {
+            from: funder.clone(),
+            to: escrow.clone(),
+        },
+    );
+    system_program::transfer(cpi_ctx, amount)?;
+
+    if shape == EscrowShape::Typed {
+        update_escrow_ledger(escrow, |escrow| {
+            escrow.ledger.funded = escrow.ledger.funded.checked_add(Lamports(amount))?;
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn update_escrow_ledger(
+    escrow: &AccountInfo,
+    update: impl FnOnce(&mut Escrow) -> Result<()>,
+) -> Result<()> {
+    let mut typed = Escrow::try_deserialize(&mut &escrow.try_borrow_data()?[..])?;
+    update(&mut typed)?;
+    typed.try_serialize(&mut &mut escrow.try_borrow_mut_data()?[..])
+}
+
+// AutoClose drops only what close_job_post could close with nothing left to
+// settle: no open applications, not frozen, and an escrow sweep_escrow has
+// already emptied, returning any unspent credits first. A token job's vault is
+// only swept by close_job_post itself, so the client closes those.
+fn require_auto_closable(job_post: &JobPost, escrow_lamports: u64) -> Result<()> {
+    require!(!job_post.frozen, ErrorCode::JobFrozen);
+    require!(job_post.applications == 0, ErrorCode::ApplicationsOpen);
+    require!(job_post.is_native(), ErrorCode::EscrowNotSwept);
+    let unspent_credits = job_post.credits_applied.0.min(escrow_lamports);
+    require!(
+        escrow_lamports == 0 && unspent_credits == 0,
+        ErrorCode::EscrowNotSwept
+    );
+    Ok(())
+}
+
+fn require_retention_due(config: &PlatformConfig, settled_at: i64) -> Result<()> {
+    let due_at = settled_at + i64::from(config.retention_days) * SECONDS_PER_DAY;
+    require!(
+        Clock::get()?.unix_timestamp >= due_at,
+        ErrorCode::RetentionNotDue
+    );
+    Ok(())
+}
+
+// Drop `freed` bytes off the end of a program-owned account and hand the rent
+// no longer needed to `receiver`
+fn shrink_account<'info>(
+    account: &AccountInfo<'info>,
+    receiver: &AccountInfo<'info>,
+    freed: usize,
+) -> Result<()> {
+    let new_len = account.data_len() - freed;
+    account.resize(new_len)?;
+
+    let excess = account
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(new_len));
+    account.sub_lamports(excess)?;
+    receiver.add_lamports(excess)?;
+    Ok(())
+}
+
+// Close a program-owned account that can't be loaded through its current type
+fn close_raw_account<'info>(
+    account: &AccountInfo<'info>,
+    receiver: &AccountInfo<'info>,
+) -> Result<()> {
+    receiver.add_lamports(account.lamports())?;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+    Ok(())
 }
 
+// Typed escrow replacing the legacy 0-byte vault at the same PDA. Its ledger
+// covers lamports; token jobs keep their balance in the escrow vault.
 #[account]
 #[derive(InitSpace)]
-pub struct Application {
-    pub applicant: Pubkey,
+pub struct Escrow {
     pub job_post: Pubkey,
-    #[max_len(200)]
-    pub resume_link: String,
-    #[max_len(200)]
-    pub submission_link: String,
-    #[max_len(300)]
-    pub narration: String,
-    #[max_len(300)]
-    pub client_review: String,
-    pub approved: bool,
-    pub submitted: bool,
-    pub completed: bool,
-    pub rejected: bool,
-    pub expected_end_date: i64,
+    pub ledger: EscrowLedger,
+    pub bump: u8,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct UserStats {
-    pub total_gigs_posted: u64,
-    pub total_revenue_earned: u64,
-    pub monthly_gigs: u64,
-    pub monthly_revenue: u64,
-    pub last_updated_month: u8,
+// An amount of SOL in lamports. Serialized as a bare u64, so it can replace
+// one in any account or event without changing the layout; keeping it a
+// distinct type stops lamports and token base units from being mixed. It
+// types what is lamports whatever the job is paid in: escrow ledgers and
+// rent, a token job's SOL leg, credits, stakes, fees and the operator and
+// insurance balances.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Debug,
+    InitSpace,
+)]
+pub struct Lamports(pub u64);
+
+impl Lamports {
+    pub const ZERO: Self = Self(0);
+
+    pub fn checked_add(self, other: Self) -> Result<Self> {
+        checked_add(self.0, other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self> {
+        checked_sub(self.0, other.0).map(Self)
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
 }
 
-// ----------------- CONTEXTS -----------------
+impl std::fmt::Display for Lamports {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
-#[derive(Accounts)]
-#[instruction(title: String)]
-pub struct InitializeJobPost<'info> {
-    #[account(
-        init,
-        payer = client,
-        space = 8 + JobPost::INIT_SPACE,
-        seeds = [b"job_post", client.key().as_ref(), title.as_bytes()],
-        bump
-    )]
-    pub job_post: Account<'info, JobPost>,
+// An amount in whatever a job (or project) is paid in: lamports for SOL jobs,
+// the base units of `payment_mint` for token jobs. Serialized as a bare u64
+// like Lamports, and typing job prices, milestones, holds and the amounts
+// events report against a job, so they can't pass for lamports unchecked;
+// `JobPost::lamports` converts one on a SOL job.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Debug,
+    InitSpace,
+)]
+pub struct BaseUnits(pub u64);
 
-    #[account(
-        mut,
-        seeds = [b"escrow", job_post.key().as_ref()],
-        bump
-    )]
-    /// CHECK: Escrow PDA (pure lamport vault, no data)
-    pub escrow: UncheckedAccount<'info>,
+impl BaseUnits {
+    pub const ZERO: Self = Self(0);
 
-    #[account(
-        init_if_needed,
-        payer = client,
-        space = 8 + UserStats::INIT_SPACE,
-        seeds = [b"user_stats", client.key().as_ref()],
-        bump
-    )]
-    pub client_stats: Account<'info, UserStats>,
+    pub fn checked_add(self, other: Self) -> Result<Self> {
+        checked_add(self.0, other.0).map(Self)
+    }
 
-    #[account(mut)]
-    pub client: Signer<'info>,
-    pub system_program: Program<'info, System>,
+    pub fn checked_sub(self, other: Self) -> Result<Self> {
+        checked_sub(self.0, other.0).map(Self)
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl std::fmt::Display for BaseUnits {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// An amount of an SPL token in its base units, with the mint's decimals so
+// it can't pass for lamports or for another mint's units
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace,
+)]
+pub struct TokenAmount {
+    pub raw: u64,
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    pub fn new(raw: u64, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    // Refuses amounts of a mint with other decimals
+    pub fn checked_add(self, other: Self) -> Result<Self> {
+        require_eq!(self.decimals, other.decimals, ErrorCode::InvalidAmount);
+        Ok(Self::new(checked_add(self.raw, other.raw)?, self.decimals))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self> {
+        require_eq!(self.decimals, other.decimals, ErrorCode::InvalidAmount);
+        Ok(Self::new(checked_sub(self.raw, other.raw)?, self.decimals))
+    }
+}
+
+// Lamports paid into a job's escrow and where they went, excluding rent. The
+// job's `amount` stays the agreed price; this is the money actually moved.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct EscrowLedger {
+    pub funded: Lamports,
+    // Paid to the freelancer, their teammates, a charity or a claimable vault
+    pub released: Lamports,
+    pub refunded: Lamports,
+    pub fees: Lamports,
+    // Auto-release holdbacks set aside in escrow until their dispute window
+    // closes. Takes the slot of a penalties total no flow ever booked.
+    pub holdbacks: Lamports,
+    // Tax withheld from payouts
+    pub withheld: Lamports,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EscrowLeg {
+    Release,
+    Refund,
+    Fee,
+    Withheld,
+    // Set aside rather than paid out; see `EscrowLedger::release_holdback`
+    Holdback,
+}
+
+impl EscrowLedger {
+    pub fn paid_out(&self) -> Result<Lamports> {
+        self.released
+            .checked_add(self.refunded)?
+            .checked_add(self.fees)?
+            .checked_add(self.withheld)
+    }
+
+    // Funded lamports still in escrow, holdbacks included
+    pub fn in_escrow(&self) -> Result<Lamports> {
+        self.funded.checked_sub(self.paid_out()?)
+    }
+
+    // Funded lamports neither paid out nor held back
+    pub fn outstanding(&self) -> Result<Lamports> {
+        self.in_escrow()?.checked_sub(self.holdbacks)
+    }
+
+    // Books a payment out of escrow, or a holdback set aside in it, refusing
+    // more than is outstanding
+    pub fn record(&mut self, leg: EscrowLeg, amount: Lamports) -> Result<()> {
+        require!(self.outstanding()? >= amount, ErrorCode::InsufficientEscrowBalance);
+        let total = match leg {
+            EscrowLeg::Release => &mut self.released,
+            EscrowLeg::Refund => &mut self.refunded,
+            EscrowLeg::Fee => &mut self.fees,
+            EscrowLeg::Withheld => &mut self.withheld,
+            EscrowLeg::Holdback => &mut self.holdbacks,
+        };
+        *total = total.checked_add(amount)?;
+        Ok(())
+    }
+
+    // Frees a settled holdback so it can be booked as a release or refund
+    pub fn release_holdback(&mut self, amount: Lamports) -> Result<()> {
+        self.holdbacks = self.holdbacks.checked_sub(amount)?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EscrowShape {
+    // Bare system account, as created by `fund_escrow`
+    Legacy,
+    // Program-owned `Escrow` account
+    Typed,
+}
+
+// Where a job's escrowed funds live: lamports on the escrow PDA, or tokens in
+// the escrow PDA's associated token account for jobs paid in an SPL token
+pub struct EscrowSource<'info> {
+    job_post_key: Pubkey,
+    escrow_bump: u8,
+    escrow_rent: Lamports,
+    escrow: AccountInfo<'info>,
+    shape: EscrowShape,
+    system_program: AccountInfo<'info>,
+    token: Option<TokenVault<'info>>,
+}
+
+// Where a SOL payout leg goes when its destination can't take it
+pub struct Parking<'a, 'info> {
+    pub vault: &'a AccountInfo<'info>,
+    pub namespace: Pubkey,
+    // Whose vault it is; the recipient, or the teammate a registered wallet
+    // belongs to
+    pub claimant: Pubkey,
+    pub rent_payer: &'a AccountInfo<'info>,
+}
+
+struct TokenVault<'info> {
+    vault: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    balance: TokenAmount,
+}
+
+impl<'info> EscrowSource<'info> {
+    pub fn new(
+        job_post: &Account<'info, JobPost>,
+        escrow: &UncheckedAccount<'info>,
+        system_program: &Program<'info, System>,
+        escrow_vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+        mint: Option<&InterfaceAccount<'info, Mint>>,
+        token_program: Option<&Interface<'info, TokenInterface>>,
+    ) -> Result<Self> {
+        let shape = escrow_shape(escrow)?;
+
+        let token = match job_post.payment_mint {
+            None => None,
+            Some(payment_mint) => {
+                let (Some(vault), Some(mint), Some(token_program)) =
+                    (escrow_vault, mint, token_program)
+                else {
+                    return err!(ErrorCode::InvalidAccount);
+                };
+                require_keys_eq!(mint.key(), payment_mint, ErrorCode::InvalidAccount);
+                require_keys_eq!(
+                    vault.key(),
+                    get_associated_token_address_with_program_id(
+                        &escrow.key(),
+                        &payment_mint,
+                        &token_program.key(),
+                    ),
+                    ErrorCode::InvalidAccount
+                );
+
+                Some(TokenVault {
+                    vault: vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    balance: TokenAmount::new(vault.amount, mint.decimals),
+                })
+            }
+        };
+
+        Ok(Self {
+            job_post_key: job_post.key(),
+            escrow_bump: job_post.escrow_bump,
+            escrow_rent: job_post.escrow_rent,
+            escrow: escrow.to_account_info(),
+            shape,
+            system_program: system_program.to_account_info(),
+            token,
+        })
+    }
+
+    pub fn is_native(&self) -> bool {
+        self.token.is_none()
+    }
+
+    // Funds available to the job, leaving the reserved rent on the escrow. A
+    // typed escrow answers from its ledger rather than its lamports.
+    pub fn balance(&self) -> Result<u64> {
+        match (&self.token, self.shape) {
+            (None, EscrowShape::Legacy) => {
+                Ok(Lamports(self.escrow.lamports()).saturating_sub(self.escrow_rent).0)
+            }
+            (None, EscrowShape::Typed) => {
+                let typed = Escrow::try_deserialize(&mut &self.escrow.try_borrow_data()?[..])?;
+                Ok(typed.ledger.outstanding()?.0)
+            }
+            (Some(token), _) => Ok(token.balance.raw),
+        }
+    }
+
+    // Release `amount` to `recipient`. `to` is the recipient's wallet for SOL
+    // jobs and a token account they own for token jobs.
+    pub fn pay(&self, to: &AccountInfo<'info>, recipient: Pubkey, amount: u64) -> Result<()> {
+        self.pay_leg(to, recipient, amount, EscrowLeg::Release)
+    }
+
+    // Return `amount` to the job's client
+    pub fn refund(&self, to: &AccountInfo<'info>, client: Pubkey, amount: u64) -> Result<()> {
+        self.pay_leg(to, client, amount, EscrowLeg::Refund)
+    }
+
+    // Refund `amount` to the client, handing the escrow's credit-funded share
+    // back to their credits first
+    pub fn refund_with_credits(
+        &self,
+        job_post: &mut JobPost,
+        credits: Option<&mut Account<'info, Credits>>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let (returned, cash) = job_post.take_credit_refund(amount);
+        if returned > 0 {
+            let credits = credits.ok_or(ErrorCode::InvalidAccount)?;
+            self.pay_leg(&credits.to_account_info(), credits.key(), returned, EscrowLeg::Refund)?;
+            credits.balance = checked_add(credits.balance, returned)?;
+            emit!(CreditsReturned {
+                job_post: self.job_post_key,
+                client: job_post.client,
+                amount: Lamports(returned),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+        if cash > 0 {
+            self.refund(to, job_post.client, cash)?;
+        }
+        Ok(())
+    }
+
+    // Move lamports out of the escrow PDA itself, whatever the job is paid
+    // in: a token job's SOL leg lives there beside the token vault
+    pub fn pay_lamports(
+        &self,
+        to: &AccountInfo<'info>,
+        recipient: Pubkey,
+        amount: Lamports,
+        leg: EscrowLeg,
+    ) -> Result<()> {
+        require_keys_eq!(to.key(), recipient, ErrorCode::InvalidAccount);
+        let seeds = &[b"escrow", self.job_post_key.as_ref(), &[self.escrow_bump]];
+        move_escrow_lamports(
+            &self.system_program,
+            &self.escrow,
+            to,
+            self.shape,
+            &[&seeds[..]],
+            amount,
+            leg,
+        )
+    }
+
+    // Withhold `amount` of a payout into the platform's tax vault
+    pub fn withhold(&self, tax_vault: &AccountInfo<'info>, key: Pubkey, amount: u64) -> Result<()> {
+        self.pay_leg(tax_vault, key, amount, EscrowLeg::Withheld)
+    }
+
+    // Set `amount` aside on a typed escrow's ledger as an auto-release
+    // holdback; the lamports stay put until it settles
+    pub fn hold_back(&self, amount: u64) -> Result<()> {
+        if self.token.is_some() || self.shape == EscrowShape::Legacy {
+            return Ok(());
+        }
+        update_escrow_ledger(&self.escrow, |typed| {
+            typed.ledger.record(EscrowLeg::Holdback, Lamports(amount))
+        })
+    }
+
+    // Free a settling holdback of `amount` before paying it out to either party
+    pub fn release_holdback(&self, amount: u64) -> Result<()> {
+        if self.token.is_some() || self.shape == EscrowShape::Legacy {
+            return Ok(());
+        }
+        update_escrow_ledger(&self.escrow, |typed| typed.ledger.release_holdback(Lamports(amount)))
+    }
+
+    // Like `pay`, booking the transfer on a typed escrow's ledger as `leg`
+    pub fn pay_leg(
+        &self,
+        to: &AccountInfo<'info>,
+        recipient: Pubkey,
+        amount: u64,
+        leg: EscrowLeg,
+    ) -> Result<()> {
+        let seeds = &[b"escrow", self.job_post_key.as_ref(), &[self.escrow_bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        match &self.token {
+            None => {
+                require_keys_eq!(to.key(), recipient, ErrorCode::InvalidAccount);
+                move_escrow_lamports(
+                    &self.system_program,
+                    &self.escrow,
+                    to,
+                    self.shape,
+                    signer_seeds,
+                    Lamports(amount),
+                    leg,
+                )
+            }
+            Some(token) => {
+                require_keys_eq!(*to.owner, token.token_program.key(), ErrorCode::InvalidAccount);
+                let destination = TokenAccount::try_deserialize(&mut &to.try_borrow_data()?[..])?;
+                require_keys_eq!(destination.owner, recipient, ErrorCode::InvalidAccount);
+
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        token.token_program.clone(),
+                        TransferChecked {
+                            from: token.vault.clone(),
+                            mint: token.mint.clone(),
+                            to: to.clone(),
+                            authority: self.escrow.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                    token.balance.decimals,
+                )?;
+
+                // Transfer-fee mints withhold part of the leg at the
+                // destination; the job books the gross amount
+                let clock = Clock::get()?;
+                let fee = transfer_fee(&token.mint.try_borrow_data()?, clock.epoch, amount)?;
+                if fee > 0 {
+                    emit!(TransferFeeWithheld {
+                        job_post: self.job_post_key,
+                        recipient,
+                        gross: BaseUnits(amount),
+                        fee: BaseUnits(fee),
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    // Like `pay`, but with `parking` a SOL leg `to` can't receive is moved to
+    // the claimant's claimable vault instead of failing the whole release.
+    // Returns whether the leg was parked.
+    pub fn pay_or_park(
+        &self,
+        to: &AccountInfo<'info>,
+        recipient: Pubkey,
+        amount: u64,
+        parking: Option<Parking<'_, 'info>>,
+    ) -> Result<bool> {
+        if let Some(parking) = parking.filter(|_| self.is_native()) {
+            require_keys_eq!(to.key(), recipient, ErrorCode::InvalidAccount);
+            if !can_receive_lamports(to, amount)? {
+                park_funds(
+                    self,
+                    parking.vault,
+                    parking.namespace,
+                    parking.claimant,
+                    amount,
+                    parking.rent_payer,
+                    &self.system_program,
+                )?;
+                emit!(PayoutParked {
+                    claimant: parking.claimant,
+                    destination: recipient,
+                    amount: Lamports(amount),
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+                return Ok(true);
+            }
+        }
+        self.pay(to, recipient, amount)?;
+        Ok(false)
+    }
+}
+
+// A transfer leaves its destination rent-exempt and can't credit a program
+fn can_receive_lamports(to: &AccountInfo, amount: u64) -> Result<bool> {
+    if to.executable {
+        return Ok(false);
+    }
+    let balance = checked_add(to.lamports(), amount)?;
+    Ok(balance >= Rent::get()?.minimum_balance(to.data_len()))
+}
+
+// Fee a Token-2022 transfer-fee mint withholds from a transfer of `amount`
+// in `epoch`; zero for mints without the extension, including spl-token ones
+pub fn transfer_fee(mint_data: &[u8], epoch: u64, amount: u64) -> Result<u64> {
+    let mint = StateWithExtensions::<MintState>::unpack(mint_data)?;
+    let Ok(config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// Amount to send so that `net` arrives after the mint's transfer fee
+pub fn gross_for_net(mint_data: &[u8], epoch: u64, net: u64) -> Result<u64> {
+    let mint = StateWithExtensions::<MintState>::unpack(mint_data)?;
+    let Ok(config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(net);
+    };
+    let fee = config
+        .calculate_inverse_epoch_fee(epoch, net)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))?;
+    checked_add(net, fee)
+}
+
+// The escrow PDA is either a bare system account or a typed `Escrow`; refuse
+// to move funds out of anything else that ends up in the escrow slot
+fn escrow_shape(escrow: &AccountInfo) -> Result<EscrowShape> {
+    if *escrow.owner == system_program::ID {
+        require!(escrow.data_is_empty(), ErrorCode::InvalidEscrowAccount);
+        return Ok(EscrowShape::Legacy);
+    }
+
+    require_keys_eq!(*escrow.owner, crate::ID, ErrorCode::InvalidEscrowAccount);
+    let data = escrow.try_borrow_data()?;
+    require!(
+        data.len() == 8 + Escrow::INIT_SPACE && data[..8] == *Escrow::DISCRIMINATOR,
+        ErrorCode::InvalidEscrowAccount
+    );
+    Ok(EscrowShape::Typed)
 }
 
-#[derive(Accounts)]
-pub struct ApplyToJob<'info> {
-    #[account(
-        init,
-        payer = freelancer,
-        space = 8 + Application::INIT_SPACE,
-        seeds = [b"application", job_post.key().as_ref(), freelancer.key().as_ref()],
-        bump
-    )]
-    pub application: Account<'info, Application>,
+// Grow a program account written under an older layout of `T` to the current
+// size, zero-filling the new tail so fields appended since decode as zero, then
+// rewrite it with `stamp` applied. Returns what `stamp` reports as the old version.
+fn migrate_layout<'info, T: AccountSerialize + AccountDeserialize + Space>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    stamp: impl FnOnce(&mut T) -> u8,
+) -> Result<u8> {
+    grow_account::<T>(account, payer, system_program)?;
 
-    #[account(mut)]
-    pub freelancer: Signer<'info>,
-    pub job_post: Account<'info, JobPost>,
-    pub system_program: Program<'info, System>,
+    let mut state = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    let from_version = stamp(&mut state);
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    Ok(from_version)
 }
 
-#[derive(Accounts)]
-pub struct ApproveApplication<'info> {
-    #[account(
-        mut,
-        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
-    )]
-    pub application: Account<'info, Application>,
+// Grow an account to the current size of `T` and write `state` over whatever
+// layout it held
+fn rewrite_layout<'info, T: AccountSerialize + Space>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    state: &T,
+) -> Result<()> {
+    grow_account::<T>(account, payer, system_program)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    state.try_serialize(&mut &mut data[..])
+}
 
-    #[account(
-        mut,
-        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized
-    )]
-    pub job_post: Account<'info, JobPost>,
+// Resize an account to fit `T`, the payer topping up its rent
+fn grow_account<'info, T: Space>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let space = 8 + T::INIT_SPACE;
+    if account.data_len() < space {
+        let shortfall = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account.lamports());
+        if shortfall > 0 {
+            let cpi_ctx = CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            );
+            system_program::transfer(cpi_ctx, shortfall)?;
+        }
+        account.resize(space)?;
+    }
+    Ok(())
+}
 
-    #[account(mut)]
-    pub client: Signer<'info>,
+// Open the revision 0 submission holding a migrated application's work
+fn create_migrated_submission<'info>(
+    submission_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    submission: &Submission,
+) -> Result<()> {
+    let (submission_key, bump) = pda::submission(&submission.application, 0);
+    require_keys_eq!(submission_info.key(), submission_key, ErrorCode::InvalidAccount);
+    let space = 8 + Submission::INIT_SPACE;
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            &submission_key,
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        ),
+        &[payer.clone(), submission_info.clone(), system_program.clone()],
+        &[&[b"submission", submission.application.as_ref(), &[0], &[bump]]],
+    )?;
+    let submission = Submission {
+        bump,
+        ..submission.clone()
+    };
+    submission.try_serialize(&mut &mut submission_info.try_borrow_mut_data()?[..])
 }
 
-#[derive(Accounts)]
-pub struct SubmitWork<'info> {
-    #[account(
-        mut,
-        constraint = application.applicant == freelancer.key() @ ErrorCode::Unauthorized,
-        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
-    )]
-    pub application: Account<'info, Application>,
+// JobPost as the first release laid it out: no namespace ahead of `client`
+// and nothing after `freelancer`. Only migrate_account reads it.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+struct LegacyJobPost {
+    client: Pubkey,
+    #[max_len(100)]
+    title: String,
+    #[max_len(500)]
+    description: String,
+    amount: u64,
+    is_filled: bool,
+    cancelled: bool,
+    start_date: i64,
+    end_date: i64,
+    escrow_bump: u8,
+    freelancer: Option<Pubkey>,
+}
 
-    #[account(mut)]
-    pub freelancer: Signer<'info>,
+impl LegacyJobPost {
+    // The post in the current layout under `namespace`; whatever the first
+    // release didn't track starts out as on a new post
+    fn upgrade(self, namespace: Pubkey) -> JobPost {
+        let spec = JobSpec {
+            title: self.title,
+            description: self.description,
+            amount: self.amount,
+            start_date: self.start_date,
+            end_date: self.end_date,
+        };
+        let mut job_post = new_job_post(namespace, self.client, spec, self.escrow_bump, None);
+        job_post.is_filled = self.is_filled;
+        job_post.cancelled = self.cancelled;
+        job_post.freelancer = self.freelancer;
+        job_post.hires_count = u8::from(self.freelancer.is_some());
+        job_post
+    }
+}
 
-    pub job_post: Account<'info, JobPost>,
+// Application as the first release laid it out, with the submitted work
+// inline and no `rejected_at`. Only migrate_account reads it.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+struct LegacyApplication {
+    applicant: Pubkey,
+    job_post: Pubkey,
+    #[max_len(200)]
+    resume_link: String,
+    #[max_len(200)]
+    submission_link: String,
+    #[max_len(300)]
+    narration: String,
+    #[max_len(300)]
+    client_review: String,
+    approved: bool,
+    submitted: bool,
+    completed: bool,
+    rejected: bool,
+    expected_end_date: i64,
 }
 
-#[derive(Accounts)]
-pub struct ApproveSubmission<'info> {
-    #[account(
-        mut,
-        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
-    )]
-    pub application: Account<'info, Application>,
+impl LegacyApplication {
+    // The application at `key` in the current layout, with the revision 0
+    // submission its inline work moves to, if it had submitted any
+    fn upgrade(self, key: Pubkey) -> (Application, Option<Submission>) {
+        let mut application = new_application(
+            self.applicant,
+            self.job_post,
+            self.resume_link,
+            self.expected_end_date,
+            self.applicant,
+        );
+        application.client_review = self.client_review;
+        application.approved = self.approved;
+        application.submitted = self.submitted;
+        application.completed = self.completed;
+        application.rejected = self.rejected;
 
-    #[account(
-        mut,
-        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized
-    )]
-    pub job_post: Account<'info, JobPost>,
+        let submission = (!self.submission_link.is_empty()).then_some(Submission {
+            job_post: self.job_post,
+            application: key,
+            freelancer: self.applicant,
+            revision: 0,
+            link: self.submission_link,
+            narration: self.narration,
+            submitted_at: 0,
+            status: if self.completed {
+                SubmissionStatus::Approved
+            } else if self.rejected {
+                SubmissionStatus::Rejected
+            } else {
+                SubmissionStatus::Pending
+            },
+            bump: 0,
+        });
+        application.submissions = u8::from(submission.is_some());
+        (application, submission)
+    }
+}
 
-    #[account(
-        mut,
-        seeds = [b"escrow", job_post.key().as_ref()],
-        bump = job_post.escrow_bump
-    )]
-    /// CHECK: Escrow PDA (pure lamport vault)
-    pub escrow: UncheckedAccount<'info>,
+// Refuses payouts to the paying client or to anyone acting for them, which
+// would let a client park funds in escrow and take them back as "earnings".
+// Applies even where `allow_self_dealing` let the hire through.
+// `payee_delegate` is the client's Delegate PDA for the payee.
+fn require_independent_payee(
+    client: Pubkey,
+    payee: Pubkey,
+    payee_delegate: &AccountInfo,
+) -> Result<()> {
+    require_keys_neq!(payee, client, ErrorCode::SelfDealing);
+    require!(
+        payee_delegate.owner != &crate::ID || payee_delegate.data_is_empty(),
+        ErrorCode::SelfDealing
+    );
+    Ok(())
+}
 
-    #[account(mut)]
-    pub client: Signer<'info>,
+// Current time, if the application's approval left its feedback open
+fn require_feedback_open(application: &Application) -> Result<i64> {
+    require!(application.completed, ErrorCode::WorkNotCompleted);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < application.feedback_closes_at, ErrorCode::FeedbackWindowClosed);
+    Ok(now)
+}
 
-    #[account(mut)]
-    /// CHECK: Freelancer wallet
-    pub freelancer: UncheckedAccount<'info>,
+// Legacy escrows pay through the system program, signed by the PDA; typed
+// escrows are program-owned, so their lamports are debited directly
+fn move_escrow_lamports<'info>(
+    system_program: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    shape: EscrowShape,
+    signer_seeds: &[&[&[u8]]],
+    amount: Lamports,
+    leg: EscrowLeg,
+) -> Result<()> {
+    match shape {
+        EscrowShape::Legacy => {
+            let cpi_ctx = CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: escrow.clone(),
+                    to: to.clone(),
+                },
+                signer_seeds,
+            );
+            system_program::transfer(cpi_ctx, amount.0)
+        }
+        EscrowShape::Typed => {
+            update_escrow_ledger(escrow, |typed| typed.ledger.record(leg, amount))?;
+            escrow.sub_lamports(amount.0)?;
+            to.add_lamports(amount.0)?;
+            Ok(())
+        }
+    }
+}
 
-    #[account(
-        init_if_needed,
-        payer = client,
-        space = 8 + UserStats::INIT_SPACE,
-        seeds = [b"user_stats", freelancer.key().as_ref()],
-        bump
-    )]
-    pub freelancer_stats: Account<'info, UserStats>,
+fn record_gig_posted(
+    client_stats: &mut UserStats,
+    monthly_stats: Option<&mut Account<MonthlyStats>>,
+) -> Result<()> {
+    let (year, month) = current_period()?;
+    client_stats.record_gig_posted(year, month)?;
+    if let Some(monthly_stats) = monthly_stats {
+        monthly_stats.require_period(year, month)?;
+        monthly_stats.gigs_posted = checked_add(monthly_stats.gigs_posted, 1)?;
+    }
+    Ok(())
+}
 
-    pub system_program: Program<'info, System>,
+// At most `max` lowercase two-letter codes, no repeats
+fn validate_language_codes(languages: &[[u8; 2]], max: usize) -> Result<()> {
+    require_ctx!(languages.len() <= max, ErrorCode::InvalidInput, "languages", max, languages.len());
+    for (i, code) in languages.iter().enumerate() {
+        require!(
+            code.iter().all(u8::is_ascii_lowercase) && !languages[..i].contains(code),
+            ErrorCode::InvalidLanguageCode
+        );
+    }
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct CancelJob<'info> {
-    #[account(
-        mut,
-        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized
-    )]
-    pub job_post: Account<'info, JobPost>,
+fn validate_profile(display_name: &str, skills: &[String], portfolio_link: &str) -> Result<()> {
+    require_ctx!(
+        !display_name.is_empty() && display_name.len() <= MAX_DISPLAY_NAME_LEN,
+        ErrorCode::InvalidInput,
+        "display_name",
+        MAX_DISPLAY_NAME_LEN,
+        display_name.len()
+    );
+    require_ctx!(skills.len() <= MAX_SKILLS, ErrorCode::InvalidInput, "skills", MAX_SKILLS, skills.len());
+    for skill in skills {
+        require_ctx!(
+            !skill.is_empty() && skill.len() <= MAX_SKILL_LEN,
+            ErrorCode::InvalidInput,
+            "skill",
+            MAX_SKILL_LEN,
+            skill.len()
+        );
+    }
+    require_ctx!(
+        portfolio_link.len() <= MAX_ATTACHMENT_URI_LEN,
+        ErrorCode::InvalidInput,
+        "portfolio_link",
+        MAX_ATTACHMENT_URI_LEN,
+        portfolio_link.len()
+    );
+    Ok(())
+}
 
-    #[account(
-        mut,
-        seeds = [b"escrow", job_post.key().as_ref()],
-        bump = job_post.escrow_bump
-    )]
-    /// CHECK: Escrow account
-    pub escrow: UncheckedAccount<'info>,
+// A submission is awaiting review and its review deadline has passed
+fn require_review_lapsed(
+    job_post: &JobPost,
+    application: &Application,
+    config: &PlatformConfig,
+) -> Result<()> {
+    require!(application.submitted, ErrorCode::WorkNotCompleted);
+    let deadline = job_post
+        .review_deadline(config, application.submitted_at)
+        .ok_or(ErrorCode::ClaimsDisabled)?;
+    require!(
+        Clock::get()?.unix_timestamp >= deadline,
+        ErrorCode::ReviewWindowOpen
+    );
+    Ok(())
+}
 
-    #[account(mut)]
-    pub client: Signer<'info>,
+// Lists `entry` on the owner's index page, when one was passed
+fn index_for_user(page: Option<&mut Account<UserIndexPage>>, entry: Pubkey) -> Result<()> {
+    match page {
+        Some(page) => page.append(entry),
+        None => Ok(()),
+    }
+}
 
-    pub system_program: Program<'info, System>,
+// Drops a closing account from the owner's index page, when one was passed
+fn unindex_for_user(page: Option<&mut Account<UserIndexPage>>, entry: Pubkey) -> Result<()> {
+    match page {
+        Some(page) => page.remove(entry),
+        None => Ok(()),
+    }
 }
 
-#[derive(Accounts)]
-pub struct RejectSubmission<'info> {
-    #[account(
-        mut,
-        constraint = application.job_post == job_post.key() @ ErrorCode::InvalidAccount
-    )]
-    pub application: Account<'info, Application>,
+// Hires `application` onto the job on the given terms, checking it may be
+// hired; callers check who approves and the job's dependency
+fn hire_applicant(
+    job_post: &mut Account<JobPost>,
+    application: &mut Account<Application>,
+    config: &PlatformConfig,
+    freelancer_stats: Option<&UserStats>,
+    freelancer_profile: Option<&mut Account<FreelancerProfile>>,
+    checklist: Vec<[u8; 32]>,
+    ip_terms: IpTerms,
+) -> Result<()> {
+    require!(!job_post.is_filled, ErrorCode::JobAlreadyFilled);
+    require!(!job_post.cancelled, ErrorCode::JobCancelled);
+    require!(
+        application.job_post == job_post.key(),
+        ErrorCode::InvalidAccount
+    );
+    require!(!application.approved, ErrorCode::ApplicationAlreadyApproved);
+    require!(!application.abandoned, ErrorCode::ApplicationAbandoned);
+    require!(!application.declined, ErrorCode::ApplicationDeclined);
+    require!(job_post.auction.is_none(), ErrorCode::AuctionJob);
+    require!(job_post.bounty.is_none(), ErrorCode::BountyJob);
+    require!(!job_post.hidden, ErrorCode::JobHidden);
+    require!(
+        application
+            .proposed_amount
+            .is_none_or(|amount| amount == job_post.amount.0),
+        ErrorCode::CounterOfferPending
+    );
+    require!(
+        config.allow_self_dealing || application.applicant != job_post.client,
+        ErrorCode::SelfDealing
+    );
 
-    #[account(
-        mut,
-        constraint = job_post.client == client.key() @ ErrorCode::Unauthorized,
-        constraint = job_post.freelancer == Some(application.applicant) @ ErrorCode::Unauthorized 
-    )]
-    pub job_post: Account<'info, JobPost>,
+    // High-value jobs only go to freelancers with a track record or an
+    // account old enough. The threshold is in lamports, so token jobs are
+    // always gated while it is set.
+    if config.high_value_threshold > 0
+        && (!job_post.is_native() || job_post.amount.0 >= config.high_value_threshold)
+    {
+        let stats = freelancer_stats.ok_or(ErrorCode::FreelancerTooNew)?;
+        let min_age = i64::from(config.min_account_age_days) * SECONDS_PER_DAY;
+        require!(
+            stats.jobs_completed > 0
+                || (stats.first_seen_at > 0
+                    && Clock::get()?.unix_timestamp - stats.first_seen_at >= min_age),
+            ErrorCode::FreelancerTooNew
+        );
+    }
 
-    #[account(mut)]
-    pub client: Signer<'info>,
+    application.approved = true;
+    application.approved_at = Clock::get()?.unix_timestamp;
+    application.checklist = checklist;
+    application.ip_terms = ip_terms;
+    job_post.hires_count = job_post
+        .hires_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    job_post.is_filled = job_post.hires_count >= job_post.max_hires;
+    let start_date = job_post.start_date;
+    if let Some(retainer) = job_post.retainer.as_mut() {
+        retainer.started_at = Clock::get()?.unix_timestamp.max(start_date);
+    }
+    if job_post.max_hires <= 1 {
+        job_post.freelancer = Some(application.applicant);
+    }
+    track_engagement(freelancer_profile, application.applicant, true)?;
+    emit!(ApplicationApproved {
+        job_post: job_post.key(),
+        application: application.key(),
+        client: job_post.client,
+        freelancer: application.applicant,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct GetUserStats<'info> {
-    #[account(
-        seeds = [b"user_stats", user.key().as_ref()],
-        bump
-    )]
-    pub user_stats: Account<'info, UserStats>,
+// Moves a freelancer's active job count as an engagement starts or ends, and
+// announces when that changes whether they're available
+fn track_engagement(
+    freelancer_profile: Option<&mut Account<FreelancerProfile>>,
+    freelancer: Pubkey,
+    started: bool,
+) -> Result<()> {
+    let Some(profile) = freelancer_profile else {
+        return Ok(());
+    };
+    require_keys_eq!(profile.freelancer, freelancer, ErrorCode::InvalidAccount);
 
-    /// CHECK: The user whose stats we're querying
-    pub user: UncheckedAccount<'info>,
+    profile.active_jobs = if started {
+        profile
+            .active_jobs
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+    } else {
+        profile.active_jobs.saturating_sub(1)
+    };
+    let available = profile.is_available();
+    if available != profile.available {
+        profile.available = available;
+        emit!(AvailabilityChanged {
+            freelancer,
+            active_jobs: profile.active_jobs,
+            max_active_jobs: profile.max_active_jobs,
+            available,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    Ok(())
+}
+
+// Real-world offsets run from UTC-12:00 to UTC+14:00
+fn validate_utc_offset(offset_minutes: i16) -> Result<()> {
+    require_ctx!(
+        (MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&offset_minutes),
+        ErrorCode::InvalidInput,
+        "utc_offset_minutes",
+        if offset_minutes < 0 { MIN_UTC_OFFSET_MINUTES } else { MAX_UTC_OFFSET_MINUTES },
+        offset_minutes
+    );
+    Ok(())
+}
+
+// Calendar year and month (1–12) of the current UTC time
+fn current_period() -> Result<(u16, u8)> {
+    Ok(civil_date(Clock::get()?.unix_timestamp))
 }
 
 // ----------------- ERRORS -----------------
@@ -583,4 +19978,947 @@ pub enum ErrorCode {
     WorkAlreadyRejected,
     #[msg("Escrow account does not have enough balance.")]
     InsufficientEscrowBalance,
-}
\ No newline at end of file
+    #[msg("This wallet is not an approved on-ramp.")]
+    OnrampNotAllowed,
+    #[msg("The on-ramp allowlist is full.")]
+    OnrampAllowlistFull,
+    #[msg("Job has not been settled yet.")]
+    JobNotSettled,
+    #[msg("Retention period has not elapsed yet.")]
+    RetentionNotDue,
+    #[msg("Retention policy does not apply to this account.")]
+    RetentionNotApplicable,
+    #[msg("Not enough accrued fees to withdraw.")]
+    InsufficientFeeBalance,
+    #[msg("Batch must contain between 1 and 10 jobs.")]
+    InvalidBatchSize,
+    #[msg("All occurrences of this recurring job have been posted.")]
+    RecurringJobExhausted,
+    #[msg("The next occurrence is not due yet.")]
+    OccurrenceNotDue,
+    #[msg("This job is awarded by auction.")]
+    AuctionJob,
+    #[msg("This job is not an auction.")]
+    NotAnAuction,
+    #[msg("The auction is closed.")]
+    AuctionClosed,
+    #[msg("The auction is still open.")]
+    AuctionStillOpen,
+    #[msg("Bidder does not meet the reputation floor.")]
+    ReputationTooLow,
+    #[msg("This auction takes sealed bids.")]
+    SealedAuction,
+    #[msg("This auction does not take sealed bids.")]
+    NotSealedAuction,
+    #[msg("Sealed bids can only be revealed between bid close and reveal end.")]
+    NotRevealPhase,
+    #[msg("Bid has already been revealed.")]
+    BidAlreadyRevealed,
+    #[msg("Revealed bid does not match its commitment.")]
+    BidCommitmentMismatch,
+    #[msg("This job is a direct offer to a specific freelancer.")]
+    DirectOfferJob,
+    #[msg("This job is not a direct offer.")]
+    NotADirectOffer,
+    #[msg("The offer has expired.")]
+    OfferExpired,
+    #[msg("The offer has not expired yet.")]
+    OfferNotExpired,
+    #[msg("Job has already been settled.")]
+    JobAlreadySettled,
+    #[msg("Scope history is full.")]
+    ScopeHistoryFull,
+    #[msg("There is no pending scope amendment.")]
+    NoPendingScope,
+    #[msg("Basis points must not exceed 10000.")]
+    InvalidBps,
+    #[msg("Tax year does not match the current year.")]
+    InvalidTaxYear,
+    #[msg("The charity allowlist is full.")]
+    CharityAllowlistFull,
+    #[msg("This charity is not on the allowlist.")]
+    CharityNotAllowed,
+    #[msg("Job has already been published.")]
+    JobAlreadyPublished,
+    #[msg("Job has not been published yet.")]
+    JobNotPublished,
+    #[msg("Escrow PDA is not approved as delegate for the job amount.")]
+    DelegateApprovalMissing,
+    #[msg("Only available for jobs paid in native SOL.")]
+    NativeOnly,
+    #[msg("Escrow must be a bare system account or a typed escrow.")]
+    InvalidEscrowAccount,
+    #[msg("Clients cannot apply to or approve themselves on their own jobs.")]
+    SelfDealing,
+    #[msg("Freelancer needs a prior completion or an older account for this job.")]
+    FreelancerTooNew,
+    #[msg("Paid trials are disabled for this platform.")]
+    TrialsDisabled,
+    #[msg("This job is not a paid trial.")]
+    NotATrial,
+    #[msg("Trial has already been converted into a job.")]
+    TrialAlreadyConverted,
+    #[msg("Trial work has not been approved yet.")]
+    TrialNotCompleted,
+    #[msg("Satisfaction score has already been recorded.")]
+    SatisfactionAlreadyRecorded,
+    #[msg("Registry page does not match the job's registry position.")]
+    InvalidRegistryPage,
+    #[msg("A price update for the configured feed is required.")]
+    OraclePriceRequired,
+    #[msg("Price update is not a verified update for the configured feed.")]
+    InvalidOraclePrice,
+    #[msg("Oracle price is too old.")]
+    StaleOraclePrice,
+    #[msg("Repricing clauses only apply to open, standard SOL jobs.")]
+    RepricingJob,
+    #[msg("Job has no repricing clause.")]
+    NotRepricing,
+    #[msg("Escrow has already been migrated.")]
+    EscrowAlreadyMigrated,
+    #[msg("This feature is disabled for the platform.")]
+    FeatureDisabled,
+    #[msg("Attestation issuer allowlist is full.")]
+    IssuerAllowlistFull,
+    #[msg("Attestation issuer is not allowed.")]
+    IssuerNotAllowed,
+    #[msg("Attestation does not match its digest.")]
+    InvalidAttestation,
+    #[msg("Payout is held for the freelancer's guardian.")]
+    PayoutHeld,
+    #[msg("No payout is held for this job.")]
+    NoHeldPayout,
+    #[msg("The job this one depends on has not settled yet.")]
+    DependencyNotSettled,
+    #[msg("Payment mint is not supported.")]
+    InvalidMint,
+    #[msg("Milestone does not exist.")]
+    InvalidMilestone,
+    #[msg("Milestone has already been approved.")]
+    MilestoneAlreadyApproved,
+    #[msg("Job is already attached to a project.")]
+    JobInProject,
+    #[msg("No arbiter is available for this job.")]
+    NoArbiter,
+    #[msg("Application is under dispute.")]
+    ApplicationDisputed,
+    #[msg("Dispute has already been answered.")]
+    DisputeAlreadyAnswered,
+    #[msg("Dispute has already been resolved.")]
+    DisputeAlreadyResolved,
+    #[msg("Unreviewed work claims are disabled.")]
+    ClaimsDisabled,
+    #[msg("The client's review window is still open.")]
+    ReviewWindowOpen,
+    #[msg("The window to dispute this rejection has closed.")]
+    DisputeWindowClosed,
+    #[msg("The freelancer's window to dispute the rejection is still open.")]
+    DisputeWindowOpen,
+    #[msg("Submission has not been rejected.")]
+    SubmissionNotRejected,
+    #[msg("No dispute window is set, so rejected work can't be reclaimed.")]
+    DisputeWindowUnset,
+    #[msg("The program is paused; only refunds are available.")]
+    ProgramPaused,
+    #[msg("The reminder is not due yet.")]
+    ReminderNotDue,
+    #[msg("No SOL/USD price feed is configured.")]
+    PriceFeedUnset,
+    #[msg("Oracle price confidence interval is too wide.")]
+    OraclePriceUncertain,
+    #[msg("The applicant and the job's client can't be the referrer.")]
+    InvalidReferrer,
+    #[msg("The freelancer's delivery date has not passed yet.")]
+    DeliveryNotOverdue,
+    #[msg("This applicant was removed from the job for abandoning it.")]
+    ApplicationAbandoned,
+    #[msg("This deliverable has already been reviewed.")]
+    DeliverableReviewed,
+    #[msg("Both parties have already agreed to this cancellation.")]
+    CancellationAccepted,
+    #[msg("Only rejected deliverables can be resubmitted.")]
+    DeliverableNotRejected,
+    #[msg("No revisions are left on this job.")]
+    RevisionLimitReached,
+    #[msg("The work can still be revised and resubmitted.")]
+    RevisionsRemaining,
+    #[msg("The job's grace period has not ended yet.")]
+    ReclaimTooEarly,
+    #[msg("Work has already been submitted for this job.")]
+    WorkSubmitted,
+    #[msg("Not every checklist item has been signed off.")]
+    ChecklistIncomplete,
+    #[msg("Expert reviewer allowlist is full.")]
+    ExpertAllowlistFull,
+    #[msg("Expert reviewer is not allowed.")]
+    ExpertNotAllowed,
+    #[msg("Second opinion has already been posted.")]
+    OpinionAlreadyPosted,
+    #[msg("Second opinion is still within its response time.")]
+    OpinionPending,
+    #[msg("Application can still change.")]
+    ApplicationNotTerminal,
+    #[msg("Job still has open applications.")]
+    ApplicationsOpen,
+    #[msg("NDA does not match the job's NDA.")]
+    NdaMismatch,
+    #[msg("The job's NDA has not been acknowledged.")]
+    NdaNotAcknowledged,
+    #[msg("Rating must be between 1 and 5.")]
+    InvalidRating,
+    #[msg("This job has already been rated.")]
+    AlreadyRated,
+    #[msg("Release would exceed the client's daily cap.")]
+    ReleaseCapExceeded,
+    #[msg("Job's price is set by an offer, auction or repricing clause.")]
+    PriceLocked,
+    #[msg("Job is frozen pending investigation.")]
+    JobFrozen,
+    #[msg("Job is not frozen.")]
+    JobNotFrozen,
+    #[msg("Not supported on jobs with several seats.")]
+    MultiSeatJob,
+    #[msg("Application proposes a different price; accept the counter-offer first.")]
+    CounterOfferPending,
+    #[msg("Application has no counter-offer.")]
+    NoCounterOffer,
+    #[msg("Arithmetic overflow.")]
+    ArithmeticOverflow,
+    #[msg("Answer each of the job's screening questions.")]
+    ScreeningAnswersMismatch,
+    #[msg("Monthly stats account is for a different month.")]
+    WrongStatsPeriod,
+    #[msg("Language codes must be distinct lowercase ISO 639-1 codes.")]
+    InvalidLanguageCode,
+    #[msg("Applicant doesn't list every language the job requires.")]
+    LanguageRequirementNotMet,
+    #[msg("Job is no longer taking applications.")]
+    ApplicationsClosed,
+    #[msg("Applicant's UTC offset is outside the job's required range.")]
+    TimezoneOutsideRange,
+    #[msg("Escrow rent reserve already matches the rent-exempt minimum.")]
+    EscrowRentUnchanged,
+    #[msg("Job is invite-only and the freelancer wasn't invited.")]
+    NotInvited,
+    #[msg("Nothing to claim.")]
+    NothingToClaim,
+    #[msg("Job is not a retainer.")]
+    NotARetainer,
+    #[msg("Not available for retainer jobs.")]
+    RetainerJob,
+    #[msg("No retainer period is due for payment yet.")]
+    PeriodNotDue,
+    #[msg("Retainer has no future periods left to cancel.")]
+    NoFuturePeriods,
+    #[msg("Retainer has no hired freelancer yet.")]
+    RetainerNotStarted,
+    #[msg("Job does not stream its pay.")]
+    NotStreaming,
+    #[msg("Not available for streaming jobs.")]
+    StreamingJob,
+    #[msg("Stream has no hired freelancer yet.")]
+    StreamNotStarted,
+    #[msg("Stream was already stopped.")]
+    StreamStopped,
+    #[msg("Nothing has vested since the last withdrawal.")]
+    NothingVested,
+    #[msg("Stream has fully vested.")]
+    StreamFullyVested,
+    #[msg("Settlement does not match the client's current offer.")]
+    SettlementMismatch,
+    #[msg("Release of this job needs its co-signer's approval first.")]
+    CoSignatureRequired,
+    #[msg("User index page is full; open the next page.")]
+    UserIndexPageFull,
+    #[msg("The client declined this application.")]
+    ApplicationDeclined,
+    #[msg("Job amount is below the platform minimum.")]
+    JobAmountTooLow,
+    #[msg("Job amount is above the platform maximum.")]
+    JobAmountTooHigh,
+    #[msg("Job runs shorter than the platform minimum duration.")]
+    JobTooShort,
+    #[msg("Link is too long.")]
+    LinkTooLong,
+    #[msg("Links must start with https:// or ipfs://.")]
+    LinkSchemeNotAllowed,
+    #[msg("Links may only contain printable ASCII without spaces.")]
+    LinkCharsetInvalid,
+    #[msg("The job's price is hidden; reveal it first.")]
+    AmountHidden,
+    #[msg("Revealed amount does not match the job's commitment.")]
+    AmountCommitmentMismatch,
+    #[msg("You already sealed a review for this job.")]
+    ReviewAlreadySealed,
+    #[msg("You have no sealed review for this job.")]
+    ReviewNotSealed,
+    #[msg("You already revealed your review.")]
+    ReviewAlreadyRevealed,
+    #[msg("Reviews stay sealed until both sides commit or the wait ends.")]
+    ReviewsStillSealed,
+    #[msg("Revealed review does not match its seal.")]
+    ReviewCommitmentMismatch,
+    #[msg("This job is not a bounty.")]
+    NotABounty,
+    #[msg("Bounties are claimed, not applied to.")]
+    BountyJob,
+    #[msg("This bounty no longer takes claims or votes.")]
+    BountyClosed,
+    #[msg("You have already voted on this bounty.")]
+    AlreadyVoted,
+    #[msg("The escrow is already empty.")]
+    NothingToSweep,
+    #[msg("This nonce was already used; the instruction was applied before.")]
+    NonceReused,
+    #[msg("This job is not paid hourly.")]
+    NotHourly,
+    #[msg("Not available for hourly jobs.")]
+    HourlyJob,
+    #[msg("Logged hours would exceed the job's maximum.")]
+    HoursExceeded,
+    #[msg("This timesheet entry is already approved.")]
+    TimesheetAlreadyApproved,
+    #[msg("Timesheet entries are still awaiting review.")]
+    TimesheetsPending,
+    #[msg("A completion badge was already minted for this application.")]
+    BadgeAlreadyMinted,
+    #[msg("Only available for jobs paid in a token.")]
+    TokenJobOnly,
+    #[msg("The account already has the current layout.")]
+    AccountAlreadyMigrated,
+    #[msg("The dispute has not been resolved yet.")]
+    DisputeNotResolved,
+    #[msg("The insurance pool already paid out on this dispute.")]
+    InsuranceAlreadyPaid,
+    #[msg("The insurance pool does not hold enough to pay this claim.")]
+    InsufficientInsurancePool,
+    #[msg("Metrics were emitted too recently.")]
+    HeartbeatTooSoon,
+    #[msg("The window for changing this feedback has closed.")]
+    FeedbackWindowClosed,
+    #[msg("The job is hidden by moderation.")]
+    JobHidden,
+    #[msg("The job is not hidden.")]
+    JobNotHidden,
+    #[msg("The job's content was taken down.")]
+    JobTakenDown,
+    #[msg("The transaction landed more slots after signing than it allows.")]
+    StaleTransaction,
+    #[msg("This hour block has already been purchased.")]
+    HourBlockSold,
+    #[msg("This hour block has expired.")]
+    HourBlockExpired,
+    #[msg("This hour block has not expired and has hours left.")]
+    HourBlockNotExpired,
+    #[msg("This application holds the auction's best bid until it closes.")]
+    BestBidStanding,
+    #[msg("Only SOL jobs support this.")]
+    NativeJobOnly,
+    #[msg("No satisfaction score was recorded for this party.")]
+    SatisfactionNotRecorded,
+    #[msg("This satisfaction score was already counted.")]
+    SatisfactionAlreadyRevealed,
+    #[msg("The score and salt don't match the recorded hash.")]
+    SatisfactionCommitmentMismatch,
+    #[msg("Sweep the job's escrow before closing it.")]
+    EscrowNotSwept,
+    #[msg("The job is funded with credits.")]
+    CreditFunded,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overflow() -> Error {
+        error!(ErrorCode::ArithmeticOverflow)
+    }
+
+    fn user_stats() -> UserStats {
+        UserStats {
+            total_gigs_posted: 0,
+            total_revenue_earned: 0,
+            monthly_gigs: 0,
+            monthly_revenue: 0,
+            last_updated_month: 1,
+            jobs_completed: 0,
+            reputation_score: 0,
+            first_seen_at: 0,
+            imported_reputation: 0,
+            ratings_count: 0,
+            ratings_sum: 0,
+            rating_average: 0,
+            last_updated_year: 2025,
+            jobs_cancelled: 0,
+            disputes_won: 0,
+            disputes_lost: 0,
+            total_spent: 0,
+            completion_secs_total: 0,
+            timed_completions: 0,
+            average_completion_secs: 0,
+            version: USER_STATS_VERSION,
+        }
+    }
+
+    fn yearly_earnings() -> YearlyEarnings {
+        YearlyEarnings {
+            namespace: Pubkey::default(),
+            freelancer: Pubkey::default(),
+            year: 2025,
+            gross: 0,
+            fees: 0,
+            withheld: 0,
+            net: 0,
+            jobs: 0,
+        }
+    }
+
+    #[test]
+    fn gig_posted_counts_up_to_max() {
+        let mut stats = user_stats();
+        stats.total_gigs_posted = u64::MAX - 1;
+        stats.record_gig_posted(2025, 1).unwrap();
+        assert_eq!(stats.total_gigs_posted, u64::MAX);
+        assert_eq!(stats.record_gig_posted(2025, 1).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn new_month_resets_monthly_counters() {
+        let mut stats = user_stats();
+        stats.monthly_gigs = u64::MAX;
+        stats.monthly_revenue = u64::MAX;
+        stats.record_job_completed(2025, 2, 500).unwrap();
+        assert_eq!(stats.last_updated_month, 2);
+        assert_eq!(stats.monthly_gigs, 1);
+        assert_eq!(stats.monthly_revenue, 500);
+    }
+
+    #[test]
+    fn same_month_of_next_year_resets_monthly_counters() {
+        let mut stats = user_stats();
+        stats.record_gig_posted(2025, 1).unwrap();
+        stats.record_gig_posted(2026, 1).unwrap();
+        assert_eq!(stats.last_updated_year, 2026);
+        assert_eq!(stats.monthly_gigs, 1);
+        assert_eq!(stats.total_gigs_posted, 2);
+    }
+
+    #[test]
+    fn completed_job_revenue_overflow_is_an_error() {
+        let mut stats = user_stats();
+        stats.total_revenue_earned = u64::MAX - 10;
+        stats.record_job_completed(2025, 1, 10).unwrap();
+        assert_eq!(stats.total_revenue_earned, u64::MAX);
+        assert_eq!(stats.record_job_completed(2025, 1, 1).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn completion_time_averages_over_timed_jobs() {
+        let mut stats = user_stats();
+        stats.record_completion_time(3 * SECONDS_PER_DAY as u64).unwrap();
+        stats.record_completion_time(SECONDS_PER_DAY as u64).unwrap();
+        assert_eq!(stats.timed_completions, 2);
+        assert_eq!(stats.average_completion_secs, 2 * SECONDS_PER_DAY as u64);
+
+        stats.completion_secs_total = u64::MAX;
+        assert_eq!(stats.record_completion_time(1).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn rating_average_survives_large_sums() {
+        let mut stats = user_stats();
+        stats.ratings_count = u64::MAX / 5 - 1;
+        stats.ratings_sum = stats.ratings_count * 5;
+        stats.record_rating(5).unwrap();
+        assert_eq!(stats.rating_average, 500);
+
+        stats.ratings_count = u64::MAX;
+        assert_eq!(stats.record_rating(5).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn yearly_payout_rejects_deductions_above_gross() {
+        let mut earnings = yearly_earnings();
+        earnings.record_payout(100, 60, 40).unwrap();
+        assert_eq!(earnings.net, 0);
+        assert_eq!(earnings.record_payout(100, 60, 41).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn yearly_payout_rejects_gross_overflow() {
+        let mut earnings = yearly_earnings();
+        earnings.gross = u64::MAX;
+        assert_eq!(earnings.record_payout(1, 0, 0).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn operator_volume_overflow_is_an_error() {
+        let mut stats = OperatorStats {
+            namespace: Pubkey::default(),
+            jobs_posted: 0,
+            jobs_completed: 0,
+            jobs_cancelled: 0,
+            volume_escrowed: u64::MAX,
+            volume_released: 0,
+            fee_revenue: Lamports::ZERO,
+            fees_withdrawn: Lamports::ZERO,
+            disputes_opened: 0,
+            disputes_resolved: 0,
+            registered_jobs: 0,
+            satisfaction_responses: 0,
+            satisfaction_total: 0,
+            promoters: 0,
+            detractors: 0,
+            bump: 0,
+            last_heartbeat_at: 0,
+        };
+        stats.record_job_posted(0).unwrap();
+        assert_eq!(stats.jobs_posted, 1);
+        assert_eq!(stats.record_job_posted(1).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn usd_budget_converts_at_the_oracle_price() {
+        // $150.00 per SOL, as Pyth reports it
+        let price = PriceSnapshot {
+            price: 15_000_000_000,
+            expo: -8,
+            publish_time: 0,
+        };
+        assert_eq!(usd_cents_to_lamports(10_000, &price), Some(666_666_666));
+        assert_eq!(usd_cents_to_lamports(15_000, &price), Some(1_000_000_000));
+
+        let coarse = PriceSnapshot { price: 15, expo: 1, ..price };
+        assert_eq!(usd_cents_to_lamports(15_000, &coarse), Some(1_000_000_000));
+        assert_eq!(usd_cents_to_lamports(1, &PriceSnapshot { price: 0, ..price }), None);
+    }
+
+    #[test]
+    fn escrow_ledger_never_pays_out_more_than_funded() {
+        let mut ledger = EscrowLedger {
+            funded: Lamports(1_000),
+            ..Default::default()
+        };
+        ledger.record(EscrowLeg::Fee, Lamports(50)).unwrap();
+        ledger.record(EscrowLeg::Withheld, Lamports(100)).unwrap();
+        ledger.record(EscrowLeg::Release, Lamports(600)).unwrap();
+        assert_eq!(ledger.outstanding().unwrap(), Lamports(250));
+
+        // A holdback stays in escrow but can't be paid out until it's freed
+        ledger.record(EscrowLeg::Holdback, Lamports(50)).unwrap();
+        assert_eq!(ledger.in_escrow().unwrap(), Lamports(250));
+        assert!(ledger.record(EscrowLeg::Refund, Lamports(201)).is_err());
+        assert!(ledger.release_holdback(Lamports(51)).is_err());
+        ledger.release_holdback(Lamports(50)).unwrap();
+
+        assert!(ledger.record(EscrowLeg::Refund, Lamports(251)).is_err());
+        ledger.record(EscrowLeg::Refund, Lamports(250)).unwrap();
+        assert_eq!(ledger.paid_out().unwrap(), ledger.funded);
+    }
+
+    #[test]
+    fn amounts_keep_to_their_units() {
+        // Same wire format as the u64 they replace
+        assert_eq!(Lamports(42).try_to_vec().unwrap(), 42u64.try_to_vec().unwrap());
+        assert!(Lamports(u64::MAX).checked_add(Lamports(1)).is_err());
+
+        let usdc = TokenAmount::new(1_500_000, 6);
+        assert_eq!(usdc.checked_add(usdc).unwrap(), TokenAmount::new(3_000_000, 6));
+        assert!(usdc.checked_add(TokenAmount::new(1, 9)).is_err());
+        assert!(usdc.checked_sub(TokenAmount::new(1_500_001, 6)).is_err());
+    }
+    #[test]
+    fn user_index_page_fills_up_and_regains_room() {
+        let mut page = UserIndexPage {
+            owner: Pubkey::new_unique(),
+            kind: INDEX_JOBS,
+            page: 0,
+            entries: Vec::new(),
+            bump: 0,
+        };
+        let jobs: Vec<Pubkey> = (0..USER_INDEX_PAGE_SIZE).map(|_| Pubkey::new_unique()).collect();
+        for job in &jobs {
+            page.append(*job).unwrap();
+        }
+        page.append(jobs[0]).unwrap();
+        assert_eq!(page.entries.len(), USER_INDEX_PAGE_SIZE);
+        assert!(page.append(Pubkey::new_unique()).is_err());
+
+        page.remove(jobs[1]).unwrap();
+        assert!(page.remove(jobs[1]).is_err());
+        assert_eq!(page.entries[1], jobs[2]);
+        page.append(Pubkey::new_unique()).unwrap();
+    }
+
+    fn transfer_fee_mint(bps: u16, maximum_fee: u64) -> Vec<u8> {
+        use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
+        use anchor_spl::token_2022::spl_token_2022::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0; space];
+        let mut mint = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        let fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: bps.into(),
+        };
+        let config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = fee;
+        config.newer_transfer_fee = fee;
+        mint.base = MintState {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn transfer_fee_mint_withholds_its_fee() {
+        let mint = transfer_fee_mint(100, 5_000);
+        assert_eq!(transfer_fee(&mint, 1, 100_000).unwrap(), 1_000);
+        // Capped at the maximum fee
+        assert_eq!(transfer_fee(&mint, 1, 10_000_000).unwrap(), 5_000);
+
+        let gross = gross_for_net(&mint, 1, 99_000).unwrap();
+        assert_eq!(gross - transfer_fee(&mint, 1, gross).unwrap(), 99_000);
+        let gross = gross_for_net(&mint, 1, 10_000_000).unwrap();
+        assert_eq!(gross, 10_005_000);
+    }
+
+    #[test]
+    fn mint_without_transfer_fee_charges_nothing() {
+        use anchor_lang::solana_program::program_pack::Pack;
+
+        let mut mint = vec![0; MintState::LEN];
+        MintState {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut mint);
+        assert_eq!(transfer_fee(&mint, 1, 100_000).unwrap(), 0);
+        assert_eq!(gross_for_net(&mint, 1, 100_000).unwrap(), 100_000);
+    }
+
+    #[test]
+    fn links_need_an_allowed_scheme_and_charset() {
+        assert!(validate_link("link", "https://example.com/work.zip").is_ok());
+        assert!(validate_link("link", "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").is_ok());
+
+        let rejected = |link: &str| validate_link("link", link).unwrap_err();
+        assert_eq!(rejected("http://example.com"), ErrorCode::LinkSchemeNotAllowed.into());
+        assert_eq!(rejected("javascript:alert(1)"), ErrorCode::LinkSchemeNotAllowed.into());
+        assert_eq!(rejected("https://example.com/a b"), ErrorCode::LinkCharsetInvalid.into());
+        assert_eq!(rejected("https://exämple.com"), ErrorCode::LinkCharsetInvalid.into());
+        let long = format!("https://{}", "a".repeat(MAX_LINK_LEN));
+        assert_eq!(rejected(&long), ErrorCode::LinkTooLong.into());
+    }
+
+    #[test]
+    fn nonce_ring_refuses_recent_nonces_only() {
+        let mut ring = NonceRing::default();
+        assert!(ring.consume(None).is_ok());
+        assert!(ring.consume(None).is_ok());
+        assert!(ring.consume(Some(7)).is_ok());
+        assert_eq!(ring.consume(Some(7)).unwrap_err(), ErrorCode::NonceReused.into());
+        assert_eq!(ring.consume(Some(0)).unwrap_err(), ErrorCode::InvalidInput.into());
+
+        for nonce in 100..100 + NONCE_RING_LEN as u64 {
+            ring.consume(Some(nonce)).unwrap();
+        }
+        // 7 has been pushed out of the ring
+        assert!(ring.consume(Some(7)).is_ok());
+    }
+
+    #[test]
+    fn slot_bound_refuses_stale_and_future_slots() {
+        let bound = SlotBound {
+            reference_slot: 1_000,
+            max_staleness: 150,
+        };
+        assert!(bound.check(1_000).is_ok());
+        assert!(bound.check(1_150).is_ok());
+        assert_eq!(bound.check(1_151).unwrap_err(), ErrorCode::StaleTransaction.into());
+        assert_eq!(bound.check(999).unwrap_err(), ErrorCode::InvalidInput.into());
+    }
+
+    #[test]
+    fn zero_extended_old_layout_reads_as_unversioned() {
+        let mut stats = user_stats();
+        stats.jobs_completed = 3;
+        let mut data = Vec::new();
+        stats.try_serialize(&mut data).unwrap();
+        // As written before `version` was appended, then grown in place
+        data.pop();
+        data.resize(8 + UserStats::INIT_SPACE, 0);
+
+        let migrated = UserStats::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(migrated.version, 0);
+        assert_eq!(migrated.jobs_completed, 3);
+    }
+    #[test]
+    fn legacy_user_stats_read_in_both_layouts() {
+        let mut first_release = Vec::new();
+        for field in [4u64, 9_000, 1, 500] {
+            first_release.extend_from_slice(&field.to_le_bytes());
+        }
+        first_release.push(6);
+        assert_eq!(first_release.len(), LegacyUserStats::FIRST_RELEASE_LEN);
+
+        let stats = LegacyUserStats::read(&first_release).unwrap();
+        assert!(!stats.namespaced);
+        assert_eq!((stats.total_gigs_posted, stats.total_revenue_earned), (4, 9_000));
+        assert_eq!((stats.monthly_revenue, stats.last_updated_month), (500, 6));
+        assert_eq!(stats.jobs_completed, 0);
+
+        let mut namespaced = first_release.clone();
+        namespaced.extend_from_slice(&3u64.to_le_bytes());
+        let stats = LegacyUserStats::read(&namespaced).unwrap();
+        assert!(stats.namespaced);
+        assert_eq!(stats.jobs_completed, 3);
+
+        first_release.pop();
+        assert_eq!(
+            LegacyUserStats::read(&first_release).err(),
+            Some(ErrorCode::InvalidAccount.into())
+        );
+    }
+
+    #[test]
+    fn first_release_job_post_migrates_field_by_field() {
+        let (client, freelancer, namespace) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let legacy = LegacyJobPost {
+            client,
+            title: "Landing page".to_string(),
+            description: "Two sections".to_string(),
+            amount: 5_000,
+            is_filled: true,
+            cancelled: false,
+            start_date: 100,
+            end_date: 200,
+            escrow_bump: 254,
+            freelancer: Some(freelancer),
+        };
+        // As the first release wrote it: discriminator, fields, zero padding
+        let mut data = JobPost::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(8 + LegacyJobPost::INIT_SPACE, 0);
+
+        let decoded = LegacyJobPost::deserialize(&mut &data[8..]).unwrap();
+        let mut migrated = Vec::new();
+        decoded.upgrade(namespace).try_serialize(&mut migrated).unwrap();
+        let job_post = JobPost::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(job_post.namespace, namespace);
+        assert_eq!(job_post.client, client);
+        assert_eq!(job_post.title, "Landing page");
+        assert_eq!(job_post.description, "Two sections");
+        assert_eq!(job_post.amount, BaseUnits(5_000));
+        assert!(job_post.is_filled && !job_post.cancelled);
+        assert_eq!((job_post.start_date, job_post.end_date), (100, 200));
+        assert_eq!(job_post.escrow_bump, 254);
+        assert_eq!(job_post.freelancer, Some(freelancer));
+        assert_eq!(job_post.hires_count, 1);
+        assert_eq!(job_post.version, JOB_POST_VERSION);
+    }
+
+    #[test]
+    fn first_release_application_moves_its_work_to_a_submission() {
+        let (key, applicant, job) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let legacy = LegacyApplication {
+            applicant,
+            job_post: job,
+            resume_link: "https://cv.example".to_string(),
+            submission_link: "https://work.example".to_string(),
+            narration: "Done".to_string(),
+            client_review: String::new(),
+            approved: true,
+            submitted: true,
+            completed: false,
+            rejected: true,
+            expected_end_date: 300,
+        };
+        let mut data = Application::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(8 + LegacyApplication::INIT_SPACE, 0);
+
+        let decoded = LegacyApplication::deserialize(&mut &data[8..]).unwrap();
+        let (application, submission) = decoded.upgrade(key);
+        let mut migrated = Vec::new();
+        application.try_serialize(&mut migrated).unwrap();
+        let application = Application::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!((application.applicant, application.job_post), (applicant, job));
+        assert_eq!(application.resume_link, "https://cv.example");
+        assert!(application.approved && application.submitted && application.rejected);
+        assert_eq!(application.rejected_at, 0);
+        assert_eq!(application.expected_end_date, 300);
+        assert_eq!(application.submissions, 1);
+        assert_eq!(application.version, APPLICATION_VERSION);
+
+        let submission = submission.unwrap();
+        assert_eq!(submission.application, key);
+        assert_eq!(submission.link, "https://work.example");
+        assert_eq!(submission.narration, "Done");
+        assert_eq!(submission.status, SubmissionStatus::Rejected);
+    }
+
+    #[test]
+    fn refunds_return_credit_funded_escrow_before_cash() {
+        let spec = JobSpec {
+            title: "Logo".to_string(),
+            description: String::new(),
+            amount: 1_000,
+            start_date: 0,
+            end_date: 0,
+        };
+        let mut job = new_job_post(Pubkey::default(), Pubkey::default(), spec, 255, None);
+        job.credits_applied = Lamports(600);
+
+        // A budget decrease of 300 comes out of credits alone
+        assert_eq!(job.take_credit_refund(300), (300, 0));
+        assert_eq!(job.credits_applied, Lamports(300));
+        // Cancelling the remaining 700 returns the rest of the credits first
+        assert_eq!(job.take_credit_refund(700), (300, 400));
+        assert_eq!(job.credits_applied, Lamports::ZERO);
+        assert_eq!(job.take_credit_refund(50), (0, 50));
+    }
+
+    #[test]
+    fn settlement_preview_accounts_for_every_share_of_the_gross() {
+        let spec = JobSpec {
+            title: "App".to_string(),
+            description: String::new(),
+            amount: 100_000,
+            start_date: 0,
+            end_date: 0,
+        };
+        let mut job = new_job_post(Pubkey::default(), Pubkey::default(), spec, 255, None);
+        job.sol_leg = Lamports(5_000);
+        let legacy = LegacyApplication::deserialize(&mut &[0u8; LegacyApplication::INIT_SPACE][..]).unwrap();
+        let (mut application, _) = legacy.upgrade(Pubkey::default());
+        application.referrer = Some(Pubkey::new_unique());
+        application.team = vec![
+            TeamShare { recipient: Pubkey::new_unique(), bps: 2_000 },
+            TeamShare { recipient: Pubkey::new_unique(), bps: 1_000 },
+        ];
+        let mut config = PlatformConfig::deserialize(&mut &[0u8; PlatformConfig::INIT_SPACE][..]).unwrap();
+        config.fee_bps = 1_000;
+        config.withholding_bps = 500;
+        config.referral_bps = 2_500;
+        config.release_holdback_bps = 1_000;
+
+        let approved = settlement_breakdown(&job, &application, &config, None, false);
+        // 10% fee, 5% of the rest withheld, then 30% of the net to the team
+        assert_eq!((approved.fee, approved.referral, approved.withheld), (10_000, 2_500, 4_500));
+        assert_eq!(approved.team_shares, 25_650);
+        assert_eq!((approved.holdback, approved.net_payout), (0, 59_850));
+        assert_eq!(approved.sol_leg, Lamports(5_000));
+
+        let released = settlement_breakdown(&job, &application, &config, None, true);
+        assert_eq!((released.holdback, released.net_payout), (5_985, 53_865));
+        for breakdown in [approved, released] {
+            let paid = breakdown.fee
+                + breakdown.withheld
+                + breakdown.donated
+                + breakdown.team_shares
+                + breakdown.holdback
+                + breakdown.net_payout;
+            assert_eq!(paid, breakdown.gross);
+        }
+    }
+
+    #[test]
+    fn co_sign_threshold_applies_to_the_whole_job() {
+        let spec = JobSpec {
+            title: String::new(),
+            description: String::new(),
+            amount: 1_000,
+            start_date: 0,
+            end_date: 0,
+        };
+        let mut job = new_job_post(Pubkey::default(), Pubkey::default(), spec, 255, None);
+        let mut config = PlatformConfig::deserialize(&mut &[0u8; PlatformConfig::INIT_SPACE][..]).unwrap();
+        assert!(!config.requires_co_sign(&job));
+
+        config.co_sign_threshold = 1_000;
+        assert!(config.requires_co_sign(&job));
+        // Releasing most of it in milestones first doesn't lower the bar
+        job.milestones_released = BaseUnits(900);
+        assert!(config.requires_co_sign(&job));
+
+        job.amount = BaseUnits(999);
+        assert!(!config.requires_co_sign(&job));
+        job.payment_mint = Some(Pubkey::new_unique());
+        assert!(config.requires_co_sign(&job));
+    }
+
+    #[test]
+    fn retention_closes_only_swept_idle_jobs() {
+        let spec = JobSpec {
+            title: String::new(),
+            description: String::new(),
+            amount: 1_000,
+            start_date: 0,
+            end_date: 0,
+        };
+        let mut job = new_job_post(Pubkey::default(), Pubkey::default(), spec, 255, None);
+        require_auto_closable(&job, 0).unwrap();
+
+        let escrow_open = require_auto_closable(&job, 890_880).unwrap_err();
+        assert_eq!(escrow_open, error!(ErrorCode::EscrowNotSwept));
+        job.credits_applied = Lamports(500);
+        assert!(require_auto_closable(&job, 500).is_err());
+        require_auto_closable(&job, 0).unwrap();
+
+        job.applications = 1;
+        assert_eq!(require_auto_closable(&job, 0).unwrap_err(), error!(ErrorCode::ApplicationsOpen));
+        job.applications = 0;
+        job.frozen = true;
+        assert_eq!(require_auto_closable(&job, 0).unwrap_err(), error!(ErrorCode::JobFrozen));
+        job.frozen = false;
+        job.payment_mint = Some(Pubkey::new_unique());
+        assert_eq!(require_auto_closable(&job, 0).unwrap_err(), error!(ErrorCode::EscrowNotSwept));
+    }
+
+    #[test]
+    fn late_fee_accrues_until_review_and_caps_at_the_buffer() {
+        let legacy = LegacyApplication::deserialize(&mut &[0u8; LegacyApplication::INIT_SPACE][..]).unwrap();
+        let (mut application, _) = legacy.upgrade(Pubkey::default());
+        application.submitted_at = 1_000;
+        let mut sla = ReviewSla {
+            job_post: Pubkey::default(),
+            application: Pubkey::default(),
+            client: Pubkey::default(),
+            daily_fee: 100,
+            buffer: 450,
+            submitted_at: 0,
+            days_paid: 0,
+            total_paid: 0,
+            bump: 0,
+        };
+        let late_from = 2_000;
+
+        // Less than a day late owes nothing yet
+        assert_eq!(sla.accrue(&application, late_from, late_from + SECONDS_PER_DAY - 1), (0, 0));
+        assert_eq!(sla.submitted_at, 1_000);
+        assert_eq!(sla.accrue(&application, late_from, late_from + 3 * SECONDS_PER_DAY), (3, 300));
+
+        // Two days already claimed; closing after a review on day 7 owes the rest, up to the buffer
+        sla.days_paid = 2;
+        application.reviewed_at = late_from + 7 * SECONDS_PER_DAY;
+        assert_eq!(sla.accrue(&application, late_from, late_from + 30 * SECONDS_PER_DAY), (7, 450));
+
+        // A resubmission restarts the paid days
+        application.submitted_at = 5_000;
+        application.reviewed_at = 0;
+        assert_eq!(sla.accrue(&application, 6_000, 6_000 + SECONDS_PER_DAY), (1, 100));
+        assert_eq!(sla.days_paid, 0);
+    }
+}
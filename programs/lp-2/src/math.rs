@@ -0,0 +1,235 @@
+// Pure arithmetic the program settles with: checked sums, basis-point fee
+// splits, calendar months, vesting and period schedules, and the late-review
+// penalty. Nothing here reads accounts or sysvars, so clients can reproduce
+// any amount the program will pay before sending a transaction.
+
+use crate::{ErrorCode, MAX_BPS, SECONDS_PER_DAY};
+use anchor_lang::prelude::*;
+
+pub fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+}
+
+pub fn checked_sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticOverflow))
+}
+
+// `bps` basis points of `amount`, rounded down
+pub fn bps_of(amount: u64, bps: u16) -> u64 {
+    (u128::from(amount) * u128::from(bps) / u128::from(MAX_BPS)) as u64
+}
+
+// How a payout of `gross` divides up. Each cut is taken from what the
+// previous ones leave: the fee from the gross, withholding from what's left
+// after the fee, and the donation from what's left after both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayoutSplit {
+    pub fee: u64,
+    pub withheld: u64,
+    pub donated: u64,
+    pub net: u64,
+}
+
+pub fn split_payout(gross: u64, fee_bps: u16, withholding_bps: u16, donation_bps: u16) -> PayoutSplit {
+    let fee = bps_of(gross, fee_bps);
+    let withheld = bps_of(gross - fee, withholding_bps);
+    let donated = bps_of(gross - fee - withheld, donation_bps);
+    PayoutSplit {
+        fee,
+        withheld,
+        donated,
+        net: gross - fee - withheld - donated,
+    }
+}
+
+// Proleptic Gregorian (year, month) of a unix timestamp, in UTC
+pub fn civil_date(timestamp: i64) -> (u16, u8) {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as u16, month as u8)
+}
+
+// Share of `amount` vested by `now` when it vests by the second from `start`
+// to `end`; all of it once `end` has passed
+pub fn vested_amount(amount: u64, start: i64, end: i64, now: i64) -> u64 {
+    if now >= end {
+        return amount;
+    }
+    if now <= start {
+        return 0;
+    }
+    let elapsed = (now - start) as u128;
+    let duration = (end - start) as u128;
+    (u128::from(amount) * elapsed / duration) as u64
+}
+
+// Whole periods of `period_seconds` elapsed between `started_at` and `now`,
+// at most `periods`; none before the schedule starts or if it never did
+pub fn periods_elapsed(started_at: i64, period_seconds: i64, periods: u16, now: i64) -> u16 {
+    if started_at == 0 || now < started_at {
+        return 0;
+    }
+    let elapsed = (now - started_at) / period_seconds;
+    elapsed.min(i64::from(periods)) as u16
+}
+
+// Whole days from `late_from` to `stopped_at`; zero until a full day has passed
+pub fn days_late(late_from: i64, stopped_at: i64) -> u64 {
+    u64::try_from((stopped_at - late_from) / SECONDS_PER_DAY).unwrap_or(0)
+}
+
+// Late-review fee still owed for `days_late` days when `days_paid` of them
+// were already paid, at `daily_fee` a day and capped at the `buffer` left
+pub fn late_fee(days_late: u64, days_paid: u64, daily_fee: u64, buffer: u64) -> u64 {
+    days_late
+        .saturating_sub(days_paid)
+        .saturating_mul(daily_fee)
+        .min(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overflow() -> Error {
+        error!(ErrorCode::ArithmeticOverflow)
+    }
+
+    #[test]
+    fn checked_add_reaches_max_then_overflows() {
+        assert_eq!(checked_add(u64::MAX - 1, 1).unwrap(), u64::MAX);
+        assert_eq!(checked_add(u64::MAX, 0).unwrap(), u64::MAX);
+        assert_eq!(checked_add(u64::MAX, 1).unwrap_err(), overflow());
+        assert_eq!(checked_add(u64::MAX, u64::MAX).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn checked_sub_reaches_zero_then_underflows() {
+        assert_eq!(checked_sub(1, 1).unwrap(), 0);
+        assert_eq!(checked_sub(u64::MAX, u64::MAX).unwrap(), 0);
+        assert_eq!(checked_sub(0, 1).unwrap_err(), overflow());
+    }
+
+    #[test]
+    fn bps_of_rounds_down_without_overflowing() {
+        assert_eq!(bps_of(0, MAX_BPS), 0);
+        assert_eq!(bps_of(1_000, 0), 0);
+        assert_eq!(bps_of(1_000, 250), 25);
+        assert_eq!(bps_of(999, 1), 0);
+        assert_eq!(bps_of(10_001, 1), 1);
+        assert_eq!(bps_of(u64::MAX, MAX_BPS), u64::MAX);
+        assert_eq!(bps_of(u64::MAX, MAX_BPS / 2), u64::MAX / 2);
+    }
+
+    #[test]
+    fn payout_split_takes_each_cut_from_what_is_left() {
+        let split = split_payout(10_000, 1_000, 2_000, 5_000);
+        assert_eq!(
+            split,
+            PayoutSplit {
+                fee: 1_000,
+                withheld: 1_800,
+                donated: 3_600,
+                net: 3_600,
+            }
+        );
+        assert_eq!(
+            split_payout(10_000, 0, 0, 0),
+            PayoutSplit {
+                fee: 0,
+                withheld: 0,
+                donated: 0,
+                net: 10_000,
+            }
+        );
+        assert_eq!(split_payout(10_000, MAX_BPS, MAX_BPS, MAX_BPS).net, 0);
+    }
+
+    #[test]
+    fn payout_split_always_adds_up_to_the_gross() {
+        for gross in [0, 1, 7, 9_999, 10_001, 123_456_789, u64::MAX] {
+            for bps in [0, 1, 333, 5_000, 9_999, MAX_BPS] {
+                let split = split_payout(gross, bps, MAX_BPS - bps, bps / 2);
+                let total = u128::from(split.fee)
+                    + u128::from(split.withheld)
+                    + u128::from(split.donated)
+                    + u128::from(split.net);
+                assert_eq!(total, u128::from(gross), "gross {gross}, bps {bps}");
+            }
+        }
+    }
+
+    #[test]
+    fn civil_date_follows_calendar_months() {
+        assert_eq!(civil_date(0), (1970, 1));
+        // 2024-02-29 23:59:59 and the second after
+        assert_eq!(civil_date(1_709_251_199), (2024, 2));
+        assert_eq!(civil_date(1_709_251_200), (2024, 3));
+        // 2025-12-31 23:59:59 and the second after
+        assert_eq!(civil_date(1_767_225_599), (2025, 12));
+        assert_eq!(civil_date(1_767_225_600), (2026, 1));
+    }
+
+    #[test]
+    fn civil_date_handles_century_leap_rules() {
+        // 1969-12-31 23:59:59, before the epoch
+        assert_eq!(civil_date(-1), (1969, 12));
+        // 2000-02-29 is a leap day, 2100-02-29 doesn't exist
+        assert_eq!(civil_date(951_782_400), (2000, 2));
+        assert_eq!(civil_date(951_868_800), (2000, 3));
+        assert_eq!(civil_date(4_107_542_399), (2100, 2));
+        assert_eq!(civil_date(4_107_542_400), (2100, 3));
+    }
+
+    #[test]
+    fn stream_vests_by_the_second() {
+        assert_eq!(vested_amount(1_000, 100, 200, 50), 0);
+        assert_eq!(vested_amount(1_000, 100, 200, 100), 0);
+        assert_eq!(vested_amount(1_000, 100, 200, 133), 330);
+        assert_eq!(vested_amount(1_000, 100, 200, 200), 1_000);
+        assert_eq!(vested_amount(u64::MAX, 0, 4, 2), u64::MAX / 2);
+    }
+
+    #[test]
+    fn periods_elapse_whole_and_stop_at_the_last() {
+        assert_eq!(periods_elapsed(0, 100, 12, 5_000), 0);
+        assert_eq!(periods_elapsed(1_000, 100, 12, 999), 0);
+        assert_eq!(periods_elapsed(1_000, 100, 12, 1_099), 0);
+        assert_eq!(periods_elapsed(1_000, 100, 12, 1_100), 1);
+        assert_eq!(periods_elapsed(1_000, 100, 12, 2_199), 11);
+        assert_eq!(periods_elapsed(1_000, 100, 12, i64::MAX), 12);
+    }
+
+    #[test]
+    fn lateness_counts_whole_days_only() {
+        assert_eq!(days_late(1_000, 0), 0);
+        assert_eq!(days_late(1_000, 1_000), 0);
+        assert_eq!(days_late(1_000, 1_000 + SECONDS_PER_DAY - 1), 0);
+        assert_eq!(days_late(1_000, 1_000 + SECONDS_PER_DAY), 1);
+        assert_eq!(days_late(0, 10 * SECONDS_PER_DAY + 5), 10);
+    }
+
+    #[test]
+    fn late_fee_pays_unpaid_days_up_to_the_buffer() {
+        assert_eq!(late_fee(0, 0, 100, 1_000), 0);
+        assert_eq!(late_fee(3, 0, 100, 1_000), 300);
+        assert_eq!(late_fee(3, 2, 100, 1_000), 100);
+        assert_eq!(late_fee(3, 3, 100, 1_000), 0);
+        assert_eq!(late_fee(2, 3, 100, 1_000), 0);
+        assert_eq!(late_fee(30, 0, 100, 1_000), 1_000);
+        assert_eq!(late_fee(u64::MAX, 0, u64::MAX, 42), 42);
+    }
+}
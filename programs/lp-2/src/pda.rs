@@ -0,0 +1,172 @@
+// Program-derived addresses and bumps, from the same seeds the account
+// constraints use. Handlers checking accounts passed outside a context
+// (remaining accounts, batched posts) derive them here, as do clients.
+
+use anchor_lang::prelude::*;
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+
+// Stats as the first release kept them, one account per user
+pub fn first_release_user_stats(user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"user_stats", user.as_ref()])
+}
+
+// Namespaced stats from before they were split by role
+pub fn legacy_user_stats(namespace: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"user_stats", namespace.as_ref(), user.as_ref()])
+}
+
+pub fn platform_config(namespace: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"platform_config", namespace.as_ref()])
+}
+
+pub fn operator_stats(namespace: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"operator_stats", namespace.as_ref()])
+}
+
+pub fn insurance_pool(namespace: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"insurance_pool", namespace.as_ref()])
+}
+
+pub fn settlement_hooks(namespace: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"settlement_hooks", namespace.as_ref()])
+}
+
+pub fn job_counter(client: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"job_counter", client.as_ref()])
+}
+
+// The client's `job_id`-th job post, numbered by their job counter
+pub fn job_post(client: &Pubkey, job_id: u64) -> (Pubkey, u8) {
+    find(&[b"job_post", client.as_ref(), &job_id.to_le_bytes()])
+}
+
+pub fn escrow(job_post: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"escrow", job_post.as_ref()])
+}
+
+pub fn job_card(job_post: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"job_card", job_post.as_ref()])
+}
+
+pub fn milestones(job_post: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"milestones", job_post.as_ref()])
+}
+
+pub fn application(job_post: &Pubkey, applicant: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"application", job_post.as_ref(), applicant.as_ref()])
+}
+
+// The application's `revision`-th submission of work
+pub fn submission(application: &Pubkey, revision: u8) -> (Pubkey, u8) {
+    find(&[b"submission", application.as_ref(), &[revision]])
+}
+
+pub fn dispute(application: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"dispute", application.as_ref()])
+}
+
+// Role-scoped stats, `role` being ROLE_CLIENT or ROLE_FREELANCER
+pub fn stats(namespace: &Pubkey, role: u8, user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"stats", namespace.as_ref(), &[role], user.as_ref()])
+}
+
+pub fn monthly_stats(namespace: &Pubkey, role: u8, user: &Pubkey, year: u16, month: u8) -> (Pubkey, u8) {
+    find(&[
+        b"monthly_stats",
+        namespace.as_ref(),
+        &[role],
+        user.as_ref(),
+        &year.to_le_bytes(),
+        &[month],
+    ])
+}
+
+pub fn freelancer_profile(namespace: &Pubkey, freelancer: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"freelancer_profile", namespace.as_ref(), freelancer.as_ref()])
+}
+
+pub fn guardian(namespace: &Pubkey, freelancer: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"guardian", namespace.as_ref(), freelancer.as_ref()])
+}
+
+pub fn payout_account(namespace: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"payout_account", namespace.as_ref(), owner.as_ref()])
+}
+
+pub fn claimable_vault(namespace: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"claimable", namespace.as_ref(), owner.as_ref()])
+}
+
+pub fn delegate(client: &Pubkey, delegate: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"delegate", client.as_ref(), delegate.as_ref()])
+}
+
+pub fn release_limit(client: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"release_limit", client.as_ref()])
+}
+
+pub fn hour_block(namespace: &Pubkey, freelancer: &Pubkey, block_id: u64) -> (Pubkey, u8) {
+    find(&[
+        b"hour_block",
+        namespace.as_ref(),
+        freelancer.as_ref(),
+        &block_id.to_le_bytes(),
+    ])
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+    use crate::test_vectors::*;
+    use crate::{ROLE_CLIENT, ROLE_FREELANCER};
+
+    fn key(base58: &str) -> Pubkey {
+        base58.parse().unwrap()
+    }
+
+    #[test]
+    fn derivations_match_the_published_vectors() {
+        let (namespace, client, freelancer) = (key(NAMESPACE), key(CLIENT), key(FREELANCER));
+        let (job, application_key) = (key(JOB_POST), key(APPLICATION));
+        let derived = [
+            ("platform_config", platform_config(&namespace)),
+            ("operator_stats", operator_stats(&namespace)),
+            ("insurance_pool", insurance_pool(&namespace)),
+            ("settlement_hooks", settlement_hooks(&namespace)),
+            ("job_counter", job_counter(&client)),
+            ("job_post", job_post(&client, 0)),
+            ("escrow", escrow(&job)),
+            ("job_card", job_card(&job)),
+            ("milestones", milestones(&job)),
+            ("application", application(&job, &freelancer)),
+            ("submission", submission(&application_key, 0)),
+            ("dispute", dispute(&application_key)),
+            ("stats (client)", stats(&namespace, ROLE_CLIENT, &client)),
+            ("stats (freelancer)", stats(&namespace, ROLE_FREELANCER, &freelancer)),
+            (
+                "monthly_stats",
+                monthly_stats(&namespace, ROLE_FREELANCER, &freelancer, 2026, 3),
+            ),
+            ("user_stats (first release)", first_release_user_stats(&client)),
+            ("user_stats (legacy)", legacy_user_stats(&namespace, &client)),
+            ("freelancer_profile", freelancer_profile(&namespace, &freelancer)),
+            ("guardian", guardian(&namespace, &freelancer)),
+            ("payout_account", payout_account(&namespace, &freelancer)),
+            ("claimable", claimable_vault(&namespace, &freelancer)),
+            ("delegate", delegate(&client, &freelancer)),
+            ("release_limit", release_limit(&client)),
+            ("hour_block", hour_block(&namespace, &freelancer, 0)),
+        ];
+        for (name, (address, bump)) in derived {
+            let vector = PDA_VECTORS
+                .iter()
+                .find(|vector| vector.name == name)
+                .unwrap_or_else(|| panic!("no vector for {name}"));
+            assert_eq!(address.to_string(), vector.address, "{name}");
+            assert_eq!(bump, vector.bump, "{name}");
+        }
+    }
+}
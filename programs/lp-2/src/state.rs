@@ -0,0 +1,124 @@
+// Job lifecycle states and the rules deriving them from a post's flags, shared
+// with clients so list views and the program agree on where a job stands.
+
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum JobStatus {
+    Draft,
+    Open,
+    Auction,
+    Filled,
+    Completed,
+    Cancelled,
+    Expired,
+}
+
+impl JobStatus {
+    // Settled one way or another; only sweeping what's left of the escrow
+    // can still happen
+    pub fn is_terminal(self) -> bool {
+        matches!(self, JobStatus::Completed | JobStatus::Cancelled | JobStatus::Expired)
+    }
+}
+
+// The flags on a job post its status is derived from
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JobLifecycle {
+    pub expired: bool,
+    pub cancelled: bool,
+    pub settled: bool,
+    pub published: bool,
+    pub filled: bool,
+    pub auction_open: bool,
+}
+
+impl JobLifecycle {
+    // Terminal states win over everything else, expiry (itself a
+    // cancellation) first; then a draft, a filled job and an open auction
+    pub fn status(&self) -> JobStatus {
+        if self.expired {
+            JobStatus::Expired
+        } else if self.cancelled {
+            JobStatus::Cancelled
+        } else if self.settled {
+            JobStatus::Completed
+        } else if !self.published {
+            JobStatus::Draft
+        } else if self.filled {
+            JobStatus::Filled
+        } else if self.auction_open {
+            JobStatus::Auction
+        } else {
+            JobStatus::Open
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every combination of the six flags
+    fn lifecycles() -> impl Iterator<Item = JobLifecycle> {
+        (0u8..64).map(|bits| JobLifecycle {
+            expired: bits & 1 != 0,
+            cancelled: bits & 2 != 0,
+            settled: bits & 4 != 0,
+            published: bits & 8 != 0,
+            filled: bits & 16 != 0,
+            auction_open: bits & 32 != 0,
+        })
+    }
+
+    #[test]
+    fn published_job_moves_from_open_to_completed() {
+        let mut job = JobLifecycle::default();
+        assert_eq!(job.status(), JobStatus::Draft);
+        job.published = true;
+        assert_eq!(job.status(), JobStatus::Open);
+        job.auction_open = true;
+        assert_eq!(job.status(), JobStatus::Auction);
+        job.filled = true;
+        assert_eq!(job.status(), JobStatus::Filled);
+        job.settled = true;
+        assert_eq!(job.status(), JobStatus::Completed);
+    }
+
+    #[test]
+    fn expiry_wins_over_cancellation_and_both_over_settlement() {
+        let expired = JobLifecycle {
+            expired: true,
+            cancelled: true,
+            published: true,
+            ..Default::default()
+        };
+        assert_eq!(expired.status(), JobStatus::Expired);
+        let cancelled = JobLifecycle {
+            cancelled: true,
+            settled: true,
+            ..Default::default()
+        };
+        assert_eq!(cancelled.status(), JobStatus::Cancelled);
+    }
+
+    #[test]
+    fn terminal_exactly_when_expired_cancelled_or_settled() {
+        for job in lifecycles() {
+            assert_eq!(
+                job.status().is_terminal(),
+                job.expired || job.cancelled || job.settled,
+                "{job:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn unpublished_live_job_is_always_a_draft() {
+        for job in lifecycles().filter(|job| !job.published) {
+            if !job.status().is_terminal() {
+                assert_eq!(job.status(), JobStatus::Draft, "{job:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,990 @@
+// Canonical derivations for other SDKs to check theirs against. Every seed
+// scheme the program uses appears once, derived from the fixed keys below;
+// derived addresses feed later vectors the way they do on chain (the job post
+// address seeds its escrow, the application seeds its dispute, ...).
+
+use crate::*;
+
+// Fixed wallets used as seeds; `[n; 32]` as base58
+pub const ADMIN: &str = "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi";
+pub const CLIENT: &str = "8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR";
+pub const FREELANCER: &str = "CktRuQ2mttgRGkXJtyksdKHjUdc2C4TgDzyB98oEzy8";
+pub const CHARITY: &str = "GgBaCs3NCBuZN12kCJgAW63ydqohFkHEdfdEXBPzLHq";
+
+// The namespace of a deployment is its admin's key
+pub const NAMESPACE: &str = ADMIN;
+pub const JOB_TITLE: &str = "Logo design";
+pub const PROJECT_NAME: &str = "Rebrand";
+
+pub enum Seed {
+    // UTF-8 bytes of the string
+    Str(&'static str),
+    // The 32 bytes of a base58 key
+    Key(&'static str),
+    U8(u8),
+    // Little-endian, as `to_le_bytes`
+    U16(u16),
+    U32(u32),
+    U64(u64),
+}
+
+impl Seed {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Seed::Str(s) => s.as_bytes().to_vec(),
+            Seed::Key(key) => key.parse::<Pubkey>().expect("base58 key").to_bytes().to_vec(),
+            Seed::U8(n) => vec![*n],
+            Seed::U16(n) => n.to_le_bytes().to_vec(),
+            Seed::U32(n) => n.to_le_bytes().to_vec(),
+            Seed::U64(n) => n.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+pub struct PdaVector {
+    pub name: &'static str,
+    pub seeds: &'static [Seed],
+    pub address: &'static str,
+    pub bump: u8,
+}
+
+impl PdaVector {
+    // Re-derives the address with this crate's program id
+    pub fn derive(&self) -> (Pubkey, u8) {
+        let seeds: Vec<Vec<u8>> = self.seeds.iter().map(Seed::to_bytes).collect();
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+}
+
+// Derived addresses that seed later vectors
+pub const JOB_POST: &str = "ExCP5iqFW8FHYXXLtsEbmu9BaufUv9iLVAMSSufD4YA7";
+pub const RECURRING_JOB: &str = "EhXNWyMKziZtMudYVuuMXF9VEcjnqcPkk1r7Rd5KdVEx";
+pub const APPLICATION: &str = "92PWeKmuBVTqU5VyDAjRGfmv6ViYAVbkbjaAgpxf3kgG";
+pub const ATTESTATION: &str = "EmRJeP15qw4CabBteTDQZ3SPfCyJGCdUhEjQa4dDKvcd";
+
+pub const PDA_VECTORS: &[PdaVector] = &[
+    PdaVector {
+        name: "platform_config",
+        seeds: &[Seed::Str("platform_config"), Seed::Key(NAMESPACE)],
+        address: "9x5Uq12wb38ubUmzZshWs1ygeQseFKJNzGBxZX7JQXRN",
+        bump: 255,
+    },
+    PdaVector {
+        name: "operator_stats",
+        seeds: &[Seed::Str("operator_stats"), Seed::Key(NAMESPACE)],
+        address: "3CZVtrzpaFroKBrg2K6YL8Eyvm4gWHeyBMkNfvW2CeZL",
+        bump: 252,
+    },
+    PdaVector {
+        name: "insurance_pool",
+        seeds: &[Seed::Str("insurance_pool"), Seed::Key(NAMESPACE)],
+        address: "4PbmZqJ4Jz9wFhfYyvQXHmJxCwTzYgkVzZoW4DW9spPp",
+        bump: 255,
+    },
+    PdaVector {
+        name: "settlement_hooks",
+        seeds: &[Seed::Str("settlement_hooks"), Seed::Key(NAMESPACE)],
+        address: "8CRkPKjPAeGbAqzygTQok2W8WLStpvhxc9xGXiYJbFnn",
+        bump: 254,
+    },
+    PdaVector {
+        name: "job_counter",
+        seeds: &[Seed::Str("job_counter"), Seed::Key(CLIENT)],
+        address: "Ce694vzvUQ8DRkjqcbgXiWgrQ2ZGF8KdkJWxj8RH5dTw",
+        bump: 253,
+    },
+    PdaVector {
+        name: "job_post",
+        seeds: &[Seed::Str("job_post"), Seed::Key(CLIENT), Seed::U64(0)],
+        address: JOB_POST,
+        bump: 253,
+    },
+    PdaVector {
+        name: "recurring_job",
+        seeds: &[Seed::Str("recurring_job"), Seed::Key(CLIENT), Seed::Str(JOB_TITLE)],
+        address: RECURRING_JOB,
+        bump: 255,
+    },
+    PdaVector {
+        name: "job_post (recurring occurrence)",
+        seeds: &[Seed::Str("job_post"), Seed::Key(RECURRING_JOB), Seed::U32(3)],
+        address: "E5NPGZ8JL7y6kNZ1riAgLpM5hhZV6ptf1AwKHuD3cDs2",
+        bump: 253,
+    },
+    PdaVector {
+        name: "escrow",
+        seeds: &[Seed::Str("escrow"), Seed::Key(JOB_POST)],
+        address: "6Yce734BRVfASNBDaFz8LrT7htQpuB4j6cYszfzQ9PNA",
+        bump: 251,
+    },
+    PdaVector {
+        name: "job_card",
+        seeds: &[Seed::Str("job_card"), Seed::Key(JOB_POST)],
+        address: "FsYVF29JzMTqAovf1SxGC7YKXt4AWydbw2LX95DyEzYV",
+        bump: 255,
+    },
+    PdaVector {
+        name: "milestones",
+        seeds: &[Seed::Str("milestones"), Seed::Key(JOB_POST)],
+        address: "9gptD2WG7KbWHMz4hgQma9EoDGDjEsc4pntuvdn3Rq5F",
+        bump: 254,
+    },
+    PdaVector {
+        name: "application",
+        seeds: &[Seed::Str("application"), Seed::Key(JOB_POST), Seed::Key(FREELANCER)],
+        address: APPLICATION,
+        bump: 254,
+    },
+    PdaVector {
+        name: "invitation",
+        seeds: &[Seed::Str("invitation"), Seed::Key(JOB_POST), Seed::Key(FREELANCER)],
+        address: "4eAF3pyivcbUCnRCW6RHpa8VRdTQ7faPmyAYoWGUbxFZ",
+        bump: 249,
+    },
+    PdaVector {
+        name: "nda",
+        seeds: &[Seed::Str("nda"), Seed::Key(JOB_POST), Seed::Key(FREELANCER)],
+        address: "7r2bBQ9fmVzCMi3YxhUPK6gdFP7fjXstA5S9GiBapMXR",
+        bump: 255,
+    },
+    PdaVector {
+        name: "submission",
+        seeds: &[Seed::Str("submission"), Seed::Key(APPLICATION), Seed::U8(0)],
+        address: "3cYKJVa3oD9rqUgXd6guD6qTcjKr3pJHKtqrHuo35V7z",
+        bump: 255,
+    },
+    PdaVector {
+        name: "timesheet",
+        seeds: &[Seed::Str("timesheet"), Seed::Key(APPLICATION), Seed::U16(0)],
+        address: "FePqfbwwH6Vkh591B5ww2SsagDHgbyHXCaFyU9XhUrZs",
+        bump: 255,
+    },
+    PdaVector {
+        name: "hour_block",
+        seeds: &[
+            Seed::Str("hour_block"),
+            Seed::Key(NAMESPACE),
+            Seed::Key(FREELANCER),
+            Seed::U64(0),
+        ],
+        address: "AUL1atbuu3GjTpfkFVi9RUm1kCXoLyiB8zJB5X9YUHk8",
+        bump: 255,
+    },
+    PdaVector {
+        name: "badge",
+        seeds: &[Seed::Str("badge"), Seed::Key(APPLICATION)],
+        address: "7BdALCcMd2CCZCWCKT64B6GvtEcTJVC5HJZtWg5oUncz",
+        bump: 255,
+    },
+    PdaVector {
+        name: "bounty_claim",
+        seeds: &[Seed::Str("bounty_claim"), Seed::Key(JOB_POST), Seed::Key(FREELANCER)],
+        address: "6uag16JsKJ79EirJ5RxBhwtFKTDzEk9FnKYYTmRXzpCh",
+        bump: 255,
+    },
+    PdaVector {
+        name: "dispute",
+        seeds: &[Seed::Str("dispute"), Seed::Key(APPLICATION)],
+        address: "3m8KZKEatCKKN2L6viCZqe4ZPLvaatasuUQoKm4UimUm",
+        bump: 255,
+    },
+    PdaVector {
+        name: "second_opinion",
+        seeds: &[Seed::Str("second_opinion"), Seed::Key(APPLICATION)],
+        address: "3AfKsRNMr7frWgdidtikfgh5nzQ7XcP2edWisAz7H6hN",
+        bump: 255,
+    },
+    PdaVector {
+        name: "stats (client)",
+        seeds: &[
+            Seed::Str("stats"),
+            Seed::Key(NAMESPACE),
+            Seed::U8(ROLE_CLIENT),
+            Seed::Key(CLIENT),
+        ],
+        address: "7KXAph9nQ6VBPkrcXfyaqzzq5pYauKGBdDV1LGGiVteh",
+        bump: 255,
+    },
+    PdaVector {
+        name: "stats (freelancer)",
+        seeds: &[
+            Seed::Str("stats"),
+            Seed::Key(NAMESPACE),
+            Seed::U8(ROLE_FREELANCER),
+            Seed::Key(FREELANCER),
+        ],
+        address: "33td3iVjrJnfqZpt9YBcnE1A6ykQoPxHNSfvzw7Zd5Ve",
+        bump: 255,
+    },
+    PdaVector {
+        name: "monthly_stats",
+        seeds: &[
+            Seed::Str("monthly_stats"),
+            Seed::Key(NAMESPACE),
+            Seed::U8(ROLE_FREELANCER),
+            Seed::Key(FREELANCER),
+            Seed::U16(2026),
+            Seed::U8(3),
+        ],
+        address: "5YYFBRms3g7n72DJSEHoLaCbpvgQv82Kg1JbREYSrEtB",
+        bump: 253,
+    },
+    PdaVector {
+        name: "user_stats (first release)",
+        seeds: &[Seed::Str("user_stats"), Seed::Key(CLIENT)],
+        address: "7yg97f24T1hnKNYtjQWxeHWYd3Bk5rESzJPkgoGexsL8",
+        bump: 255,
+    },
+    PdaVector {
+        name: "user_stats (legacy)",
+        seeds: &[Seed::Str("user_stats"), Seed::Key(NAMESPACE), Seed::Key(CLIENT)],
+        address: "8SwpPBkNXpuk8PC1q2DZeSYY7fRYyKTrH6HNFPAFCfof",
+        bump: 255,
+    },
+    PdaVector {
+        name: "yearly_earnings",
+        seeds: &[
+            Seed::Str("yearly_earnings"),
+            Seed::Key(NAMESPACE),
+            Seed::Key(FREELANCER),
+            Seed::U16(2026),
+        ],
+        address: "8qDxomA6B2NbK6eQw4BzRzfEH4NZwLy8it7ZhpWPyxp2",
+        bump: 253,
+    },
+    PdaVector {
+        name: "freelancer_profile",
+        seeds: &[Seed::Str("freelancer_profile"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "D7cn6DFVNQwp6DfD5BW5eMdwZULUkzk2eVTnqJ3vofZ3",
+        bump: 254,
+    },
+    PdaVector {
+        name: "guardian",
+        seeds: &[Seed::Str("guardian"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "Fc1CUVimCHrigcb36BvYpNz5Y8YXwQ9agEjAEjWmuoNF",
+        bump: 255,
+    },
+    PdaVector {
+        name: "payout_account",
+        seeds: &[Seed::Str("payout_account"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "7ud1DSkwcHnjc5AGdWSqTDXirAKiUrcjfgKHL39ELHen",
+        bump: 255,
+    },
+    PdaVector {
+        name: "claimable",
+        seeds: &[Seed::Str("claimable"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "G87RtCYnXz32vnEwh5tADwHf8dyu5CJNHUVa9kXC5ATV",
+        bump: 254,
+    },
+    PdaVector {
+        name: "donation",
+        seeds: &[Seed::Str("donation"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "CFkiFpi2fsCH5UgHgGeMRMo1GfPLFXPW28UDojff4Xn",
+        bump: 255,
+    },
+    PdaVector {
+        name: "charity_totals",
+        seeds: &[Seed::Str("charity_totals"), Seed::Key(NAMESPACE), Seed::Key(CHARITY)],
+        address: "HYnEsKHcyFqNX76ypaCm96P2NHo8jqMMaFPG4uoTkk3t",
+        bump: 254,
+    },
+    PdaVector {
+        name: "feed_prefs",
+        seeds: &[Seed::Str("feed_prefs"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "6B2SJUtU1ErJ6cSGy41zyTRYcg7uYjsoVy1RWZVbDvCg",
+        bump: 254,
+    },
+    PdaVector {
+        name: "attestation",
+        seeds: &[Seed::Str("attestation"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: ATTESTATION,
+        bump: 253,
+    },
+    PdaVector {
+        name: "attestation_import",
+        seeds: &[Seed::Str("attestation_import"), Seed::Key(NAMESPACE), Seed::Key(ATTESTATION)],
+        address: "HxKQ7KCiraXUYFtLqhG9PyJPCmSwvn1jPcgAFyWKMMoB",
+        bump: 254,
+    },
+    PdaVector {
+        name: "pair_history",
+        seeds: &[
+            Seed::Str("pair_history"),
+            Seed::Key(NAMESPACE),
+            Seed::Key(CLIENT),
+            Seed::Key(FREELANCER),
+        ],
+        address: "FEc2HHtKY67YHtSyXD3Bmr2VE9JZUaqFe6bErYxFo38v",
+        bump: 255,
+    },
+    PdaVector {
+        name: "project",
+        seeds: &[
+            Seed::Str("project"),
+            Seed::Key(NAMESPACE),
+            Seed::Key(CLIENT),
+            Seed::Str(PROJECT_NAME),
+        ],
+        address: "DyT1ioZaGnnuR81zxx5pNkQBKmKc3xD9nzax1qyqhcxx",
+        bump: 252,
+    },
+    PdaVector {
+        name: "registry",
+        seeds: &[Seed::Str("registry"), Seed::Key(NAMESPACE), Seed::U32(0)],
+        address: "3YnucjYdAF7szrBV5nTLHjugQCq8hkMmTLAL4SHzXSvy",
+        bump: 251,
+    },
+    PdaVector {
+        name: "user_index (jobs)",
+        seeds: &[Seed::Str("user_index"), Seed::Key(CLIENT), Seed::U8(0), Seed::U32(0)],
+        address: "CaaUgHLrQtRNzqFxXHRV5a1ydxzLGyrcUdpiyVfpVfiP",
+        bump: 252,
+    },
+    PdaVector {
+        name: "user_index (applications)",
+        seeds: &[Seed::Str("user_index"), Seed::Key(FREELANCER), Seed::U8(1), Seed::U32(0)],
+        address: "AqhcqZxAkHVHoAjjE3rptva5boP3bnbc4uqZWjV2ebZX",
+        bump: 255,
+    },
+    PdaVector {
+        name: "credits",
+        seeds: &[Seed::Str("credits"), Seed::Key(NAMESPACE), Seed::Key(CLIENT)],
+        address: "DahgXTGzetzChUZvSGeMQwk4RZxmVGYnbv3WcYdmrg1z",
+        bump: 255,
+    },
+    PdaVector {
+        name: "release_limit",
+        seeds: &[Seed::Str("release_limit"), Seed::Key(CLIENT)],
+        address: "FELXN5ThRBdPXnSKctB8LwNVP8pHNRgPLFkYjzeyMZHh",
+        bump: 255,
+    },
+    PdaVector {
+        name: "delegate",
+        seeds: &[Seed::Str("delegate"), Seed::Key(CLIENT), Seed::Key(FREELANCER)],
+        address: "FoMhzaGLbet6KnRjN9NiYi8cgiVJQsNpwLqmKTZePsFC",
+        bump: 253,
+    },
+    PdaVector {
+        name: "reminder",
+        seeds: &[Seed::Str("reminder"), Seed::Key(JOB_POST), Seed::U8(0)],
+        address: "3vaxcVYQ7eDPsq99s5mDCUM2uFECMGRhfRKQi3kHG8jz",
+        bump: 254,
+    },
+    PdaVector {
+        name: "job_extension",
+        seeds: &[Seed::Str("job_extension"), Seed::Key(JOB_POST)],
+        address: "BA8E9kKreqqKzRgDVRbmkNmrLafdHgQy1hTuY6ayrdTK",
+        bump: 252,
+    },
+    PdaVector {
+        name: "referral_stats",
+        seeds: &[Seed::Str("referral_stats"), Seed::Key(NAMESPACE), Seed::Key(FREELANCER)],
+        address: "Ck6CaVqchcJnkFRvtK8JMzjTUwYfQk3kxnB3V16hn8Ei",
+        bump: 255,
+    },
+    PdaVector {
+        name: "deliverables",
+        seeds: &[Seed::Str("deliverables"), Seed::Key(APPLICATION)],
+        address: "28U9btkSs5CRwo5oGB8agsz9GhHmgMspSdv1zmwk8myE",
+        bump: 248,
+    },
+    PdaVector {
+        name: "cancellation",
+        seeds: &[Seed::Str("cancellation"), Seed::Key(APPLICATION)],
+        address: "zP4vCBEHuUpR3hgRP48QabQ1xFbzA4Cme7CDxFA8Yxf",
+        bump: 254,
+    },
+    PdaVector {
+        name: "review_sla",
+        seeds: &[Seed::Str("review_sla"), Seed::Key(APPLICATION)],
+        address: "6NjhzQPqpaH7Bk8WTttEQj4xdyYEA8z4nCfpesibURKM",
+        bump: 255,
+    },
+    PdaVector {
+        name: "profile",
+        seeds: &[Seed::Str("profile"), Seed::Key(FREELANCER)],
+        address: "FwokuTnM9xk4aCzAfrfV9zfdkiTGjt2YqGG3oKkDPANg",
+        bump: 253,
+    },
+    PdaVector {
+        name: "category_index",
+        seeds: &[Seed::Str("category_index"), Seed::Key(NAMESPACE), Seed::U8(2)],
+        address: "553AYVzcKTQcQfoa2bdofSGMTA58DM9yp5f4rhjYgTxm",
+        bump: 254,
+    },
+];
+
+// First 8 bytes of sha256("account:<Name>") / sha256("event:<Name>"),
+// prefixed to account data and to event payloads
+pub struct DiscriminatorVector {
+    pub name: &'static str,
+    pub discriminator: [u8; 8],
+}
+
+pub const ACCOUNT_DISCRIMINATORS: &[DiscriminatorVector] = &[
+    DiscriminatorVector {
+        name: "JobCounter",
+        discriminator: [146, 77, 173, 100, 24, 53, 195, 39],
+    },
+    DiscriminatorVector {
+        name: "JobPost",
+        discriminator: [209, 251, 190, 205, 19, 180, 151, 8],
+    },
+    DiscriminatorVector {
+        name: "JobCard",
+        discriminator: [1, 174, 72, 183, 27, 144, 97, 65],
+    },
+    DiscriminatorVector {
+        name: "CategoryIndex",
+        discriminator: [174, 97, 185, 19, 253, 33, 128, 92],
+    },
+    DiscriminatorVector {
+        name: "RegistryPage",
+        discriminator: [190, 151, 207, 163, 226, 253, 16, 250],
+    },
+    DiscriminatorVector {
+        name: "UserIndexPage",
+        discriminator: [242, 121, 62, 81, 223, 133, 236, 124],
+    },
+    DiscriminatorVector {
+        name: "Credits",
+        discriminator: [167, 92, 156, 89, 224, 222, 250, 237],
+    },
+    DiscriminatorVector {
+        name: "Application",
+        discriminator: [219, 9, 27, 113, 208, 126, 203, 30],
+    },
+    DiscriminatorVector {
+        name: "CancellationRequest",
+        discriminator: [11, 41, 62, 214, 209, 227, 236, 182],
+    },
+    DiscriminatorVector {
+        name: "Deliverables",
+        discriminator: [166, 229, 255, 58, 253, 4, 254, 115],
+    },
+    DiscriminatorVector {
+        name: "UserStats",
+        discriminator: [176, 223, 136, 27, 122, 79, 32, 227],
+    },
+    DiscriminatorVector {
+        name: "MonthlyStats",
+        discriminator: [17, 84, 78, 126, 154, 203, 168, 195],
+    },
+    DiscriminatorVector {
+        name: "ReputationAttestation",
+        discriminator: [55, 68, 116, 237, 237, 115, 173, 127],
+    },
+    DiscriminatorVector {
+        name: "AttestationImport",
+        discriminator: [230, 221, 0, 0, 2, 125, 89, 82],
+    },
+    DiscriminatorVector {
+        name: "Submission",
+        discriminator: [58, 194, 159, 158, 75, 102, 178, 197],
+    },
+    DiscriminatorVector {
+        name: "TimesheetEntry",
+        discriminator: [247, 254, 72, 149, 140, 75, 67, 123],
+    },
+    DiscriminatorVector {
+        name: "HourBlock",
+        discriminator: [50, 22, 123, 187, 85, 117, 220, 209],
+    },
+    DiscriminatorVector {
+        name: "BountyClaim",
+        discriminator: [130, 113, 6, 57, 79, 54, 78, 205],
+    },
+    DiscriminatorVector {
+        name: "Dispute",
+        discriminator: [36, 49, 241, 67, 40, 36, 241, 74],
+    },
+    DiscriminatorVector {
+        name: "SecondOpinion",
+        discriminator: [27, 120, 86, 97, 239, 2, 250, 220],
+    },
+    DiscriminatorVector {
+        name: "Delegate",
+        discriminator: [92, 145, 166, 111, 11, 38, 38, 247],
+    },
+    DiscriminatorVector {
+        name: "Reminder",
+        discriminator: [63, 229, 29, 224, 96, 48, 117, 92],
+    },
+    DiscriminatorVector {
+        name: "ReviewSla",
+        discriminator: [176, 131, 127, 183, 48, 84, 107, 98],
+    },
+    DiscriminatorVector {
+        name: "ReleaseLimit",
+        discriminator: [89, 155, 143, 70, 51, 135, 209, 96],
+    },
+    DiscriminatorVector {
+        name: "NdaAcknowledgment",
+        discriminator: [214, 15, 230, 224, 25, 32, 204, 215],
+    },
+    DiscriminatorVector {
+        name: "Invitation",
+        discriminator: [33, 100, 20, 1, 94, 190, 111, 106],
+    },
+    DiscriminatorVector {
+        name: "Project",
+        discriminator: [205, 168, 189, 202, 181, 247, 142, 19],
+    },
+    DiscriminatorVector {
+        name: "JobMilestones",
+        discriminator: [140, 149, 69, 97, 240, 225, 43, 59],
+    },
+    DiscriminatorVector {
+        name: "JobExtension",
+        discriminator: [7, 43, 214, 215, 113, 223, 105, 228],
+    },
+    DiscriminatorVector {
+        name: "FeedPrefs",
+        discriminator: [64, 219, 114, 121, 201, 12, 40, 108],
+    },
+    DiscriminatorVector {
+        name: "FreelancerProfile",
+        discriminator: [142, 199, 151, 44, 211, 185, 36, 26],
+    },
+    DiscriminatorVector {
+        name: "Profile",
+        discriminator: [184, 101, 165, 188, 95, 63, 127, 188],
+    },
+    DiscriminatorVector {
+        name: "PayoutGuardian",
+        discriminator: [206, 16, 180, 100, 83, 57, 55, 138],
+    },
+    DiscriminatorVector {
+        name: "PayoutAccount",
+        discriminator: [115, 109, 69, 6, 217, 253, 137, 228],
+    },
+    DiscriminatorVector {
+        name: "ClaimableVault",
+        discriminator: [165, 48, 166, 177, 48, 129, 74, 46],
+    },
+    DiscriminatorVector {
+        name: "PairHistory",
+        discriminator: [128, 204, 135, 119, 173, 16, 198, 84],
+    },
+    DiscriminatorVector {
+        name: "PlatformConfig",
+        discriminator: [160, 78, 128, 0, 248, 83, 230, 160],
+    },
+    DiscriminatorVector {
+        name: "OperatorStats",
+        discriminator: [245, 74, 110, 196, 86, 24, 185, 51],
+    },
+    DiscriminatorVector {
+        name: "InsurancePool",
+        discriminator: [239, 152, 145, 201, 228, 155, 139, 140],
+    },
+    DiscriminatorVector {
+        name: "SettlementHooks",
+        discriminator: [116, 122, 236, 249, 18, 252, 98, 230],
+    },
+    DiscriminatorVector {
+        name: "RecurringJob",
+        discriminator: [179, 163, 169, 135, 225, 237, 19, 205],
+    },
+    DiscriminatorVector {
+        name: "YearlyEarnings",
+        discriminator: [187, 207, 59, 92, 27, 218, 253, 166],
+    },
+    DiscriminatorVector {
+        name: "DonationPreference",
+        discriminator: [110, 135, 221, 162, 52, 69, 111, 81],
+    },
+    DiscriminatorVector {
+        name: "CharityTotals",
+        discriminator: [225, 171, 198, 185, 88, 86, 218, 163],
+    },
+    DiscriminatorVector {
+        name: "ReferralStats",
+        discriminator: [1, 195, 51, 123, 240, 93, 99, 240],
+    },
+    DiscriminatorVector {
+        name: "Escrow",
+        discriminator: [31, 213, 123, 187, 186, 22, 218, 155],
+    },
+];
+
+pub const EVENT_DISCRIMINATORS: &[DiscriminatorVector] = &[
+    DiscriminatorVector {
+        name: "JobPosted",
+        discriminator: [18, 171, 12, 141, 212, 169, 183, 52],
+    },
+    DiscriminatorVector {
+        name: "ReviewWindowSet",
+        discriminator: [9, 210, 211, 140, 114, 87, 159, 116],
+    },
+    DiscriminatorVector {
+        name: "ApplicationSubmitted",
+        discriminator: [202, 125, 149, 115, 111, 233, 172, 132],
+    },
+    DiscriminatorVector {
+        name: "PeriodPaid",
+        discriminator: [19, 42, 33, 29, 99, 106, 49, 34],
+    },
+    DiscriminatorVector {
+        name: "HoursLogged",
+        discriminator: [194, 62, 52, 244, 123, 157, 90, 242],
+    },
+    DiscriminatorVector {
+        name: "TimesheetApproved",
+        discriminator: [112, 210, 102, 136, 22, 132, 234, 180],
+    },
+    DiscriminatorVector {
+        name: "HourlyJobClosed",
+        discriminator: [197, 159, 32, 220, 179, 90, 7, 228],
+    },
+    DiscriminatorVector {
+        name: "HourBlockListed",
+        discriminator: [241, 17, 31, 221, 121, 60, 46, 86],
+    },
+    DiscriminatorVector {
+        name: "HourBlockPurchased",
+        discriminator: [207, 100, 189, 16, 142, 229, 208, 140],
+    },
+    DiscriminatorVector {
+        name: "HoursConsumed",
+        discriminator: [176, 144, 98, 26, 12, 181, 30, 146],
+    },
+    DiscriminatorVector {
+        name: "HourBlockRefunded",
+        discriminator: [113, 172, 104, 208, 139, 10, 49, 149],
+    },
+    DiscriminatorVector {
+        name: "RetainerCancelled",
+        discriminator: [24, 76, 89, 83, 1, 94, 51, 170],
+    },
+    DiscriminatorVector {
+        name: "VestedWithdrawn",
+        discriminator: [105, 42, 140, 91, 39, 128, 244, 57],
+    },
+    DiscriminatorVector {
+        name: "StreamCancelled",
+        discriminator: [91, 215, 29, 237, 194, 6, 184, 92],
+    },
+    DiscriminatorVector {
+        name: "TeamSharePaid",
+        discriminator: [178, 227, 13, 138, 164, 65, 121, 205],
+    },
+    DiscriminatorVector {
+        name: "PayoutParked",
+        discriminator: [105, 156, 186, 112, 140, 62, 5, 183],
+    },
+    DiscriminatorVector {
+        name: "FundsClaimed",
+        discriminator: [202, 115, 101, 227, 91, 111, 239, 217],
+    },
+    DiscriminatorVector {
+        name: "FreelancerInvited",
+        discriminator: [80, 138, 215, 206, 44, 211, 16, 132],
+    },
+    DiscriminatorVector {
+        name: "TransferFeeWithheld",
+        discriminator: [38, 243, 53, 144, 13, 18, 97, 232],
+    },
+    DiscriminatorVector {
+        name: "EscrowRentReconciled",
+        discriminator: [194, 62, 28, 84, 127, 202, 190, 38],
+    },
+    DiscriminatorVector {
+        name: "EscrowSnapshot",
+        discriminator: [112, 224, 2, 26, 71, 83, 105, 37],
+    },
+    DiscriminatorVector {
+        name: "EscrowDiscrepancy",
+        discriminator: [24, 248, 174, 41, 70, 32, 244, 35],
+    },
+    DiscriminatorVector {
+        name: "AccountMigrated",
+        discriminator: [153, 121, 252, 128, 30, 241, 166, 101],
+    },
+    DiscriminatorVector {
+        name: "EscrowSwept",
+        discriminator: [183, 123, 76, 75, 208, 119, 180, 208],
+    },
+    DiscriminatorVector {
+        name: "ApplicationStakeForfeited",
+        discriminator: [42, 167, 124, 175, 140, 15, 202, 33],
+    },
+    DiscriminatorVector {
+        name: "AvailabilityChanged",
+        discriminator: [14, 84, 172, 114, 53, 65, 211, 205],
+    },
+    DiscriminatorVector {
+        name: "TimezoneMismatch",
+        discriminator: [199, 136, 76, 58, 12, 63, 113, 245],
+    },
+    DiscriminatorVector {
+        name: "ApplicationsClosed",
+        discriminator: [15, 107, 100, 1, 207, 153, 156, 1],
+    },
+    DiscriminatorVector {
+        name: "LanguageMismatch",
+        discriminator: [44, 77, 65, 193, 210, 33, 244, 182],
+    },
+    DiscriminatorVector {
+        name: "ApplicationApproved",
+        discriminator: [15, 107, 157, 97, 85, 208, 97, 93],
+    },
+    DiscriminatorVector {
+        name: "CreditsGranted",
+        discriminator: [128, 66, 160, 5, 222, 142, 108, 212],
+    },
+    DiscriminatorVector {
+        name: "CreditsApplied",
+        discriminator: [237, 89, 57, 178, 30, 155, 252, 104],
+    },
+    DiscriminatorVector {
+        name: "CreditsReturned",
+        discriminator: [36, 44, 255, 193, 215, 163, 209, 201],
+    },
+    DiscriminatorVector {
+        name: "FeedbackUpdated",
+        discriminator: [94, 183, 102, 153, 18, 86, 138, 205],
+    },
+    DiscriminatorVector {
+        name: "ReviewRevealed",
+        discriminator: [201, 196, 41, 220, 56, 18, 36, 107],
+    },
+    DiscriminatorVector {
+        name: "JobAmountRevealed",
+        discriminator: [48, 218, 15, 94, 122, 55, 155, 236],
+    },
+    DiscriminatorVector {
+        name: "BountyClaimSubmitted",
+        discriminator: [67, 172, 222, 179, 234, 167, 201, 207],
+    },
+    DiscriminatorVector {
+        name: "BountyVoteCast",
+        discriminator: [190, 61, 112, 245, 252, 162, 90, 231],
+    },
+    DiscriminatorVector {
+        name: "BountyAwarded",
+        discriminator: [230, 101, 91, 225, 16, 230, 193, 102],
+    },
+    DiscriminatorVector {
+        name: "RefundedAsCredits",
+        discriminator: [20, 135, 133, 208, 251, 115, 112, 170],
+    },
+    DiscriminatorVector {
+        name: "ApplicationDeclined",
+        discriminator: [87, 245, 222, 208, 73, 53, 76, 215],
+    },
+    DiscriminatorVector {
+        name: "WorkSubmitted",
+        discriminator: [136, 185, 210, 174, 216, 140, 64, 125],
+    },
+    DiscriminatorVector {
+        name: "SubmissionRejected",
+        discriminator: [222, 120, 204, 232, 51, 196, 71, 146],
+    },
+    DiscriminatorVector {
+        name: "ApplicationWithdrawn",
+        discriminator: [227, 34, 249, 159, 119, 53, 49, 162],
+    },
+    DiscriminatorVector {
+        name: "JobCancelled",
+        discriminator: [203, 84, 143, 130, 48, 134, 74, 191],
+    },
+    DiscriminatorVector {
+        name: "JobExpired",
+        discriminator: [213, 123, 115, 173, 157, 242, 12, 71],
+    },
+    DiscriminatorVector {
+        name: "DeliverableAdded",
+        discriminator: [64, 49, 2, 72, 198, 47, 196, 29],
+    },
+    DiscriminatorVector {
+        name: "DeliverableAccepted",
+        discriminator: [104, 239, 54, 166, 110, 198, 163, 126],
+    },
+    DiscriminatorVector {
+        name: "DeliverableRejected",
+        discriminator: [100, 252, 207, 24, 73, 230, 148, 229],
+    },
+    DiscriminatorVector {
+        name: "ReviewLateFeePaid",
+        discriminator: [143, 22, 0, 250, 182, 244, 245, 180],
+    },
+    DiscriminatorVector {
+        name: "EscrowToppedUp",
+        discriminator: [55, 95, 238, 56, 146, 173, 210, 70],
+    },
+    DiscriminatorVector {
+        name: "BadgeMinted",
+        discriminator: [53, 227, 68, 72, 115, 78, 25, 14],
+    },
+    DiscriminatorVector {
+        name: "SolLegFunded",
+        discriminator: [76, 111, 97, 66, 129, 252, 246, 182],
+    },
+    DiscriminatorVector {
+        name: "SolLegSettled",
+        discriminator: [109, 219, 169, 247, 108, 92, 53, 10],
+    },
+    DiscriminatorVector {
+        name: "TipPaid",
+        discriminator: [2, 156, 36, 89, 38, 63, 2, 2],
+    },
+    DiscriminatorVector {
+        name: "MutualCancelRequested",
+        discriminator: [14, 25, 104, 32, 178, 244, 151, 134],
+    },
+    DiscriminatorVector {
+        name: "FreelancerRemoved",
+        discriminator: [59, 163, 179, 204, 70, 15, 140, 66],
+    },
+    DiscriminatorVector {
+        name: "ReferralRewarded",
+        discriminator: [9, 69, 68, 66, 160, 28, 147, 12],
+    },
+    DiscriminatorVector {
+        name: "ReminderFired",
+        discriminator: [119, 18, 142, 252, 240, 74, 225, 243],
+    },
+    DiscriminatorVector {
+        name: "PauseChanged",
+        discriminator: [238, 188, 213, 78, 134, 209, 178, 218],
+    },
+    DiscriminatorVector {
+        name: "JobModerated",
+        discriminator: [138, 197, 109, 134, 226, 93, 116, 33],
+    },
+    DiscriminatorVector {
+        name: "JobFreezeChanged",
+        discriminator: [39, 80, 163, 239, 189, 199, 64, 142],
+    },
+    DiscriminatorVector {
+        name: "LargePayout",
+        discriminator: [103, 14, 189, 140, 84, 20, 255, 91],
+    },
+    DiscriminatorVector {
+        name: "ReleaseCoSigned",
+        discriminator: [171, 95, 46, 84, 241, 111, 197, 198],
+    },
+    DiscriminatorVector {
+        name: "SubmissionApproved",
+        discriminator: [59, 102, 182, 29, 243, 202, 148, 164],
+    },
+    DiscriminatorVector {
+        name: "MilestoneApproved",
+        discriminator: [40, 109, 159, 144, 169, 230, 35, 229],
+    },
+    DiscriminatorVector {
+        name: "DisputeOpened",
+        discriminator: [239, 222, 102, 235, 193, 85, 1, 214],
+    },
+    DiscriminatorVector {
+        name: "SettlementOffered",
+        discriminator: [139, 198, 199, 111, 72, 121, 192, 210],
+    },
+    DiscriminatorVector {
+        name: "SettlementAccepted",
+        discriminator: [126, 2, 19, 157, 94, 224, 187, 150],
+    },
+    DiscriminatorVector {
+        name: "Metrics",
+        discriminator: [70, 32, 81, 210, 16, 212, 16, 173],
+    },
+    DiscriminatorVector {
+        name: "InsuranceClaimPaid",
+        discriminator: [192, 156, 171, 111, 107, 227, 78, 164],
+    },
+    DiscriminatorVector {
+        name: "DisputeResolved",
+        discriminator: [121, 64, 249, 153, 139, 128, 236, 187],
+    },
+    DiscriminatorVector {
+        name: "BudgetThresholdCrossed",
+        discriminator: [130, 122, 108, 247, 143, 53, 219, 234],
+    },
+    DiscriminatorVector {
+        name: "DeadlineSet",
+        discriminator: [234, 94, 242, 33, 204, 98, 123, 227],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn discriminator(table: &[DiscriminatorVector], name: &str) -> [u8; 8] {
+        table
+            .iter()
+            .find(|vector| vector.name == name)
+            .unwrap_or_else(|| panic!("no vector for {name}"))
+            .discriminator
+    }
+
+    macro_rules! assert_discriminators {
+        ($table:expr, $($ty:ident),* $(,)?) => {
+            $(assert_eq!(&discriminator($table, stringify!($ty))[..], $ty::DISCRIMINATOR);)*
+            assert_eq!($table.len(), [$(stringify!($ty)),*].len());
+        };
+    }
+
+    #[test]
+    fn fixed_keys_are_repeated_bytes() {
+        for (n, key) in [ADMIN, CLIENT, FREELANCER, CHARITY].iter().enumerate() {
+            assert_eq!(key.parse::<Pubkey>().unwrap(), Pubkey::new_from_array([n as u8 + 1; 32]));
+        }
+    }
+
+    #[test]
+    fn pda_vectors_match_derivation() {
+        for vector in PDA_VECTORS {
+            let (address, bump) = vector.derive();
+            assert_eq!(address.to_string(), vector.address, "{}", vector.name);
+            assert_eq!(bump, vector.bump, "{}", vector.name);
+        }
+    }
+
+    #[test]
+    fn discriminator_vectors_match_types() {
+        assert_discriminators!(
+            ACCOUNT_DISCRIMINATORS,
+            JobCounter, JobPost, JobCard, CategoryIndex, RegistryPage, UserIndexPage,
+            Credits, Application,
+            CancellationRequest, Deliverables, UserStats, MonthlyStats,
+            ReputationAttestation, AttestationImport, Submission, TimesheetEntry, BountyClaim,
+            HourBlock, Dispute,
+            SecondOpinion,
+            Delegate, Reminder, ReviewSla, ReleaseLimit, NdaAcknowledgment, Invitation,
+            Project,
+            JobMilestones, JobExtension, FeedPrefs, FreelancerProfile, Profile,
+            PayoutGuardian, PayoutAccount, ClaimableVault, PairHistory, PlatformConfig,
+            OperatorStats, InsurancePool, SettlementHooks, RecurringJob, YearlyEarnings,
+            DonationPreference, CharityTotals, ReferralStats, Escrow
+        );
+        assert_discriminators!(
+            EVENT_DISCRIMINATORS,
+            JobPosted, ReviewWindowSet, ApplicationSubmitted, PeriodPaid, RetainerCancelled,
+            HoursLogged, TimesheetApproved, HourlyJobClosed, HourBlockListed, HourBlockPurchased,
+            HoursConsumed, HourBlockRefunded,
+            VestedWithdrawn, StreamCancelled, TeamSharePaid, PayoutParked, FundsClaimed,
+            FreelancerInvited, TransferFeeWithheld, EscrowRentReconciled, EscrowSnapshot,
+            EscrowDiscrepancy, EscrowSwept, AccountMigrated, ApplicationStakeForfeited,
+            AvailabilityChanged,
+            TimezoneMismatch,
+            ApplicationsClosed, LanguageMismatch, ApplicationApproved, CreditsGranted,
+            CreditsApplied, CreditsReturned, ReviewRevealed, FeedbackUpdated, JobAmountRevealed,
+            BountyClaimSubmitted, BountyVoteCast, BountyAwarded,
+            RefundedAsCredits, ApplicationDeclined, WorkSubmitted, SubmissionRejected,
+            ApplicationWithdrawn, JobCancelled, JobExpired,
+            DeliverableAdded, DeliverableAccepted, DeliverableRejected, ReviewLateFeePaid,
+            EscrowToppedUp, BadgeMinted, SolLegFunded, SolLegSettled, TipPaid,
+            MutualCancelRequested, FreelancerRemoved,
+            ReferralRewarded, ReminderFired, PauseChanged, JobFreezeChanged, JobModerated,
+            LargePayout,
+            ReleaseCoSigned,
+            SubmissionApproved, MilestoneApproved, DisputeOpened, SettlementOffered,
+            SettlementAccepted, DisputeResolved, InsuranceClaimPaid, BudgetThresholdCrossed,
+            DeadlineSet, Metrics
+        );
+    }
+}